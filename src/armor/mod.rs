@@ -0,0 +1,337 @@
+use std::{fmt::Display, rc::Rc};
+
+use serde::{
+    de::{Unexpected, Visitor},
+    Deserialize, Serialize,
+};
+
+use crate::weapon::CraftingMaterial;
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct ArmorEntity {
+    pub id: u16,
+    pub name: String,
+    /// Armor set the piece belongs to, usually the monster it is forged from.
+    pub set: String,
+    pub rank: ArmorRank,
+    pub kind: ArmorKind,
+    pub rarity: u8,
+    pub defense: u16,
+    #[serde(default)]
+    pub resistances: Resistances,
+    #[serde(default)]
+    pub skills: Vec<ArmorSkill>,
+    /// Decoration slot levels.
+    #[serde(default)]
+    pub slots: Vec<u8>,
+    pub materials: Vec<CraftingMaterial>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub struct Resistances {
+    #[serde(default)]
+    pub fire: i8,
+    #[serde(default)]
+    pub water: i8,
+    #[serde(default)]
+    pub thunder: i8,
+    #[serde(default)]
+    pub ice: i8,
+    #[serde(default)]
+    pub dragon: i8,
+}
+
+impl Resistances {
+    /// Resistances in the order fire, water, thunder, ice, dragon.
+    pub fn values(&self) -> [i8; 5] {
+        [self.fire, self.water, self.thunder, self.ice, self.dragon]
+    }
+}
+
+/// Sums clamp at the `i8` bounds instead of overflowing on hand written data.
+impl std::ops::Add for Resistances {
+    type Output = Resistances;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Resistances {
+            fire: self.fire.saturating_add(rhs.fire),
+            water: self.water.saturating_add(rhs.water),
+            thunder: self.thunder.saturating_add(rhs.thunder),
+            ice: self.ice.saturating_add(rhs.ice),
+            dragon: self.dragon.saturating_add(rhs.dragon),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct ArmorSkill {
+    pub name: String,
+    pub level: u8,
+}
+
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ArmorRank {
+    #[default]
+    Low,
+    High,
+}
+
+impl ArmorRank {
+    pub const ALL: [ArmorRank; 2] = [ArmorRank::Low, ArmorRank::High];
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "low" => Some(ArmorRank::Low),
+            "high" => Some(ArmorRank::High),
+            _ => None,
+        }
+    }
+
+    pub fn key(&self) -> &'static str {
+        match self {
+            ArmorRank::Low => "low",
+            ArmorRank::High => "high",
+        }
+    }
+}
+
+impl Display for ArmorRank {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ArmorRank::Low => write!(f, "Low Rank"),
+            ArmorRank::High => write!(f, "High Rank"),
+        }
+    }
+}
+
+impl Serialize for ArmorRank {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.key())
+    }
+}
+
+impl<'de> Deserialize<'de> for ArmorRank {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(ArmorRankVisitor)
+    }
+}
+
+struct ArmorRankVisitor;
+
+impl<'de> Visitor<'de> for ArmorRankVisitor {
+    type Value = ArmorRank;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("Armor Rank")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        ArmorRank::from_key(v).ok_or_else(|| E::invalid_value(Unexpected::Str(v), &self))
+    }
+}
+
+/// Equipment slot of an armor piece.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ArmorKind {
+    #[default]
+    Head,
+    Chest,
+    Arms,
+    Waist,
+    Legs,
+}
+
+impl ArmorKind {
+    pub const ALL: [ArmorKind; 5] = [
+        ArmorKind::Head,
+        ArmorKind::Chest,
+        ArmorKind::Arms,
+        ArmorKind::Waist,
+        ArmorKind::Legs,
+    ];
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        use ArmorKind::*;
+        match key {
+            "head" => Some(Head),
+            "chest" => Some(Chest),
+            "arms" => Some(Arms),
+            "waist" => Some(Waist),
+            "legs" => Some(Legs),
+            _ => None,
+        }
+    }
+
+    pub fn key(&self) -> &'static str {
+        use ArmorKind::*;
+        match self {
+            Head => "head",
+            Chest => "chest",
+            Arms => "arms",
+            Waist => "waist",
+            Legs => "legs",
+        }
+    }
+
+    /// Position of the kind in a set, head first.
+    pub fn index(&self) -> usize {
+        *self as usize
+    }
+}
+
+impl Display for ArmorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use ArmorKind::*;
+        match self {
+            Head => write!(f, "Head"),
+            Chest => write!(f, "Chest"),
+            Arms => write!(f, "Arms"),
+            Waist => write!(f, "Waist"),
+            Legs => write!(f, "Legs"),
+        }
+    }
+}
+
+impl Serialize for ArmorKind {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.key())
+    }
+}
+
+impl<'de> Deserialize<'de> for ArmorKind {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(ArmorKindVisitor)
+    }
+}
+
+struct ArmorKindVisitor;
+
+impl<'de> Visitor<'de> for ArmorKindVisitor {
+    type Value = ArmorKind;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("Armor Kind")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        ArmorKind::from_key(v).ok_or_else(|| E::invalid_value(Unexpected::Str(v), &self))
+    }
+}
+
+/// Pieces picked in the set builder, one per armor kind.
+#[derive(Clone, Default)]
+pub struct ArmorSet {
+    pub pieces: [Option<Rc<ArmorEntity>>; 5],
+}
+
+impl ArmorSet {
+    /// Put `piece` in its slot, or take it off when it is already worn.
+    pub fn toggle(&mut self, piece: &Rc<ArmorEntity>) {
+        let slot = &mut self.pieces[piece.kind.index()];
+        *slot = match slot {
+            Some(worn) if worn.id == piece.id => None,
+            _ => Some(Rc::clone(piece)),
+        };
+    }
+
+    pub fn is_worn(&self, piece: &ArmorEntity) -> bool {
+        self.pieces[piece.kind.index()]
+            .as_ref()
+            .is_some_and(|worn| worn.id == piece.id)
+    }
+
+    pub fn clear(&mut self) {
+        self.pieces = Default::default();
+    }
+
+    fn worn(&self) -> impl Iterator<Item = &Rc<ArmorEntity>> {
+        self.pieces.iter().flatten()
+    }
+
+    pub fn defense(&self) -> u16 {
+        self.worn()
+            .fold(0, |total: u16, piece| total.saturating_add(piece.defense))
+    }
+
+    pub fn resistances(&self) -> Resistances {
+        self.worn()
+            .map(|piece| piece.resistances)
+            .fold(Resistances::default(), |total, r| total + r)
+    }
+
+    /// Summed skill levels, highest first.
+    pub fn skills(&self) -> Vec<ArmorSkill> {
+        let mut skills: Vec<ArmorSkill> = vec![];
+        for skill in self.worn().flat_map(|piece| &piece.skills) {
+            match skills.iter_mut().find(|s| s.name == skill.name) {
+                Some(total) => total.level = total.level.saturating_add(skill.level),
+                None => skills.push(skill.clone()),
+            }
+        }
+
+        skills.sort_by(|a, b| b.level.cmp(&a.level).then_with(|| a.name.cmp(&b.name)));
+        skills
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn piece(id: u16, kind: ArmorKind, value: i8) -> Rc<ArmorEntity> {
+        Rc::new(ArmorEntity {
+            id,
+            kind,
+            defense: u16::MAX,
+            resistances: Resistances {
+                fire: value,
+                water: value,
+                thunder: value,
+                ice: value,
+                dragon: value,
+            },
+            skills: vec![ArmorSkill {
+                name: String::from("Attack Boost"),
+                level: u8::MAX,
+            }],
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn extreme_set_saturates() {
+        let mut set = ArmorSet::default();
+        for (id, kind) in ArmorKind::ALL.into_iter().enumerate() {
+            set.toggle(&piece(id as u16, kind, i8::MAX));
+        }
+
+        assert_eq!(set.defense(), u16::MAX);
+        assert_eq!(set.resistances().values(), [i8::MAX; 5]);
+        let skills = set.skills();
+        assert_eq!(skills.len(), 1);
+        assert_eq!(skills[0].level, u8::MAX);
+
+        for (id, kind) in ArmorKind::ALL.into_iter().enumerate() {
+            set.toggle(&piece(10 + id as u16, kind, i8::MIN));
+        }
+        assert_eq!(set.resistances().values(), [i8::MIN; 5]);
+    }
+}
@@ -0,0 +1,138 @@
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand};
+
+use crate::{
+    command::{iconify::parse_hex_color, show::ShowFormat},
+    data::{
+        export::ExportFormat,
+        validate::ValidationMode,
+        weak_to::{Attack, RankBy},
+    },
+    theme::glyph::GlyphSet,
+};
+
+#[derive(Parser, Debug)]
+#[command(version, about = "Monster Hunter Wilds data source in your terminal")]
+pub struct Cli {
+    /// Monster data file, or directory of per-monster JSON files
+    #[arg(long, value_name = "PATH", global = true)]
+    pub data: Option<PathBuf>,
+
+    /// Reject unknown enum values in the data instead of warning about them
+    #[arg(long, global = true)]
+    pub strict: bool,
+
+    /// Directory of monster icon files, instead of `icons` next to the executable, in the
+    /// working directory or embedded
+    #[arg(long, value_name = "DIR", global = true)]
+    pub icons: Option<PathBuf>,
+
+    /// Keymap file, instead of keymap.toml in the config directory
+    #[arg(long, value_name = "PATH", global = true)]
+    pub keymap: Option<PathBuf>,
+
+    /// Color theme: dark, light, high-contrast or a theme file, instead of theme.toml in the config directory
+    #[arg(long, value_name = "NAME|PATH", global = true)]
+    pub theme: Option<String>,
+
+    /// Glyphs to draw with, instead of the theme's or the one detected from the terminal
+    #[arg(long, value_enum, global = true)]
+    pub glyphs: Option<GlyphSet>,
+
+    /// Notes file, instead of notes.json in the data directory
+    #[arg(long, value_name = "PATH", global = true)]
+    pub notes: Option<PathBuf>,
+
+    /// Hunt log file, instead of hunts.json in the data directory
+    #[arg(long, value_name = "PATH")]
+    pub hunt_log: Option<PathBuf>,
+
+    /// Reopen the screen and monster of the last run
+    #[arg(long)]
+    pub restore: bool,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Check the monster data for integrity problems and exit non-zero if any is found
+    CheckData,
+
+    /// Export the monster data, or a subset of it, as JSON, CSV or Markdown
+    Export {
+        #[arg(short, long, value_enum, default_value_t)]
+        format: ExportFormat,
+
+        /// Output file (directory for CSV). JSON and Markdown go to stdout when omitted
+        #[arg(short, long, value_name = "PATH")]
+        output: Option<PathBuf>,
+
+        /// Only export this monster, by id, name, alias or icon code. Can be repeated
+        #[arg(short, long, value_name = "MONSTER")]
+        monster: Vec<String>,
+
+        /// Only export monsters whose name contains this text
+        #[arg(short, long, value_name = "TEXT")]
+        search: Option<String>,
+    },
+
+    /// Print a monster profile: basic info, weaknesses, ailments, items, habitats, quests and drops
+    Show {
+        /// Monster id, name, alias or icon code. Anything else is fuzzy matched on the names
+        monster: String,
+
+        /// Output format, colored text on a terminal and plain text otherwise by default
+        #[arg(short, long, value_enum)]
+        format: Option<ShowFormat>,
+    },
+
+    /// Rank every monster by its weakness to an element or a weapon damage type
+    WeakTo {
+        /// Element, or weapon damage type
+        #[arg(value_enum)]
+        attack: Attack,
+
+        /// Order by the weakest part or by the average over all parts
+        #[arg(short, long, value_enum, default_value_t)]
+        by: RankBy,
+
+        /// Only print the first N monsters
+        #[arg(short = 'n', long, value_name = "N")]
+        limit: Option<usize>,
+    },
+
+    /// Convert a PNG/JPEG image, or a directory of them, into a half-block ANSI icon
+    Iconify {
+        /// Image file, or directory of images for batch mode
+        input: PathBuf,
+
+        /// Output file (directory in batch mode). A single icon goes to stdout when omitted
+        #[arg(short, long, value_name = "PATH")]
+        output: Option<PathBuf>,
+
+        /// Icon width in terminal columns
+        #[arg(short, long, default_value_t = 50)]
+        width: u32,
+
+        /// Pixels with a lower alpha (0-255) are left transparent
+        #[arg(long, default_value_t = 128, value_name = "ALPHA")]
+        alpha_threshold: u8,
+
+        /// Blend transparent pixels onto this color instead, e.g. `1e1e2e`
+        #[arg(long, value_name = "RRGGBB", value_parser = parse_hex_color)]
+        background: Option<[u8; 3]>,
+    },
+}
+
+impl Cli {
+    pub fn validation_mode(&self) -> ValidationMode {
+        if self.strict {
+            ValidationMode::Strict
+        } else {
+            ValidationMode::Lenient
+        }
+    }
+}
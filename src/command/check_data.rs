@@ -0,0 +1,32 @@
+use crate::{
+    data::{check::check_data, MonsterData},
+    monster::icon::IconSource,
+};
+
+/// Print every integrity problem in the dataset and return the process exit code.
+pub fn run(data: &MonsterData, icons: &IconSource) -> i32 {
+    let problems = check_data(&data.monsters, icons);
+
+    data.warnings
+        .iter()
+        .for_each(|issue| println!("invalid: {}", issue));
+    problems
+        .iter()
+        .for_each(|problem| println!("problem: {}", problem));
+
+    let total = data.warnings.len() + problems.len();
+    if total == 0 {
+        println!(
+            "{} monster(s) checked, no problem found",
+            data.monsters.len()
+        );
+        0
+    } else {
+        println!(
+            "{} monster(s) checked, {} problem(s) found",
+            data.monsters.len(),
+            total
+        );
+        1
+    }
+}
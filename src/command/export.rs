@@ -0,0 +1,70 @@
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::PathBuf,
+};
+
+use anyhow::{bail, Context, Result};
+
+use crate::{
+    data::{
+        export::{export_csv, export_json, export_markdown, ExportFormat},
+        notes::Notes,
+    },
+    monster::monster::MonsterEntity,
+};
+
+pub struct ExportOptions {
+    pub format: ExportFormat,
+    pub output: Option<PathBuf>,
+    pub monster: Vec<String>,
+    pub search: Option<String>,
+}
+
+pub fn run(monsters: &[MonsterEntity], notes: &Notes, options: ExportOptions) -> Result<()> {
+    let search = options.search.map(|s| s.to_lowercase());
+    let selected = monsters
+        .iter()
+        .filter(|m| options.monster.is_empty() || options.monster.iter().any(|k| m.is_named(k)))
+        .filter(|m| {
+            search
+                .as_ref()
+                .is_none_or(|s| m.name.name.to_lowercase().contains(s))
+        })
+        .collect::<Vec<&MonsterEntity>>();
+
+    if selected.is_empty() {
+        bail!("no monster matches the export filter");
+    }
+
+    match (options.format, options.output) {
+        (ExportFormat::Csv, Some(dir)) => export_csv(&selected, notes, &dir)
+            .with_context(|| format!("cannot write CSV files into `{}`", dir.display())),
+        (ExportFormat::Csv, None) => bail!("CSV export needs an output directory (--output)"),
+        (format, Some(path)) => {
+            let file = File::create(&path)
+                .with_context(|| format!("cannot create `{}`", path.display()))?;
+            let mut writer = BufWriter::new(file);
+            write_document(format, &selected, notes, &mut writer)
+                .and_then(|_| writer.flush())
+                .with_context(|| format!("cannot write `{}`", path.display()))
+        }
+        (format, None) => {
+            let mut stdout = io::stdout().lock();
+            write_document(format, &selected, notes, &mut stdout).context("cannot write to stdout")
+        }
+    }
+}
+
+fn write_document(
+    format: ExportFormat,
+    monsters: &[&MonsterEntity],
+    notes: &Notes,
+    writer: &mut impl Write,
+) -> io::Result<()> {
+    match format {
+        ExportFormat::Json => export_json(monsters, notes, writer),
+        ExportFormat::Markdown => export_markdown(monsters, notes, writer),
+        ExportFormat::Csv => unreachable!("CSV is written as one file per table"),
+    }
+}
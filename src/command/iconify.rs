@@ -0,0 +1,165 @@
+use std::{
+    fmt::Write as _,
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context, Result};
+use image::{imageops::FilterType, RgbaImage};
+
+/// Image extensions picked up in batch mode.
+const IMAGE_EXTENSIONS: [&str; 3] = ["png", "jpg", "jpeg"];
+
+pub struct IconifyOptions {
+    pub input: PathBuf,
+    pub output: Option<PathBuf>,
+    /// Icon width in terminal columns, one pixel per column.
+    pub width: u32,
+    /// Pixels with a lower alpha are left transparent.
+    pub alpha_threshold: u8,
+    /// Blend transparency onto this color instead of keeping it transparent.
+    pub background: Option<[u8; 3]>,
+}
+
+pub fn run(options: IconifyOptions) -> Result<()> {
+    if options.width == 0 {
+        bail!("the icon width must be at least 1");
+    }
+
+    if options.input.is_dir() {
+        let Some(dir) = &options.output else {
+            bail!("batch mode needs an output directory (--output)");
+        };
+        return convert_dir(&options.input, dir, &options);
+    }
+
+    let icon = convert_file(&options.input, &options)?;
+    match &options.output {
+        Some(path) => {
+            fs::write(path, icon).with_context(|| format!("cannot write `{}`", path.display()))
+        }
+        None => io::stdout()
+            .lock()
+            .write_all(icon.as_bytes())
+            .context("cannot write to stdout"),
+    }
+}
+
+fn convert_dir(input: &Path, output: &Path, options: &IconifyOptions) -> Result<()> {
+    fs::create_dir_all(output).with_context(|| format!("cannot create `{}`", output.display()))?;
+
+    let mut images: Vec<PathBuf> = fs::read_dir(input)
+        .with_context(|| format!("cannot read `{}`", input.display()))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| is_image(path))
+        .collect();
+    images.sort();
+
+    if images.is_empty() {
+        bail!("no PNG or JPEG image in `{}`", input.display());
+    }
+
+    for image in &images {
+        let target = output.join(icon_code(image));
+        let icon = convert_file(image, options)?;
+        fs::write(&target, icon).with_context(|| format!("cannot write `{}`", target.display()))?;
+        eprintln!("{} -> {}", image.display(), target.display());
+    }
+
+    eprintln!("{} icon(s) written to `{}`", images.len(), output.display());
+    Ok(())
+}
+
+fn is_image(path: &Path) -> bool {
+    path.is_file()
+        && path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| IMAGE_EXTENSIONS.contains(&ext.to_lowercase().as_str()))
+}
+
+/// File name the monster data expects as `icon_code`: "Gore Magala.png" -> "gore_magala".
+fn icon_code(path: &Path) -> String {
+    path.file_stem()
+        .map(|stem| stem.to_string_lossy().to_lowercase())
+        .unwrap_or_default()
+        .split(|c: char| c.is_whitespace() || c == '-')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<&str>>()
+        .join("_")
+}
+
+fn convert_file(path: &Path, options: &IconifyOptions) -> Result<String> {
+    let image = image::open(path)
+        .with_context(|| format!("cannot read image `{}`", path.display()))?
+        .to_rgba8();
+
+    // a terminal cell is two pixels high, so keep the height even
+    let height = (image.height() as u64 * options.width as u64)
+        .div_ceil(image.width().max(1) as u64)
+        .max(2)
+        .next_multiple_of(2) as u32;
+    let resized = image::imageops::resize(&image, options.width, height, FilterType::Lanczos3);
+
+    Ok(to_ansi(&resized, options))
+}
+
+/// Render two pixel rows per line with `▄`: the foreground is the bottom pixel
+/// and the background the top one, the same format as the files in `icons/`.
+fn to_ansi(image: &RgbaImage, options: &IconifyOptions) -> String {
+    let pixel = |x: u32, y: u32| -> Option<[u8; 3]> {
+        let [r, g, b, a] = image.get_pixel(x, y).0;
+        match options.background {
+            Some(bg) => Some([blend(r, bg[0], a), blend(g, bg[1], a), blend(b, bg[2], a)]),
+            None if a < options.alpha_threshold => None,
+            None => Some([r, g, b]),
+        }
+    };
+
+    let mut out = String::new();
+    for y in (0..image.height()).step_by(2) {
+        let mut blank = false;
+        for x in 0..image.width() {
+            let top = pixel(x, y);
+            let bottom = pixel(x, y + 1);
+
+            match (top, bottom) {
+                (None, None) => {
+                    if !blank {
+                        out.push_str("\x1b[49m");
+                    }
+                    out.push(' ');
+                }
+                (None, Some([r, g, b])) => {
+                    let _ = write!(out, "\x1b[38;2;{r};{g};{b};49m▄");
+                }
+                (Some([r, g, b]), None) => {
+                    let _ = write!(out, "\x1b[38;2;{r};{g};{b};49m▀");
+                }
+                (Some([tr, tg, tb]), Some([br, bg, bb])) => {
+                    let _ = write!(out, "\x1b[38;2;{br};{bg};{bb};48;2;{tr};{tg};{tb}m▄");
+                }
+            }
+            blank = top.is_none() && bottom.is_none();
+        }
+        out.push_str("\x1b[m\n");
+    }
+
+    out
+}
+
+fn blend(color: u8, background: u8, alpha: u8) -> u8 {
+    ((color as u16 * alpha as u16 + background as u16 * (255 - alpha as u16)) / 255) as u8
+}
+
+/// Parse `RRGGBB`, with or without a leading `#`.
+pub fn parse_hex_color(value: &str) -> Result<[u8; 3], String> {
+    let hex = value.trim_start_matches('#');
+    if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(format!("`{value}` is not a RRGGBB color"));
+    }
+
+    let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).map_err(|e| e.to_string());
+    Ok([channel(0)?, channel(2)?, channel(4)?])
+}
@@ -0,0 +1,5 @@
+pub mod check_data;
+pub mod export;
+pub mod iconify;
+pub mod show;
+pub mod weak_to;
@@ -0,0 +1,392 @@
+use std::io::{self, IsTerminal, Write};
+
+use anyhow::{bail, Context, Result};
+use clap::ValueEnum;
+use ratatui::{
+    crossterm::style::{self, Stylize},
+    style::Color,
+};
+
+use crate::{
+    monster::{
+        monster::{MonsterEntity, HITZONE_ELEMENTS},
+        query::{Filter, Query},
+    },
+    theme::Theme,
+};
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ShowFormat {
+    /// Aligned plain text
+    Text,
+    /// Text colored with the theme, the default on a terminal
+    Ansi,
+    /// The monster data entry itself
+    Json,
+}
+
+pub struct ShowOptions {
+    pub monster: String,
+    pub format: Option<ShowFormat>,
+}
+
+pub fn run(monsters: &[MonsterEntity], theme: &Theme, options: ShowOptions) -> Result<()> {
+    let monster = find_monster(monsters, &options.monster)?;
+    let format = options.format.unwrap_or_else(|| {
+        if io::stdout().is_terminal() {
+            ShowFormat::Ansi
+        } else {
+            ShowFormat::Text
+        }
+    });
+
+    let mut stdout = io::stdout().lock();
+    let written = match format {
+        ShowFormat::Json => serde_json::to_writer_pretty(&mut stdout, monster)
+            .map_err(io::Error::from)
+            .and_then(|_| writeln!(stdout)),
+        ShowFormat::Text => write_profile(monster, Paint { ansi: false, theme }, &mut stdout),
+        ShowFormat::Ansi => write_profile(monster, Paint { ansi: true, theme }, &mut stdout),
+    };
+
+    match written {
+        // `monsterex show rathalos | head` closes stdout early
+        Err(err) if err.kind() == io::ErrorKind::BrokenPipe => Ok(()),
+        written => written.context("cannot write to stdout"),
+    }
+}
+
+/// The monster with `key` as id, name, alias or icon code, else the best fuzzy match on the names.
+fn find_monster<'a>(monsters: &'a [MonsterEntity], key: &str) -> Result<&'a MonsterEntity> {
+    let named = monsters
+        .iter()
+        .filter(|m| m.is_named(key))
+        .collect::<Vec<&MonsterEntity>>();
+    match named.as_slice() {
+        [monster] => return Ok(monster),
+        [_, _, ..] => bail!("`{}` matches several monsters: {}", key, names(&named)),
+        [] => {}
+    }
+
+    let query = Query::Filter(Filter::Name(key.trim().to_string()));
+    let mut ranked = monsters
+        .iter()
+        .filter_map(|m| query.rank(m).map(|rank| (rank.score, m)))
+        .collect::<Vec<_>>();
+    ranked.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+
+    match ranked.as_slice() {
+        [] => bail!("no monster named `{}`", key),
+        [(best, monster), rest @ ..] if rest.first().is_none_or(|(score, _)| score < best) => {
+            Ok(monster)
+        }
+        [(best, _), ..] => {
+            let tied = ranked
+                .iter()
+                .take_while(|(score, _)| score == best)
+                .map(|(_, m)| *m)
+                .collect::<Vec<&MonsterEntity>>();
+            bail!("`{}` matches several monsters: {}", key, names(&tied))
+        }
+    }
+}
+
+fn names(monsters: &[&MonsterEntity]) -> String {
+    monsters
+        .iter()
+        .map(|m| m.name.name.as_str())
+        .collect::<Vec<&str>>()
+        .join(", ")
+}
+
+/// Styles text with ANSI escapes, or leaves it plain.
+#[derive(Clone, Copy)]
+struct Paint<'a> {
+    ansi: bool,
+    theme: &'a Theme,
+}
+
+impl Paint<'_> {
+    fn fg(&self, text: &str, color: Color) -> String {
+        match self.ansi {
+            true => text.with(style::Color::from(color)).to_string(),
+            false => text.to_string(),
+        }
+    }
+
+    fn bold(&self, text: &str) -> String {
+        match self.ansi {
+            true => text.bold().to_string(),
+            false => text.to_string(),
+        }
+    }
+
+    fn heading(&self, text: &str) -> String {
+        match self.ansi {
+            true => text
+                .with(style::Color::from(self.theme.ui.highlight))
+                .bold()
+                .to_string(),
+            false => text.to_string(),
+        }
+    }
+}
+
+/// A table cell, colored when painted.
+struct Cell {
+    text: String,
+    color: Option<Color>,
+}
+
+impl Cell {
+    fn new(text: impl ToString) -> Self {
+        Self {
+            text: text.to_string(),
+            color: None,
+        }
+    }
+
+    fn colored(text: impl ToString, color: Color) -> Self {
+        Self {
+            text: text.to_string(),
+            color: Some(color),
+        }
+    }
+}
+
+fn write_profile(monster: &MonsterEntity, paint: Paint<'_>, w: &mut impl Write) -> io::Result<()> {
+    let info = &monster.basic_info;
+    let theme = paint.theme;
+    let ui = &theme.ui;
+
+    writeln!(
+        w,
+        "{} {} {}",
+        paint.fg(&paint.bold(&monster.name.name), ui.accent),
+        paint.fg("|", ui.dim),
+        paint.bold(&monster.name.aka)
+    )?;
+    writeln!(w)?;
+
+    let elements = monster
+        .elements
+        .iter()
+        .map(|e| paint.fg(&e.to_string(), e.color(theme)))
+        .collect::<Vec<String>>();
+    let status_effects = info
+        .status_effect
+        .iter()
+        .map(|s| paint.fg(&s.to_string(), s.color(theme)))
+        .collect::<Vec<String>>();
+    let weak_to = HITZONE_ELEMENTS
+        .iter()
+        .filter(|e| monster.weaknesses.is_weak_to(e))
+        .map(|e| paint.fg(&e.to_string(), e.color(theme)))
+        .collect::<Vec<String>>();
+
+    let basic_info = [
+        ("Type", info.m_type.clone()),
+        ("Elements", list(&elements)),
+        ("Roar", info.roar.to_string()),
+        ("Wind Pressure", info.wind_pressure.to_string()),
+        ("Tremor", info.tremor.to_string()),
+        ("Status Effects", list(&status_effects)),
+        ("Weak To", list(&weak_to)),
+    ];
+    for (label, value) in basic_info {
+        writeln!(
+            w,
+            "{} {}",
+            paint.fg(&format!("{:<15}", label), ui.muted),
+            value
+        )?;
+    }
+
+    section(w, paint, "Hitzones")?;
+    let mut header = vec![
+        Cell::new("Part"),
+        Cell::new("Cut"),
+        Cell::new("Blunt"),
+        Cell::new("Ammo"),
+    ];
+    header.extend(
+        HITZONE_ELEMENTS
+            .iter()
+            .map(|e| Cell::colored(e, e.color(theme))),
+    );
+    table(
+        w,
+        paint,
+        header,
+        monster.weaknesses.dmg_data.iter().map(|d| {
+            let mut row = vec![
+                Cell::new(&d.monster_part),
+                Cell::new(d.weapon.cut_damage),
+                Cell::new(d.weapon.blunt_damage),
+                Cell::new(d.weapon.ammo_damage),
+            ];
+            row.extend(HITZONE_ELEMENTS.iter().map(|e| Cell::new(d.element.get(e))));
+            row
+        }),
+    )?;
+
+    let ailment_data = &monster.weaknesses.ailment_data;
+    section(w, paint, "Ailments")?;
+    table(
+        w,
+        paint,
+        vec![Cell::new("Ailment"), Cell::new("Effectiveness")],
+        ailment_data.status.iter().map(|s| {
+            vec![
+                Cell::colored(&s.ailment, s.ailment.color(theme)),
+                Cell::new(s.eff),
+            ]
+        }),
+    )?;
+
+    section(w, paint, "Items")?;
+    table(
+        w,
+        paint,
+        vec![Cell::new("Item"), Cell::new("Effective")],
+        ailment_data.items.iter().map(|i| {
+            vec![
+                Cell::new(&i.item),
+                match i.is_effective {
+                    true => Cell::colored("Yes", ui.positive),
+                    false => Cell::colored("No", ui.negative),
+                },
+            ]
+        }),
+    )?;
+
+    section(w, paint, "Habitats")?;
+    table(
+        w,
+        paint,
+        vec![
+            Cell::new("Region"),
+            Cell::new("Starting Area"),
+            Cell::new("Visited Areas"),
+            Cell::new("Resting Area"),
+        ],
+        monster.habitats.iter().map(|h| {
+            vec![
+                Cell::new(&h.region),
+                Cell::new(join_areas(&h.starting_area)),
+                Cell::new(join_areas(&h.visited_area)),
+                Cell::new(h.resting_area),
+            ]
+        }),
+    )?;
+
+    section(w, paint, "Quests")?;
+    table(
+        w,
+        paint,
+        vec![Cell::new("Level"), Cell::new("Type"), Cell::new("Name")],
+        monster.quest_list.iter().map(|q| {
+            vec![
+                Cell::new(q.level),
+                Cell::new(q.quest_type),
+                Cell::new(&q.name),
+            ]
+        }),
+    )?;
+
+    section(w, paint, "Drops")?;
+    table(
+        w,
+        paint,
+        vec![
+            Cell::new("Rank"),
+            Cell::new("Source"),
+            Cell::new("Material"),
+            Cell::new("Part"),
+            Cell::new("Chance"),
+        ],
+        monster.drops.entries().into_iter().map(|e| {
+            vec![
+                Cell::new(e.rank),
+                Cell::new(e.source),
+                Cell::new(e.material),
+                Cell::new(e.part.unwrap_or("-")),
+                Cell::new(format!("{}%", e.percentage)),
+            ]
+        }),
+    )
+}
+
+fn section(w: &mut impl Write, paint: Paint<'_>, title: &str) -> io::Result<()> {
+    writeln!(w)?;
+    writeln!(w, "{}", paint.heading(title))
+}
+
+/// Columns padded to their widest cell, the header in bold.
+fn table(
+    w: &mut impl Write,
+    paint: Paint<'_>,
+    header: Vec<Cell>,
+    rows: impl IntoIterator<Item = Vec<Cell>>,
+) -> io::Result<()> {
+    let rows = rows.into_iter().collect::<Vec<Vec<Cell>>>();
+    if rows.is_empty() {
+        return writeln!(w, "{}", paint.fg("-", paint.theme.ui.dim));
+    }
+
+    let mut widths = header
+        .iter()
+        .map(|c| c.text.chars().count())
+        .collect::<Vec<usize>>();
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.text.chars().count());
+        }
+    }
+
+    let line = |row: &[Cell], bold: bool| {
+        let last = row.len().saturating_sub(1);
+        let cells = row
+            .iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(i, (cell, width))| {
+                let padded = match i == last {
+                    true => cell.text.clone(),
+                    false => format!("{:<width$}", cell.text, width = width),
+                };
+                let padded = match cell.color {
+                    Some(color) => paint.fg(&padded, color),
+                    None => padded,
+                };
+                match bold {
+                    true => paint.bold(&padded),
+                    false => padded,
+                }
+            })
+            .collect::<Vec<String>>();
+        cells.join("  ")
+    };
+
+    writeln!(w, "{}", line(&header, true))?;
+    for row in &rows {
+        writeln!(w, "{}", line(row, false))?;
+    }
+
+    Ok(())
+}
+
+fn list(items: &[String]) -> String {
+    match items.is_empty() {
+        true => String::from("-"),
+        false => items.join(", "),
+    }
+}
+
+fn join_areas(areas: &[u8]) -> String {
+    areas
+        .iter()
+        .map(|a| a.to_string())
+        .collect::<Vec<String>>()
+        .join(" ")
+}
@@ -0,0 +1,56 @@
+use std::io::{self, Write};
+
+use anyhow::{Context, Result};
+
+use crate::{
+    data::weak_to::{rank, Attack, RankBy, WeakToEntry},
+    monster::monster::MonsterEntity,
+};
+
+pub struct WeakToOptions {
+    pub attack: Attack,
+    pub by: RankBy,
+    pub limit: Option<usize>,
+}
+
+pub fn run(monsters: &[MonsterEntity], options: WeakToOptions) -> Result<()> {
+    let mut ranking = rank(monsters, options.attack, options.by);
+    if let Some(limit) = options.limit {
+        ranking.truncate(limit);
+    }
+
+    let mut stdout = io::stdout().lock();
+    match write_ranking(&ranking, &mut stdout) {
+        // `monsterex weak-to thunder | head` closes stdout early
+        Err(err) if err.kind() == io::ErrorKind::BrokenPipe => Ok(()),
+        written => written.context("cannot write to stdout"),
+    }
+}
+
+fn write_ranking(ranking: &[WeakToEntry], w: &mut impl Write) -> io::Result<()> {
+    let name_width = ranking
+        .iter()
+        .map(|entry| entry.name.chars().count())
+        .chain(["Monster".len()])
+        .max()
+        .unwrap_or_default();
+
+    writeln!(
+        w,
+        "{:>3}  {:<name_width$}  {:>4}  {:>7}  Weakest Part",
+        "#", "Monster", "Best", "Average"
+    )?;
+    for (i, entry) in ranking.iter().enumerate() {
+        writeln!(
+            w,
+            "{:>3}  {:<name_width$}  {:>4}  {:>7.1}  {}",
+            i + 1,
+            entry.name,
+            entry.best,
+            entry.average,
+            entry.weakest_part
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,233 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Display,
+};
+
+use crate::monster::{
+    icon::IconSource,
+    monster::{MaterialDropWithPart, MonsterEntity},
+};
+
+/// An integrity problem found in the monster dataset.
+#[derive(Debug, Clone)]
+pub enum DataProblem {
+    DuplicateId {
+        id: u16,
+        names: Vec<String>,
+    },
+    /// A name or alias shared by several monsters, which `show` cannot tell apart.
+    AmbiguousName {
+        key: String,
+        names: Vec<String>,
+    },
+    MissingIcon {
+        monster: String,
+        icon_code: String,
+    },
+    RestingAreaNotVisited {
+        monster: String,
+        region: String,
+        resting_area: u8,
+    },
+    UnknownBrokenPart {
+        monster: String,
+        rank: &'static str,
+        material: String,
+        part: String,
+    },
+    EmptyQuestList {
+        monster: String,
+    },
+    ConflictingQuestLevel {
+        quest: String,
+        levels: Vec<(u8, String)>,
+    },
+}
+
+impl Display for DataProblem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use DataProblem::*;
+        match self {
+            DuplicateId { id, names } => {
+                write!(f, "id {} is used by {}", id, names.join(", "))
+            }
+            AmbiguousName { key, names } => {
+                write!(f, "name `{}` is used by {}", key, names.join(", "))
+            }
+            MissingIcon { monster, icon_code } => {
+                write!(f, "{}: no icon file for icon_code `{}`", monster, icon_code)
+            }
+            RestingAreaNotVisited {
+                monster,
+                region,
+                resting_area,
+            } => write!(
+                f,
+                "{}: resting area {} in {} is not a visited area",
+                monster, resting_area, region
+            ),
+            UnknownBrokenPart {
+                monster,
+                rank,
+                material,
+                part,
+            } => write!(
+                f,
+                "{}: {} broken part drop `{}` names unknown part `{}`",
+                monster, rank, material, part
+            ),
+            EmptyQuestList { monster } => write!(f, "{}: quest list is empty", monster),
+            ConflictingQuestLevel { quest, levels } => {
+                let levels = levels
+                    .iter()
+                    .map(|(level, monster)| format!("{} ({})", level, monster))
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                write!(f, "quest `{}` has conflicting levels: {}", quest, levels)
+            }
+        }
+    }
+}
+
+/// Run every integrity check over the bundle. `icons` is where `icon_code`
+/// files are looked up.
+pub fn check_data(monsters: &[MonsterEntity], icons: &IconSource) -> Vec<DataProblem> {
+    let mut problems = vec![];
+
+    problems.extend(duplicate_ids(monsters));
+    problems.extend(ambiguous_names(monsters));
+
+    for monster in monsters {
+        let name = &monster.name.name;
+
+        if !icons.contains(&monster.icon_code) {
+            problems.push(DataProblem::MissingIcon {
+                monster: name.clone(),
+                icon_code: monster.icon_code.clone(),
+            });
+        }
+
+        problems.extend(
+            monster
+                .habitats
+                .iter()
+                .filter(|h| !h.visited_area.contains(&h.resting_area))
+                .map(|h| DataProblem::RestingAreaNotVisited {
+                    monster: name.clone(),
+                    region: h.region.clone(),
+                    resting_area: h.resting_area,
+                }),
+        );
+
+        let ranks = [
+            ("low rank", &monster.drops.low_rank.broken_part),
+            ("high rank", &monster.drops.high_rank.broken_part),
+        ];
+        for (rank, broken_part) in ranks {
+            problems.extend(unknown_broken_parts(monster, rank, broken_part));
+        }
+
+        if monster.quest_list.is_empty() {
+            problems.push(DataProblem::EmptyQuestList {
+                monster: name.clone(),
+            });
+        }
+    }
+
+    problems.extend(conflicting_quest_levels(monsters));
+
+    problems
+}
+
+fn duplicate_ids(monsters: &[MonsterEntity]) -> Vec<DataProblem> {
+    let mut by_id: BTreeMap<u16, Vec<String>> = BTreeMap::new();
+    for monster in monsters {
+        by_id
+            .entry(monster.id)
+            .or_default()
+            .push(monster.name.name.clone());
+    }
+
+    by_id
+        .into_iter()
+        .filter(|(_, names)| names.len() > 1)
+        .map(|(id, names)| DataProblem::DuplicateId { id, names })
+        .collect()
+}
+
+fn ambiguous_names(monsters: &[MonsterEntity]) -> Vec<DataProblem> {
+    let mut by_key: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for monster in monsters {
+        let mut keys = std::iter::once(&monster.name.name)
+            .chain(&monster.name.aliases)
+            .map(|key| key.trim().to_lowercase())
+            .collect::<Vec<String>>();
+        keys.sort();
+        keys.dedup();
+        for key in keys {
+            by_key
+                .entry(key)
+                .or_default()
+                .push(monster.name.name.clone());
+        }
+    }
+
+    by_key
+        .into_iter()
+        .filter(|(_, names)| names.len() > 1)
+        .map(|(key, names)| DataProblem::AmbiguousName { key, names })
+        .collect()
+}
+
+fn unknown_broken_parts(
+    monster: &MonsterEntity,
+    rank: &'static str,
+    broken_part: &[MaterialDropWithPart],
+) -> Vec<DataProblem> {
+    let is_known_part = |part: &str| {
+        monster
+            .weaknesses
+            .dmg_data
+            .iter()
+            .any(|d| d.monster_part == part)
+    };
+
+    broken_part
+        .iter()
+        .flat_map(|drop| {
+            drop.carve
+                .iter()
+                .filter(|c| !is_known_part(&c.part))
+                .map(|c| DataProblem::UnknownBrokenPart {
+                    monster: monster.name.name.clone(),
+                    rank,
+                    material: drop.material.clone(),
+                    part: c.part.clone(),
+                })
+        })
+        .collect()
+}
+
+fn conflicting_quest_levels(monsters: &[MonsterEntity]) -> Vec<DataProblem> {
+    let mut by_quest: HashMap<&str, Vec<(u8, String)>> = HashMap::new();
+    for monster in monsters {
+        for quest in &monster.quest_list {
+            by_quest
+                .entry(&quest.name)
+                .or_default()
+                .push((quest.level, monster.name.name.clone()));
+        }
+    }
+
+    let mut problems = by_quest
+        .into_iter()
+        .filter(|(_, levels)| levels.iter().any(|(l, _)| *l != levels[0].0))
+        .map(|(quest, levels)| DataProblem::ConflictingQuestLevel {
+            quest: quest.to_string(),
+            levels,
+        })
+        .collect::<Vec<DataProblem>>();
+
+    problems.sort_by_key(|p| p.to_string());
+    problems
+}
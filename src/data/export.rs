@@ -0,0 +1,384 @@
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::monster::monster::MonsterEntity;
+
+use super::notes::Notes;
+
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// The monster data format itself, loadable with `--data`
+    #[default]
+    Json,
+    /// One CSV file per table: hitzones, drops, quests, habitats and notes
+    Csv,
+    /// One section per monster, ready for a wiki page
+    Markdown,
+}
+
+/// A monster entry with the notes on it, still loadable as monster data.
+#[derive(Serialize)]
+struct NotedMonster<'a> {
+    #[serde(flatten)]
+    monster: &'a MonsterEntity,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    notes: Option<&'a str>,
+}
+
+pub fn export_json(
+    monsters: &[&MonsterEntity],
+    notes: &Notes,
+    writer: &mut impl Write,
+) -> io::Result<()> {
+    let monsters: Vec<NotedMonster> = monsters
+        .iter()
+        .map(|monster| NotedMonster {
+            monster,
+            notes: notes.get(monster.id),
+        })
+        .collect();
+    serde_json::to_writer_pretty(&mut *writer, &monsters)?;
+    writeln!(writer)
+}
+
+/// Write `hitzones.csv`, `drops.csv`, `quests.csv`, `habitats.csv` and `notes.csv` into `dir`.
+pub fn export_csv(monsters: &[&MonsterEntity], notes: &Notes, dir: &Path) -> io::Result<()> {
+    std::fs::create_dir_all(dir)?;
+
+    let mut hitzones = CsvWriter::create(
+        &dir.join("hitzones.csv"),
+        &[
+            "monster_id",
+            "monster",
+            "part",
+            "cut",
+            "blunt",
+            "ammo",
+            "fire",
+            "water",
+            "thunder",
+            "ice",
+            "dragon",
+        ],
+    )?;
+    let mut drops = CsvWriter::create(
+        &dir.join("drops.csv"),
+        &[
+            "monster_id",
+            "monster",
+            "rank",
+            "source",
+            "material",
+            "part",
+            "percentage",
+        ],
+    )?;
+    let mut quests = CsvWriter::create(
+        &dir.join("quests.csv"),
+        &["monster_id", "monster", "quest_type", "level", "name"],
+    )?;
+    let mut habitats = CsvWriter::create(
+        &dir.join("habitats.csv"),
+        &[
+            "monster_id",
+            "monster",
+            "region",
+            "starting_area",
+            "visited_area",
+            "resting_area",
+        ],
+    )?;
+    let mut monster_notes =
+        CsvWriter::create(&dir.join("notes.csv"), &["monster_id", "monster", "notes"])?;
+
+    for monster in monsters {
+        let id = monster.id.to_string();
+        let name = monster.name.name.as_str();
+
+        for dmg in &monster.weaknesses.dmg_data {
+            hitzones.row(&[
+                &id,
+                name,
+                &dmg.monster_part,
+                &dmg.weapon.cut_damage.to_string(),
+                &dmg.weapon.blunt_damage.to_string(),
+                &dmg.weapon.ammo_damage.to_string(),
+                &dmg.element.fire_damage.to_string(),
+                &dmg.element.water_damage.to_string(),
+                &dmg.element.thunder_damage.to_string(),
+                &dmg.element.ice_damage.to_string(),
+                &dmg.element.dragon_damage.to_string(),
+            ])?;
+        }
+
+        for entry in monster.drops.entries() {
+            drops.row(&[
+                &id,
+                name,
+                &entry.rank.to_string(),
+                &entry.source.to_string(),
+                entry.material,
+                entry.part.unwrap_or_default(),
+                &entry.percentage.to_string(),
+            ])?;
+        }
+
+        for quest in &monster.quest_list {
+            quests.row(&[
+                &id,
+                name,
+                &quest.quest_type.to_string(),
+                &quest.level.to_string(),
+                &quest.name,
+            ])?;
+        }
+
+        for habitat in &monster.habitats {
+            habitats.row(&[
+                &id,
+                name,
+                &habitat.region,
+                &join_areas(&habitat.starting_area),
+                &join_areas(&habitat.visited_area),
+                &habitat.resting_area.to_string(),
+            ])?;
+        }
+
+        if let Some(text) = notes.get(monster.id) {
+            monster_notes.row(&[&id, name, text])?;
+        }
+    }
+
+    hitzones.finish()?;
+    drops.finish()?;
+    quests.finish()?;
+    habitats.finish()?;
+    monster_notes.finish()
+}
+
+pub fn export_markdown(
+    monsters: &[&MonsterEntity],
+    notes: &Notes,
+    writer: &mut impl Write,
+) -> io::Result<()> {
+    for (i, monster) in monsters.iter().enumerate() {
+        if i > 0 {
+            writeln!(writer)?;
+        }
+        write_monster_markdown(monster, notes.get(monster.id), writer)?;
+    }
+
+    Ok(())
+}
+
+fn write_monster_markdown(
+    monster: &MonsterEntity,
+    notes: Option<&str>,
+    w: &mut impl Write,
+) -> io::Result<()> {
+    let info = &monster.basic_info;
+
+    writeln!(w, "# {}", monster.name.name)?;
+    writeln!(w)?;
+    writeln!(w, "*{}* — {}", monster.name.aka, info.m_type)?;
+    writeln!(w)?;
+    writeln!(w, "{}", monster.desc.original)?;
+    writeln!(w)?;
+
+    let elements = monster
+        .elements
+        .iter()
+        .map(|e| e.to_string())
+        .collect::<Vec<String>>()
+        .join(", ");
+    let status_effect = info
+        .status_effect
+        .iter()
+        .map(|s| s.to_string())
+        .collect::<Vec<String>>()
+        .join(", ");
+
+    markdown_table(
+        w,
+        &[
+            "Element",
+            "Roar",
+            "Wind Pressure",
+            "Tremor",
+            "Status Effect",
+        ],
+        [vec![
+            elements,
+            info.roar.to_string(),
+            info.wind_pressure.to_string(),
+            info.tremor.to_string(),
+            status_effect,
+        ]],
+    )?;
+
+    writeln!(w, "## Hitzones")?;
+    writeln!(w)?;
+    markdown_table(
+        w,
+        &[
+            "Part", "Cut", "Blunt", "Ammo", "Fire", "Water", "Thunder", "Ice", "Dragon",
+        ],
+        monster.weaknesses.dmg_data.iter().map(|d| {
+            vec![
+                d.monster_part.clone(),
+                d.weapon.cut_damage.to_string(),
+                d.weapon.blunt_damage.to_string(),
+                d.weapon.ammo_damage.to_string(),
+                d.element.fire_damage.to_string(),
+                d.element.water_damage.to_string(),
+                d.element.thunder_damage.to_string(),
+                d.element.ice_damage.to_string(),
+                d.element.dragon_damage.to_string(),
+            ]
+        }),
+    )?;
+
+    let ailment_data = &monster.weaknesses.ailment_data;
+    writeln!(w, "## Ailments")?;
+    writeln!(w)?;
+    markdown_table(
+        w,
+        &["Ailment", "Effectiveness"],
+        ailment_data
+            .status
+            .iter()
+            .map(|s| vec![s.ailment.to_string(), s.eff.to_string()]),
+    )?;
+
+    writeln!(w, "## Items")?;
+    writeln!(w)?;
+    markdown_table(
+        w,
+        &["Item", "Effective"],
+        ailment_data.items.iter().map(|i| {
+            vec![
+                i.item.to_string(),
+                String::from(if i.is_effective { "Yes" } else { "No" }),
+            ]
+        }),
+    )?;
+
+    writeln!(w, "## Habitats")?;
+    writeln!(w)?;
+    markdown_table(
+        w,
+        &["Region", "Starting Area", "Visited Areas", "Resting Area"],
+        monster.habitats.iter().map(|h| {
+            vec![
+                h.region.clone(),
+                join_areas(&h.starting_area),
+                join_areas(&h.visited_area),
+                h.resting_area.to_string(),
+            ]
+        }),
+    )?;
+
+    writeln!(w, "## Quests")?;
+    writeln!(w)?;
+    markdown_table(
+        w,
+        &["Type", "Level", "Name"],
+        monster.quest_list.iter().map(|q| {
+            vec![
+                q.quest_type.to_string(),
+                q.level.to_string(),
+                q.name.clone(),
+            ]
+        }),
+    )?;
+
+    writeln!(w, "## Drops")?;
+    writeln!(w)?;
+    markdown_table(
+        w,
+        &["Rank", "Source", "Material", "Part", "Chance"],
+        monster.drops.entries().into_iter().map(|e| {
+            vec![
+                e.rank.to_string(),
+                e.source.to_string(),
+                e.material.to_string(),
+                e.part.unwrap_or_default().to_string(),
+                format!("{}%", e.percentage),
+            ]
+        }),
+    )?;
+
+    if let Some(notes) = notes {
+        writeln!(w, "## Notes")?;
+        writeln!(w)?;
+        writeln!(w, "{}", notes)?;
+        writeln!(w)?;
+    }
+
+    Ok(())
+}
+
+fn markdown_table(
+    w: &mut impl Write,
+    header: &[&str],
+    rows: impl IntoIterator<Item = Vec<String>>,
+) -> io::Result<()> {
+    let escape = |cell: &str| cell.replace('|', "\\|");
+
+    writeln!(w, "| {} |", header.join(" | "))?;
+    writeln!(w, "|{}", " --- |".repeat(header.len()))?;
+    for row in rows {
+        let row = row.iter().map(|c| escape(c)).collect::<Vec<String>>();
+        writeln!(w, "| {} |", row.join(" | "))?;
+    }
+    writeln!(w)
+}
+
+fn join_areas(areas: &[u8]) -> String {
+    areas
+        .iter()
+        .map(|a| a.to_string())
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+struct CsvWriter {
+    writer: BufWriter<File>,
+}
+
+impl CsvWriter {
+    fn create(path: &Path, header: &[&str]) -> io::Result<Self> {
+        let mut csv = Self {
+            writer: BufWriter::new(File::create(path)?),
+        };
+        csv.row(header)?;
+        Ok(csv)
+    }
+
+    fn row(&mut self, fields: &[&str]) -> io::Result<()> {
+        let line = fields
+            .iter()
+            .map(|f| csv_field(f))
+            .collect::<Vec<String>>()
+            .join(",");
+        writeln!(self.writer, "{}", line)
+    }
+
+    fn finish(mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
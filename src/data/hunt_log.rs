@@ -0,0 +1,208 @@
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::monster::monster::DropRank;
+
+use super::{
+    app_data_dir,
+    store::{load_json, save_json, StoreError},
+};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Outcome {
+    #[default]
+    Slay,
+    Capture,
+}
+
+impl Outcome {
+    pub fn toggle(self) -> Self {
+        match self {
+            Outcome::Slay => Outcome::Capture,
+            Outcome::Capture => Outcome::Slay,
+        }
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Slay => write!(f, "Slay"),
+            Outcome::Capture => write!(f, "Capture"),
+        }
+    }
+}
+
+/// One finished hunt.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Hunt {
+    pub monster_id: u16,
+    pub rank: DropRank,
+    pub outcome: Outcome,
+    /// Clear time in seconds, `None` when the hunt was not timed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time: Option<u64>,
+    /// Seconds since the Unix epoch when the hunt was logged.
+    pub logged_at: u64,
+}
+
+impl Hunt {
+    pub fn new(monster_id: u16, rank: DropRank, outcome: Outcome, time: Option<u64>) -> Self {
+        Self {
+            monster_id,
+            rank,
+            outcome,
+            time,
+            logged_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |since| since.as_secs()),
+        }
+    }
+}
+
+/// Hunts on one monster at one rank.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RankRecord {
+    pub slays: u32,
+    pub captures: u32,
+    /// Fastest timed clear in seconds.
+    pub best_time: Option<u64>,
+}
+
+impl RankRecord {
+    pub fn hunts(&self) -> u32 {
+        self.slays + self.captures
+    }
+
+    fn add(&mut self, hunt: &Hunt) {
+        match hunt.outcome {
+            Outcome::Slay => self.slays += 1,
+            Outcome::Capture => self.captures += 1,
+        }
+        self.best_time = match (self.best_time, hunt.time) {
+            (Some(best), Some(time)) => Some(best.min(time)),
+            (best, time) => best.or(time),
+        };
+    }
+}
+
+/// Low and High Rank records of one monster.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MonsterRecord {
+    pub monster_id: u16,
+    pub low: RankRecord,
+    pub high: RankRecord,
+}
+
+impl MonsterRecord {
+    pub fn rank(&self, rank: DropRank) -> &RankRecord {
+        match rank {
+            DropRank::Low => &self.low,
+            DropRank::High => &self.high,
+        }
+    }
+
+    pub fn hunts(&self) -> u32 {
+        self.low.hunts() + self.high.hunts()
+    }
+
+    fn add(&mut self, hunt: &Hunt) {
+        match hunt.rank {
+            DropRank::Low => self.low.add(hunt),
+            DropRank::High => self.high.add(hunt),
+        }
+    }
+}
+
+/// Every logged hunt, the oldest first.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct HuntLog {
+    #[serde(default)]
+    pub hunts: Vec<Hunt>,
+}
+
+impl HuntLog {
+    /// Read the log at `path`, empty when there is no file yet.
+    pub fn load(path: &Path) -> Result<Self, StoreError> {
+        load_json("hunt log", path)
+    }
+
+    /// Write the log to `path`, creating its directory.
+    pub fn save(&self, path: &Path) -> Result<(), StoreError> {
+        save_json("hunt log", self, path)
+    }
+
+    pub fn record(&mut self, hunt: Hunt) {
+        self.hunts.push(hunt);
+    }
+
+    /// Record of monster `id`, all zero when it was never hunted.
+    pub fn monster_record(&self, id: u16) -> MonsterRecord {
+        let mut record = MonsterRecord {
+            monster_id: id,
+            ..Default::default()
+        };
+        self.hunts
+            .iter()
+            .filter(|hunt| hunt.monster_id == id)
+            .for_each(|hunt| record.add(hunt));
+        record
+    }
+
+    /// Records of every hunted monster, the most hunted first.
+    pub fn monster_records(&self) -> Vec<MonsterRecord> {
+        let mut records: HashMap<u16, MonsterRecord> = HashMap::new();
+        for hunt in &self.hunts {
+            records
+                .entry(hunt.monster_id)
+                .or_insert(MonsterRecord {
+                    monster_id: hunt.monster_id,
+                    ..Default::default()
+                })
+                .add(hunt);
+        }
+
+        let mut records: Vec<MonsterRecord> = records.into_values().collect();
+        records.sort_by(|a, b| {
+            b.hunts()
+                .cmp(&a.hunts())
+                .then(a.monster_id.cmp(&b.monster_id))
+        });
+        records
+    }
+
+    /// The fastest timed hunt of all.
+    pub fn fastest(&self) -> Option<&Hunt> {
+        self.hunts
+            .iter()
+            .filter(|hunt| hunt.time.is_some())
+            .min_by_key(|hunt| hunt.time)
+    }
+
+    /// Time spent on timed hunts, in seconds.
+    pub fn total_time(&self) -> u64 {
+        self.hunts.iter().filter_map(|hunt| hunt.time).sum()
+    }
+}
+
+/// `mm:ss`, or `h:mm:ss` past an hour.
+pub fn format_time(secs: u64) -> String {
+    let (hours, minutes, seconds) = (secs / 3600, secs / 60 % 60, secs % 60);
+    match hours {
+        0 => format!("{:02}:{:02}", minutes, seconds),
+        hours => format!("{}:{:02}:{:02}", hours, minutes, seconds),
+    }
+}
+
+/// `$XDG_DATA_HOME/monsterex/hunts.json` (or the platform equivalent).
+pub fn default_hunt_log_path() -> Option<PathBuf> {
+    app_data_dir().map(|dir| dir.join("hunts.json"))
+}
@@ -0,0 +1,86 @@
+use std::{collections::BTreeMap, rc::Rc};
+
+use crate::monster::monster::{DropRank, DropSource, MonsterEntity};
+
+/// One way to obtain a material from a monster.
+#[derive(Debug, Clone)]
+pub struct MaterialSource {
+    pub monster_id: u16,
+    pub monster: String,
+    pub rank: DropRank,
+    pub source: DropSource,
+    /// Row of the material in the monster's drop table for `rank` and `source`.
+    pub row: usize,
+    pub part: Option<String>,
+    pub percentage: u8,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct MaterialEntry {
+    pub material: String,
+    /// Sorted by best chance first.
+    pub sources: Vec<MaterialSource>,
+}
+
+impl MaterialEntry {
+    pub fn best_percentage(&self) -> u8 {
+        self.sources.first().map_or(0, |s| s.percentage)
+    }
+}
+
+/// Reverse index of `MonsterDrops`: every material and where it drops.
+#[derive(Debug, Clone, Default)]
+pub struct MaterialIndex {
+    /// Sorted by material name.
+    pub materials: Vec<Rc<MaterialEntry>>,
+}
+
+impl MaterialIndex {
+    pub fn build<'a>(monsters: impl IntoIterator<Item = &'a MonsterEntity>) -> Self {
+        let mut by_material: BTreeMap<&str, Vec<MaterialSource>> = BTreeMap::new();
+
+        for monster in monsters {
+            for entry in monster.drops.entries() {
+                by_material
+                    .entry(entry.material)
+                    .or_default()
+                    .push(MaterialSource {
+                        monster_id: monster.id,
+                        monster: monster.name.name.clone(),
+                        rank: entry.rank,
+                        source: entry.source,
+                        row: entry.row,
+                        part: entry.part.map(str::to_string),
+                        percentage: entry.percentage,
+                    });
+            }
+        }
+
+        let materials = by_material
+            .into_iter()
+            .map(|(material, mut sources)| {
+                sources.sort_by(|a, b| {
+                    b.percentage
+                        .cmp(&a.percentage)
+                        .then_with(|| a.monster.cmp(&b.monster))
+                        .then_with(|| a.rank.cmp(&b.rank))
+                        .then_with(|| a.source.cmp(&b.source))
+                });
+
+                Rc::new(MaterialEntry {
+                    material: material.to_string(),
+                    sources,
+                })
+            })
+            .collect();
+
+        Self { materials }
+    }
+
+    pub fn find(&self, material: &str) -> Option<&Rc<MaterialEntry>> {
+        self.materials
+            .binary_search_by(|entry| entry.material.as_str().cmp(material))
+            .ok()
+            .map(|idx| &self.materials[idx])
+    }
+}
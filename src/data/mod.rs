@@ -0,0 +1,252 @@
+pub mod check;
+pub mod export;
+pub mod hunt_log;
+pub mod material_index;
+pub mod notes;
+pub mod quest_index;
+pub mod store;
+pub mod validate;
+pub mod weak_to;
+
+use std::{
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde_json::Value;
+use validate::{validate, ValidationIssue, ValidationMode};
+
+use crate::{armor::ArmorEntity, monster::monster::MonsterEntity, weapon::WeaponEntity};
+
+/// Environment variable pointing at a monster data file or directory.
+pub const DATA_ENV: &str = "MONSTEREX_DATA";
+
+/// Weapon data file looked up next to the monster data.
+pub const WEAPON_FILE: &str = "weapon.json";
+
+/// Armor data file looked up next to the monster data.
+pub const ARMOR_FILE: &str = "armor.json";
+
+const EMBEDDED_MONSTER_DATA: &str = include_str!("../../data/monster.json");
+const EMBEDDED_WEAPON_DATA: &str = include_str!("../../data/weapon.json");
+const EMBEDDED_ARMOR_DATA: &str = include_str!("../../data/armor.json");
+
+/// Where the monster bundle is loaded from.
+#[derive(Debug, Clone)]
+pub enum DataSource {
+    /// A single JSON file holding every monster, or a directory of per-monster JSON files.
+    Path(PathBuf),
+    /// The `data/monster.json` copy baked into the binary.
+    Embedded,
+}
+
+impl DataSource {
+    /// Pick the data source in order: the `--data` argument, `$MONSTEREX_DATA`,
+    /// the XDG data dir and finally the embedded copy.
+    pub fn resolve(cli_path: Option<PathBuf>) -> Self {
+        if let Some(path) = cli_path {
+            return DataSource::Path(path);
+        }
+
+        if let Some(path) = std::env::var_os(DATA_ENV).filter(|p| !p.is_empty()) {
+            return DataSource::Path(PathBuf::from(path));
+        }
+
+        if let Some(dir) = app_data_dir() {
+            let candidates = [dir.join("monster.json"), dir.join("monster")];
+            if let Some(path) = candidates.into_iter().find(|p| p.exists()) {
+                return DataSource::Path(path);
+            }
+        }
+
+        DataSource::Embedded
+    }
+
+    pub fn load(&self, mode: ValidationMode) -> Result<MonsterData, DataError> {
+        let mut issues = vec![];
+        let monsters = match self {
+            DataSource::Embedded => {
+                parse_monsters(EMBEDDED_MONSTER_DATA, "embedded data", &mut issues)?
+            }
+            DataSource::Path(path) if path.is_dir() => load_dir(path, &mut issues)?,
+            DataSource::Path(path) => load_file(path, &mut issues)?,
+        };
+
+        if monsters.is_empty() {
+            return Err(DataError::Empty(self.to_string()));
+        }
+
+        if mode == ValidationMode::Strict && !issues.is_empty() {
+            return Err(DataError::Invalid(issues));
+        }
+
+        let weapons = match self.sibling(WEAPON_FILE) {
+            Some(path) => parse_list(&read_to_string(&path)?, &path.display().to_string())?,
+            None => parse_list(EMBEDDED_WEAPON_DATA, "embedded weapon data")?,
+        };
+
+        let armor = match self.sibling(ARMOR_FILE) {
+            Some(path) => parse_list(&read_to_string(&path)?, &path.display().to_string())?,
+            None => parse_list(EMBEDDED_ARMOR_DATA, "embedded armor data")?,
+        };
+
+        Ok(MonsterData {
+            monsters,
+            weapons,
+            armor,
+            warnings: issues,
+        })
+    }
+
+    /// `file` next to the monster data file or directory, if it exists.
+    /// Without one the embedded copy is used.
+    fn sibling(&self, file: &str) -> Option<PathBuf> {
+        match self {
+            DataSource::Path(path) => path
+                .parent()
+                .map(|dir| dir.join(file))
+                .filter(|p| p.is_file()),
+            DataSource::Embedded => None,
+        }
+    }
+}
+
+impl Display for DataSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DataSource::Path(path) => write!(f, "{}", path.display()),
+            DataSource::Embedded => write!(f, "embedded data"),
+        }
+    }
+}
+
+pub struct MonsterData {
+    pub monsters: Vec<MonsterEntity>,
+    pub weapons: Vec<WeaponEntity>,
+    pub armor: Vec<ArmorEntity>,
+    /// Unknown values the lenient mode replaced with a fallback.
+    pub warnings: Vec<ValidationIssue>,
+}
+
+#[derive(Debug)]
+pub enum DataError {
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+    Parse {
+        origin: String,
+        source: serde_json::Error,
+    },
+    Empty(String),
+    Invalid(Vec<ValidationIssue>),
+}
+
+impl Display for DataError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DataError::Io { path, source } => {
+                write!(f, "cannot read data `{}`: {}", path.display(), source)
+            }
+            DataError::Parse { origin, source } => {
+                write!(f, "invalid data in {}: {}", origin, source)
+            }
+            DataError::Empty(origin) => write!(f, "no monster found in {}", origin),
+            DataError::Invalid(issues) => {
+                write!(f, "{} invalid value(s) in monster data", issues.len())?;
+                issues
+                    .iter()
+                    .try_for_each(|issue| write!(f, "\n  {}", issue))
+            }
+        }
+    }
+}
+
+impl std::error::Error for DataError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DataError::Io { source, .. } => Some(source),
+            DataError::Parse { source, .. } => Some(source),
+            DataError::Empty(_) | DataError::Invalid(_) => None,
+        }
+    }
+}
+
+/// `$XDG_DATA_HOME/monsterex` (or the platform equivalent).
+pub fn app_data_dir() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join("monsterex"))
+}
+
+fn read_to_string(path: &Path) -> Result<String, DataError> {
+    fs::read_to_string(path).map_err(|source| DataError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+fn load_file(
+    path: &Path,
+    issues: &mut Vec<ValidationIssue>,
+) -> Result<Vec<MonsterEntity>, DataError> {
+    let content = read_to_string(path)?;
+    parse_monsters(&content, &path.display().to_string(), issues)
+}
+
+fn load_dir(
+    path: &Path,
+    issues: &mut Vec<ValidationIssue>,
+) -> Result<Vec<MonsterEntity>, DataError> {
+    let entries = fs::read_dir(path).map_err(|source| DataError::Io {
+        path: path.to_path_buf(),
+        source,
+    })?;
+
+    let mut files = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|p| p.is_file() && p.extension().is_some_and(|ext| ext == "json"))
+        .collect::<Vec<PathBuf>>();
+    files.sort();
+
+    let mut monsters = vec![];
+    for file in files {
+        monsters.extend(load_file(&file, issues)?);
+    }
+
+    monsters.sort_by_key(|m| m.id);
+    Ok(monsters)
+}
+
+/// A document is either an array of monsters or a single monster object.
+fn parse_monsters(
+    content: &str,
+    origin: &str,
+    issues: &mut Vec<ValidationIssue>,
+) -> Result<Vec<MonsterEntity>, DataError> {
+    let to_error = |source| DataError::Parse {
+        origin: origin.to_string(),
+        source,
+    };
+
+    let document: Value = serde_json::from_str(content).map_err(to_error)?;
+    issues.extend(validate(&document, origin));
+
+    if document.is_array() {
+        // deserialize from the text, not the `Value`, to keep line/column in errors
+        serde_json::from_str(content).map_err(to_error)
+    } else {
+        serde_json::from_str(content)
+            .map(|m| vec![m])
+            .map_err(to_error)
+    }
+}
+
+fn parse_list<T: serde::de::DeserializeOwned>(
+    content: &str,
+    origin: &str,
+) -> Result<Vec<T>, DataError> {
+    serde_json::from_str(content).map_err(|source| DataError::Parse {
+        origin: origin.to_string(),
+        source,
+    })
+}
@@ -0,0 +1,47 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use super::{
+    app_data_dir,
+    store::{load_json, save_json, StoreError},
+};
+
+/// Personal hunt notes by monster id.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Notes(BTreeMap<u16, String>);
+
+impl Notes {
+    /// Read the notes at `path`, none when there is no file yet.
+    pub fn load(path: &Path) -> Result<Self, StoreError> {
+        load_json("notes", path)
+    }
+
+    /// Write the notes to `path`, creating its directory.
+    pub fn save(&self, path: &Path) -> Result<(), StoreError> {
+        save_json("notes", self, path)
+    }
+
+    pub fn get(&self, id: u16) -> Option<&str> {
+        self.0.get(&id).map(String::as_str)
+    }
+
+    /// Replace the note of monster `id`, a blank `text` removes it.
+    pub fn set(&mut self, id: u16, text: &str) {
+        let text = text.trim_end();
+        if text.trim().is_empty() {
+            self.0.remove(&id);
+        } else {
+            self.0.insert(id, text.to_string());
+        }
+    }
+}
+
+/// `$XDG_DATA_HOME/monsterex/notes.json` (or the platform equivalent).
+pub fn default_notes_path() -> Option<PathBuf> {
+    app_data_dir().map(|dir| dir.join("notes.json"))
+}
@@ -0,0 +1,65 @@
+use std::{collections::BTreeMap, rc::Rc};
+
+use crate::monster::monster::{MonsterEntity, QuestType};
+
+/// A monster that appears in a quest.
+#[derive(Clone)]
+pub struct QuestTarget {
+    pub monster: Rc<MonsterEntity>,
+    /// Row of the quest in the monster's `quest_list`.
+    pub row: usize,
+}
+
+#[derive(Clone, Default)]
+pub struct QuestEntry {
+    pub name: String,
+    pub quest_type: QuestType,
+    pub level: u8,
+    pub targets: Vec<QuestTarget>,
+}
+
+/// Every quest found in the monsters' `quest_list`, merged by name.
+///
+/// Type and level come from the first monster listing the quest, `check-data`
+/// reports quests whose monsters disagree on the level.
+#[derive(Clone, Default)]
+pub struct QuestIndex {
+    /// Sorted by level, then name.
+    pub quests: Vec<Rc<QuestEntry>>,
+}
+
+impl QuestIndex {
+    pub fn build(monsters: &[Rc<MonsterEntity>]) -> Self {
+        let mut by_name: BTreeMap<&str, QuestEntry> = BTreeMap::new();
+
+        for monster in monsters {
+            for (row, quest) in monster.quest_list.iter().enumerate() {
+                by_name
+                    .entry(quest.name.as_str())
+                    .or_insert_with(|| QuestEntry {
+                        name: quest.name.clone(),
+                        quest_type: quest.quest_type,
+                        level: quest.level,
+                        targets: Vec::new(),
+                    })
+                    .targets
+                    .push(QuestTarget {
+                        monster: Rc::clone(monster),
+                        row,
+                    });
+            }
+        }
+
+        let mut quests: Vec<Rc<QuestEntry>> = by_name.into_values().map(Rc::new).collect();
+        quests.sort_by_key(|q| q.level);
+
+        Self { quests }
+    }
+
+    /// Star levels used by at least one quest, ascending.
+    pub fn levels(&self) -> Vec<u8> {
+        let mut levels: Vec<u8> = self.quests.iter().map(|q| q.level).collect();
+        levels.dedup();
+        levels
+    }
+}
@@ -0,0 +1,97 @@
+use std::{
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Read the JSON file at `path`, the default value when there is no file yet. `what`
+/// names the file in errors, like "state".
+pub fn load_json<T: DeserializeOwned + Default>(
+    what: &'static str,
+    path: &Path,
+) -> Result<T, StoreError> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(T::default()),
+        Err(source) => {
+            return Err(StoreError::Io {
+                what,
+                path: path.to_path_buf(),
+                source,
+            })
+        }
+    };
+
+    serde_json::from_str(&content).map_err(|source| StoreError::Parse {
+        what,
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// Write `value` as JSON to `path`, creating its directory.
+pub fn save_json<T: Serialize>(
+    what: &'static str,
+    value: &T,
+    path: &Path,
+) -> Result<(), StoreError> {
+    let write = |path: &Path| -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(value)?)
+    };
+
+    write(path).map_err(|source| StoreError::Write {
+        what,
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// A user file, like the saved state, that cannot be read or saved.
+#[derive(Debug)]
+pub enum StoreError {
+    Io {
+        what: &'static str,
+        path: PathBuf,
+        source: io::Error,
+    },
+    Write {
+        what: &'static str,
+        path: PathBuf,
+        source: io::Error,
+    },
+    Parse {
+        what: &'static str,
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+}
+
+impl Display for StoreError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StoreError::Io { what, path, source } => {
+                write!(f, "cannot read {} {}: {}", what, path.display(), source)
+            }
+            StoreError::Write { what, path, source } => {
+                write!(f, "cannot save {} {}: {}", what, path.display(), source)
+            }
+            StoreError::Parse { what, path, source } => {
+                write!(f, "invalid {} {}: {}", what, path.display(), source)
+            }
+        }
+    }
+}
+
+impl std::error::Error for StoreError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            StoreError::Io { source, .. } | StoreError::Write { source, .. } => Some(source),
+            StoreError::Parse { source, .. } => Some(source),
+        }
+    }
+}
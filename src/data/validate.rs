@@ -0,0 +1,198 @@
+use std::fmt::Display;
+
+use serde_json::Value;
+
+use crate::monster::monster::{
+    ItemWeakness, MonsterElements, MonsterStatusEffects, QuestType, Roarskind, StatusAilments,
+    TremorKind, WindPressureKind,
+};
+
+/// How unknown enum strings in the monster data are handled.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ValidationMode {
+    /// Reject the data and report every unknown value.
+    Strict,
+    /// Fall back to the visitor default and report every unknown value as a warning.
+    #[default]
+    Lenient,
+}
+
+/// An enum string in the data that no variant accepts.
+#[derive(Debug, Clone)]
+pub struct ValidationIssue {
+    pub origin: String,
+    pub monster_id: Option<u64>,
+    pub path: String,
+    pub kind: &'static str,
+    pub value: String,
+}
+
+impl Display for ValidationIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {} ", self.origin, self.path)?;
+        if let Some(id) = self.monster_id {
+            write!(f, "(monster id {}) ", id)?;
+        }
+        write!(f, "has unknown {} `{}`", self.kind, self.value)
+    }
+}
+
+/// Walk a parsed document (an array of monsters or a single monster) and
+/// collect every enum string the deserializer would have to guess.
+pub fn validate(document: &Value, origin: &str) -> Vec<ValidationIssue> {
+    let mut validator = Validator {
+        origin,
+        issues: vec![],
+    };
+
+    match document {
+        Value::Array(monsters) => monsters
+            .iter()
+            .enumerate()
+            .for_each(|(i, m)| validator.monster(m, format!("$[{}]", i))),
+        monster => validator.monster(monster, String::from("$")),
+    }
+
+    validator.issues
+}
+
+struct Validator<'a> {
+    origin: &'a str,
+    issues: Vec<ValidationIssue>,
+}
+
+impl Validator<'_> {
+    fn monster(&mut self, monster: &Value, path: String) {
+        let id = monster.get("id").and_then(Value::as_u64);
+
+        self.each(id, monster, &path, "elements", "element", |v| {
+            MonsterElements::from_key(v).is_some()
+        });
+
+        let basic_info_path = format!("{}.basic_info", path);
+        if let Some(basic_info) = monster.get("basic_info") {
+            self.field(id, basic_info, &basic_info_path, "roar", "roar kind", |v| {
+                Roarskind::from_key(v).is_some()
+            });
+            self.field(
+                id,
+                basic_info,
+                &basic_info_path,
+                "wind_pressure",
+                "wind pressure kind",
+                |v| WindPressureKind::from_key(v).is_some(),
+            );
+            self.field(
+                id,
+                basic_info,
+                &basic_info_path,
+                "tremor",
+                "tremor kind",
+                |v| TremorKind::from_key(v).is_some(),
+            );
+            self.each(
+                id,
+                basic_info,
+                &basic_info_path,
+                "status_effect",
+                "status effect",
+                |v| MonsterStatusEffects::from_key(v).is_some(),
+            );
+        }
+
+        for (i, quest) in array(monster, "quest_list").iter().enumerate() {
+            let quest_path = format!("{}.quest_list[{}]", path, i);
+            self.field(id, quest, &quest_path, "quest_type", "quest type", |v| {
+                QuestType::from_key(v).is_some()
+            });
+        }
+
+        let Some(ailment_data) = monster.pointer("/weaknesses/ailment_data") else {
+            return;
+        };
+        let ailment_path = format!("{}.weaknesses.ailment_data", path);
+
+        for (i, status) in array(ailment_data, "status").iter().enumerate() {
+            let status_path = format!("{}.status[{}]", ailment_path, i);
+            self.field(id, status, &status_path, "ailment", "status ailment", |v| {
+                StatusAilments::from_key(v).is_some()
+            });
+        }
+
+        for (i, item) in array(ailment_data, "items").iter().enumerate() {
+            let item_path = format!("{}.items[{}]", ailment_path, i);
+            self.field(id, item, &item_path, "item", "item weakness", |v| {
+                ItemWeakness::from_key(v).is_some()
+            });
+        }
+    }
+
+    fn field(
+        &mut self,
+        id: Option<u64>,
+        parent: &Value,
+        parent_path: &str,
+        key: &str,
+        kind: &'static str,
+        is_known: impl Fn(&str) -> bool,
+    ) {
+        if let Some(value) = parent.get(key).and_then(Value::as_str) {
+            self.check(
+                id,
+                format!("{}.{}", parent_path, key),
+                kind,
+                value,
+                &is_known,
+            );
+        }
+    }
+
+    fn each(
+        &mut self,
+        id: Option<u64>,
+        parent: &Value,
+        parent_path: &str,
+        key: &str,
+        kind: &'static str,
+        is_known: impl Fn(&str) -> bool,
+    ) {
+        for (i, value) in array(parent, key).iter().enumerate() {
+            if let Some(value) = value.as_str() {
+                self.check(
+                    id,
+                    format!("{}.{}[{}]", parent_path, key, i),
+                    kind,
+                    value,
+                    &is_known,
+                );
+            }
+        }
+    }
+
+    fn check(
+        &mut self,
+        id: Option<u64>,
+        path: String,
+        kind: &'static str,
+        value: &str,
+        is_known: &impl Fn(&str) -> bool,
+    ) {
+        if !is_known(value) {
+            self.issues.push(ValidationIssue {
+                origin: self.origin.to_string(),
+                monster_id: id,
+                path,
+                kind,
+                value: value.to_string(),
+            });
+        }
+    }
+}
+
+fn array<'a>(parent: &'a Value, key: &str) -> &'a [Value] {
+    parent
+        .get(key)
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+}
@@ -0,0 +1,177 @@
+use std::fmt::Display;
+
+use clap::ValueEnum;
+use ratatui::style::Color;
+
+use crate::{
+    monster::monster::{DamageData, MonsterElements, MonsterEntity},
+    theme::{glyph::Glyphs, Theme},
+};
+
+/// What a monster can be weak to: an element, or the damage type of a weapon.
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Attack {
+    #[default]
+    Fire,
+    Water,
+    Thunder,
+    Ice,
+    Dragon,
+    Cut,
+    Blunt,
+    Ammo,
+}
+
+impl Attack {
+    pub const ALL: [Attack; 8] = [
+        Attack::Fire,
+        Attack::Water,
+        Attack::Thunder,
+        Attack::Ice,
+        Attack::Dragon,
+        Attack::Cut,
+        Attack::Blunt,
+        Attack::Ammo,
+    ];
+
+    /// The element, `None` for a weapon damage type.
+    pub fn element(&self) -> Option<MonsterElements> {
+        match self {
+            Attack::Fire => Some(MonsterElements::Fire),
+            Attack::Water => Some(MonsterElements::Water),
+            Attack::Thunder => Some(MonsterElements::Thunder),
+            Attack::Ice => Some(MonsterElements::Ice),
+            Attack::Dragon => Some(MonsterElements::Dragon),
+            Attack::Cut | Attack::Blunt | Attack::Ammo => None,
+        }
+    }
+
+    /// Hitzone of `part` against this attack.
+    pub fn hitzone(&self, part: &DamageData) -> u8 {
+        match self {
+            Attack::Cut => part.weapon.cut_damage,
+            Attack::Blunt => part.weapon.blunt_damage,
+            Attack::Ammo => part.weapon.ammo_damage,
+            element => element
+                .element()
+                .map_or(0, |element| part.element.get(&element)),
+        }
+    }
+
+    pub fn color(&self, theme: &Theme) -> Color {
+        match self.element() {
+            Some(element) => element.color(theme),
+            None => theme.ui.text,
+        }
+    }
+
+    pub fn icon(&self, glyphs: &Glyphs) -> String {
+        match self {
+            Attack::Cut => glyphs.cut.to_string(),
+            Attack::Blunt => glyphs.blunt.to_string(),
+            Attack::Ammo => glyphs.ammo.to_string(),
+            element => element
+                .element()
+                .map(|e| e.icon(glyphs))
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl Display for Attack {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Attack::Cut => write!(f, "Cut"),
+            Attack::Blunt => write!(f, "Blunt"),
+            Attack::Ammo => write!(f, "Ammo"),
+            element => write!(f, "{}", element.element().unwrap_or_default()),
+        }
+    }
+}
+
+/// Which hitzone value orders the ranking, the other one breaks ties.
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RankBy {
+    /// The weakest part
+    #[default]
+    Best,
+    /// All parts averaged
+    Average,
+}
+
+impl RankBy {
+    pub fn toggle(self) -> Self {
+        match self {
+            RankBy::Best => RankBy::Average,
+            RankBy::Average => RankBy::Best,
+        }
+    }
+}
+
+impl Display for RankBy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RankBy::Best => write!(f, "Best Part"),
+            RankBy::Average => write!(f, "Average"),
+        }
+    }
+}
+
+/// How weak a monster is to an attack, over all of its parts.
+#[derive(Clone, Debug, Default)]
+pub struct WeakToEntry {
+    pub monster_id: u16,
+    pub name: String,
+    pub best: u8,
+    pub average: f32,
+    /// Part with the best hitzone, the first one listed on a tie.
+    pub weakest_part: String,
+    /// Row of that part in the monster's `dmg_data`.
+    pub row: usize,
+}
+
+impl WeakToEntry {
+    /// `None` for a monster without hitzone data.
+    pub fn new(monster: &MonsterEntity, attack: Attack) -> Option<Self> {
+        let parts = &monster.weaknesses.dmg_data;
+        let (row, weakest) = parts
+            .iter()
+            .enumerate()
+            .rev()
+            .max_by_key(|(_, part)| attack.hitzone(part))?;
+        let total: u32 = parts.iter().map(|part| attack.hitzone(part) as u32).sum();
+
+        Some(Self {
+            monster_id: monster.id,
+            name: monster.name.name.clone(),
+            best: attack.hitzone(weakest),
+            average: total as f32 / parts.len() as f32,
+            weakest_part: weakest.monster_part.clone(),
+            row,
+        })
+    }
+}
+
+/// Every monster with hitzone data, the weakest to `attack` first.
+pub fn rank<'a>(
+    monsters: impl IntoIterator<Item = &'a MonsterEntity>,
+    attack: Attack,
+    by: RankBy,
+) -> Vec<WeakToEntry> {
+    let mut ranking: Vec<WeakToEntry> = monsters
+        .into_iter()
+        .filter_map(|monster| WeakToEntry::new(monster, attack))
+        .collect();
+
+    ranking.sort_by(|a, b| {
+        let best = b.best.cmp(&a.best);
+        let average = b.average.total_cmp(&a.average);
+        match by {
+            RankBy::Best => best.then(average),
+            RankBy::Average => average.then(best),
+        }
+        .then_with(|| a.name.cmp(&b.name))
+    });
+
+    ranking
+}
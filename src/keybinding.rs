@@ -1,7 +1,15 @@
-use ratatui::crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind};
 use tui_input::backend::crossterm::EventHandler;
 
-use crate::state::{AppState, CurrentScreen, InputMode, MainMenuOption};
+use crate::{
+    keymap::{key::KeyChord, Action, Lookup, Scope},
+    state::{
+        list_detail::{ListDetailState, ListEntry, ListPane},
+        monster::{MonsterPane, MonsterWeaknessTab},
+        weak_to::WeakToPane,
+        AppState, CurrentScreen, InputMode, MainMenuOption,
+    },
+};
 
 #[derive(Default, Eq, PartialEq)]
 pub enum KeyHandleResult {
@@ -24,9 +32,23 @@ pub fn handle_key(event: KeyEvent, app: &mut AppState) -> KeyHandleResult {
     match app.tui_state.input_mode {
         crate::state::InputMode::Normal => on_normal(event, app),
         crate::state::InputMode::Editing => on_edit(event, app),
+        crate::state::InputMode::Notes => on_notes(event, app),
     }
 }
 
+/// Keys typed into the notes editor, `Esc` saves them.
+fn on_notes(event: KeyEvent, app: &mut AppState) -> KeyHandleResult {
+    match event.code {
+        KeyCode::Esc => {
+            app.monster_list.save_notes();
+            app.tui_state.input_mode = InputMode::Normal;
+        }
+        _ => app.monster_list.notes_state.key(event),
+    }
+
+    KeyHandleResult::Continue
+}
+
 fn on_edit(event: KeyEvent, app: &mut AppState) -> KeyHandleResult {
     match event.code {
         KeyCode::Esc => {
@@ -37,106 +59,490 @@ fn on_edit(event: KeyEvent, app: &mut AppState) -> KeyHandleResult {
         _ => {
             // handle normal key
             app.key_handle.input.handle_event(&Event::Key(event));
-            app.monster_list.filter_query.clear();
-            app.monster_list
-                .set_list_filter(app.key_handle.input.value().to_string());
-            app.monster_list.reset();
+            let query = app.key_handle.input.value().to_string();
+
+            match app.current_screen {
+                CurrentScreen::Material => app.material_list.set_list_filter(query),
+                CurrentScreen::Quest => app.quest_list.set_list_filter(query),
+                CurrentScreen::Weapon => app.weapon_list.set_list_filter(query),
+                CurrentScreen::Armor => app.armor_list.set_list_filter(query),
+                _ => {
+                    app.monster_list.filter_query.clear();
+                    app.monster_list.set_list_filter(query);
+                    app.monster_list.reset();
+                }
+            }
         }
     };
 
     KeyHandleResult::Continue
 }
 fn on_normal(event: KeyEvent, app: &mut AppState) -> KeyHandleResult {
-    let KeyEvent {
-        code,
-        modifiers,
-        kind: _,
-        state: _,
-    }: KeyEvent = event;
+    let scope = app.keymap_scope();
+    app.key_handle.pending.push(KeyChord::from(event));
 
-    match app.current_screen {
-        CurrentScreen::Main => {
-            match (code, modifiers) {
-                // handle Ctrl + char Press
-                (code, KeyModifiers::CONTROL) => match code {
-                    KeyCode::Char(_) => todo!(),
-                    _ => return KeyHandleResult::Continue,
-                },
-
-                // handle normal key
-                (code, _) => match code {
-                    KeyCode::Char('q') => return KeyHandleResult::Exit,
-
-                    KeyCode::Down | KeyCode::Char('j') => app.main_menu = app.main_menu.next(),
-                    KeyCode::Up | KeyCode::Char('k') => app.main_menu = app.main_menu.prev(),
-                    KeyCode::Enter => app.current_screen = select_screen(app),
-                    _ => (),
-                },
-            }
-        }
-        CurrentScreen::Monster => match code {
-            KeyCode::Char('q') => return KeyHandleResult::Exit,
-            KeyCode::Down | KeyCode::Char('j') => {
-                // app.monster_list.desc_scrollbar_state.scroll_down();
-                // app.monster_list.monster_on_quest_list_state.next();
-                // app.monster_list.monster_weakness_tab_state.next();
-                // app.monster_list.monster_drops_tab_state.next();
-                app.monster_list.reset();
-                app.monster_list.next();
+    let action = match app.keymap.lookup(scope, &app.key_handle.pending) {
+        Lookup::Action(action) => action,
+        Lookup::Pending => return KeyHandleResult::Continue,
+        Lookup::Unbound => {
+            let in_sequence = app.key_handle.pending.len() > 1;
+            app.key_handle.pending.clear();
+
+            // the key may still start a binding of its own
+            if in_sequence {
+                return on_normal(event, app);
             }
-            KeyCode::Up | KeyCode::Char('k') => {
-                // app.monster_list.desc_scrollbar_state.scroll_up();
-                // app.monster_list.monster_on_quest_list_state.prev();
-                // app.monster_list.monster_weakness_tab_state.prev();
-                // app.monster_list.monster_drops_tab_state.prev();
-                app.monster_list.reset();
-                app.monster_list.prev();
+            if scope == Scope::Calculator {
+                on_calculator_input(event.code, app);
             }
+            return KeyHandleResult::Continue;
+        }
+    };
+    app.key_handle.pending.clear();
 
-            KeyCode::Char('4') => {
+    match app.current_screen {
+        CurrentScreen::Main => match action {
+            Action::Quit => return KeyHandleResult::Exit,
+            Action::Down => app.main_menu = app.main_menu.next(),
+            Action::Up => app.main_menu = app.main_menu.prev(),
+            Action::Select => app.current_screen = select_screen(app),
+            _ => (),
+        },
+        CurrentScreen::Monster if app.calculator.open => on_calculator(action, app),
+        CurrentScreen::Monster if app.compare.open => on_compare(action, app),
+        CurrentScreen::Monster if app.recent.open => on_recent(action, app),
+        CurrentScreen::Monster if app.hunt_log.record.open => on_record_hunt(action, app),
+        CurrentScreen::Monster => match action {
+            Action::Quit => {
+                app.monster_list.record_visit();
+                return KeyHandleResult::Exit;
+            }
+            Action::NextPane => app.monster_list.next_pane(),
+            Action::PrevPane => app.monster_list.prev_pane(),
+            Action::Down | Action::Up => {
+                let pane = app.monster_list.focused_pane;
+                app.monster_list.scroll_pane(pane, action == Action::Down);
+            }
+            Action::Left | Action::Right => {
+                let monster_list = &mut app.monster_list;
+                let forward = action == Action::Right;
+                match monster_list.focused_pane {
+                    MonsterPane::Habitats if forward => monster_list.habitat_page_state.next(),
+                    MonsterPane::Habitats => monster_list.habitat_page_state.prev(),
+                    MonsterPane::Drops => monster_list
+                        .monster_drops_tab_state
+                        .toggle_selected_rank_tab(),
+                    MonsterPane::Weakness => monster_list
+                        .monster_weakness_tab_state
+                        .toggle_selected_weapon_elm_tab(),
+                    _ => {}
+                }
+            }
+            Action::ToggleWeaponElement => {
                 app.monster_list
                     .monster_weakness_tab_state
                     .toggle_selected_weapon_elm_tab();
             }
-            KeyCode::Char('$') => {
+            Action::ToggleAilmentItem => {
                 app.monster_list
                     .monster_weakness_tab_state
                     .toggle_selected_ailment_item_tab();
             }
-            KeyCode::Char('5') => {
+            Action::CycleDropSource => {
                 app.monster_list
                     .monster_drops_tab_state
                     .toggle_selected_source_tab();
             }
-            KeyCode::Char('%') => {
+            Action::ToggleDropRank => {
                 app.monster_list
                     .monster_drops_tab_state
                     .toggle_selected_rank_tab();
             }
-            KeyCode::Char('/') => app.tui_state.input_mode = InputMode::Editing,
-            // KeyCode::Left | KeyCode::Char('h') => app.monster_list.habitat_page_state.prev(),
-            // KeyCode::Right | KeyCode::Char('l') => app.monster_list.habitat_page_state.next(),
-            KeyCode::Esc => {
+            Action::Calculator => app.calculator.toggle(),
+            Action::MarkCompare => app.compare.toggle_mark(&app.monster_list.current_data),
+            Action::Compare => app.compare.open(&app.monster_list.current_data),
+            Action::ToggleFavorite => app.monster_list.toggle_favorite(),
+            Action::FilterFavorites => {
+                app.monster_list.toggle_favorites_only();
+                app.monster_list.reset();
+            }
+            Action::Recent => app.recent.open(app.monster_list.recent_monsters().len()),
+            Action::EditNotes => edit_notes(app),
+            Action::Stopwatch => toggle_stopwatch(app),
+            Action::StopStopwatch => app.hunt_log.stopwatch.stop(),
+            Action::RecordHunt => open_record_hunt(app),
+            Action::Select if app.monster_list.focused_pane == MonsterPane::Notes => {
+                edit_notes(app)
+            }
+            Action::Search => {
+                app.monster_list.focus(MonsterPane::Monsters);
+                app.tui_state.input_mode = InputMode::Editing;
+            }
+            Action::Back => {
+                app.monster_list.record_visit();
                 app.reset();
+                app.monster_list.focus(MonsterPane::Monsters);
                 app.current_screen = CurrentScreen::Main;
             }
-
             _ => {}
         },
+        CurrentScreen::Material => {
+            return on_list_screen(
+                action,
+                app,
+                |app| &mut app.material_list.list,
+                open_material_source,
+            )
+        }
+        CurrentScreen::Quest => match action {
+            Action::FilterType => app.quest_list.cycle_type_filter(),
+            Action::FilterLevel => app.quest_list.cycle_level_filter(),
+            _ => {
+                return on_list_screen(
+                    action,
+                    app,
+                    |app| &mut app.quest_list.list,
+                    open_quest_target,
+                )
+            }
+        },
+        CurrentScreen::Weapon => match action {
+            Action::FilterType => app.weapon_list.cycle_type_filter(),
+            _ => {
+                return on_list_screen(
+                    action,
+                    app,
+                    |app| &mut app.weapon_list.list,
+                    open_weapon_material,
+                )
+            }
+        },
+        CurrentScreen::Armor => match action {
+            Action::TogglePiece => app.armor_list.toggle_piece(),
+            Action::ClearSet => app.armor_list.builder.clear(),
+            Action::FilterRank => app.armor_list.cycle_rank_filter(),
+            Action::FilterKind => app.armor_list.cycle_kind_filter(),
+            _ => {
+                return on_list_screen(
+                    action,
+                    app,
+                    |app| &mut app.armor_list.list,
+                    open_armor_material,
+                )
+            }
+        },
+        CurrentScreen::WeakTo => match action {
+            Action::Quit => return KeyHandleResult::Exit,
+            Action::Down => match app.weak_to.focused_pane {
+                WeakToPane::Attacks => app.weak_to.next(),
+                WeakToPane::Monsters => app.weak_to.next_monster(),
+            },
+            Action::Up => match app.weak_to.focused_pane {
+                WeakToPane::Attacks => app.weak_to.prev(),
+                WeakToPane::Monsters => app.weak_to.prev_monster(),
+            },
+            Action::NextPane | Action::PrevPane | Action::Left | Action::Right => {
+                app.weak_to.toggle_focus()
+            }
+            Action::Select => match app.weak_to.focused_pane {
+                WeakToPane::Attacks => app.weak_to.toggle_focus(),
+                WeakToPane::Monsters => open_weak_to_monster(app),
+            },
+            Action::ToggleRankBy => app.weak_to.toggle_rank_by(),
+            Action::Back => {
+                app.reset();
+                app.current_screen = CurrentScreen::Main;
+            }
+            _ => {}
+        },
+        CurrentScreen::HuntLog => match action {
+            Action::Quit => return KeyHandleResult::Exit,
+            Action::Down => app.hunt_log.next(),
+            Action::Up => app.hunt_log.prev(),
+            Action::Select => open_hunt_log_monster(app),
+            Action::Back => app.current_screen = CurrentScreen::Main,
+            _ => {}
+        },
+    }
+
+    KeyHandleResult::Continue
+}
+
+/// Keys of the Material, Quest, Weapon and Armor screens, `open_detail` following the
+/// selected detail row to another screen.
+fn on_list_screen<T: ListEntry>(
+    action: Action,
+    app: &mut AppState,
+    list: fn(&mut AppState) -> &mut ListDetailState<T>,
+    open_detail: fn(&mut AppState),
+) -> KeyHandleResult {
+    match action {
+        Action::Quit => return KeyHandleResult::Exit,
+        Action::Down | Action::Up => list(app).scroll(action == Action::Down),
+        Action::NextPane | Action::PrevPane | Action::Left | Action::Right => {
+            list(app).toggle_focus()
+        }
+        Action::Select => match list(app).focused_pane {
+            ListPane::List => list(app).toggle_focus(),
+            ListPane::Detail => open_detail(app),
+        },
+        Action::Search => app.tui_state.input_mode = InputMode::Editing,
+        Action::Back => {
+            app.reset();
+            app.current_screen = CurrentScreen::Main;
+        }
         _ => {}
     }
 
     KeyHandleResult::Continue
 }
 
+fn on_calculator(action: Action, app: &mut AppState) {
+    let calculator = &mut app.calculator;
+    match action {
+        Action::Back | Action::Calculator => calculator.toggle(),
+        Action::Down | Action::NextPane => calculator.next_field(),
+        Action::Up | Action::PrevPane => calculator.prev_field(),
+        Action::Right => calculator.increase(),
+        Action::Left => calculator.decrease(),
+        _ => {}
+    }
+}
+
+fn on_compare(action: Action, app: &mut AppState) {
+    let compare = &mut app.compare;
+    match action {
+        Action::Back | Action::Compare => compare.close(),
+        Action::Down => compare.scroll.scroll_down(),
+        Action::Up => compare.scroll.scroll_up(),
+        _ => {}
+    }
+}
+
+fn edit_notes(app: &mut AppState) {
+    if app.monster_list.edit_notes() {
+        app.tui_state.input_mode = InputMode::Notes;
+    }
+}
+
+fn on_recent(action: Action, app: &mut AppState) {
+    let len = app.monster_list.recent_monsters().len();
+    match action {
+        Action::Back | Action::Recent => app.recent.close(),
+        Action::Down => app.recent.next(len),
+        Action::Up => app.recent.prev(len),
+        Action::Select => open_recent_monster(app),
+        _ => {}
+    }
+}
+
+fn on_record_hunt(action: Action, app: &mut AppState) {
+    let hunt_log = &mut app.hunt_log;
+    match action {
+        Action::Back | Action::RecordHunt => hunt_log.close_record(),
+        Action::Down | Action::Up | Action::NextPane | Action::PrevPane => {
+            hunt_log.record.toggle_field()
+        }
+        Action::Left | Action::Right => hunt_log.record.toggle_value(),
+        Action::Select => hunt_log.save_record(),
+        _ => {}
+    }
+}
+
+/// Start the stopwatch on the selected monster, or pause and resume it.
+fn toggle_stopwatch(app: &mut AppState) {
+    // nothing is selected in an empty search
+    if app.hunt_log.stopwatch.monster_id.is_none() && app.monster_list.list_items().is_empty() {
+        return;
+    }
+    app.hunt_log
+        .stopwatch
+        .toggle(app.monster_list.current_data.id);
+}
+
+/// Record a hunt on the stopwatch's monster, else on the selected one.
+fn open_record_hunt(app: &mut AppState) {
+    if app.hunt_log.stopwatch.monster_id.is_none() && app.monster_list.list_items().is_empty() {
+        return;
+    }
+    app.hunt_log.open_record(app.monster_list.current_data.id);
+}
+
+/// Digits typed into the calculator fields, unless they are bound to an action.
+fn on_calculator_input(code: KeyCode, app: &mut AppState) {
+    let calculator = &mut app.calculator;
+    match code {
+        KeyCode::Backspace => calculator.pop_digit(),
+        KeyCode::Char(c) if c.is_ascii_digit() => {
+            calculator.push_digit(c.to_digit(10).unwrap_or_default())
+        }
+        _ => {}
+    }
+}
+
+/// Jump to the drop tab of the monster behind the selected material source.
+fn open_material_source(app: &mut AppState) {
+    let Some(source) = app.material_list.selected_source().cloned() else {
+        return;
+    };
+
+    app.reset();
+    if !app.monster_list.select_by_id(source.monster_id) {
+        return;
+    }
+
+    app.monster_list.reset();
+    app.monster_list
+        .monster_drops_tab_state
+        .show(source.rank, source.source, source.row);
+    app.monster_list.focus(MonsterPane::Drops);
+    app.current_screen = CurrentScreen::Monster;
+}
+
+/// Open the profile of the selected quest target with the quest selected.
+fn open_quest_target(app: &mut AppState) {
+    let Some(target) = app.quest_list.selected_target().cloned() else {
+        return;
+    };
+
+    app.reset();
+    if !app.monster_list.select_by_id(target.monster.id) {
+        return;
+    }
+
+    app.monster_list.reset();
+    app.monster_list
+        .monster_on_quest_list_state
+        .select(target.row);
+    app.monster_list.focus(MonsterPane::Quests);
+    app.current_screen = CurrentScreen::Monster;
+}
+
+/// Open the hitzones of the selected monster on the tab of the attack, its weakest part selected.
+fn open_weak_to_monster(app: &mut AppState) {
+    let Some(entry) = app.weak_to.selected_entry().cloned() else {
+        return;
+    };
+    let tab = match app.weak_to.attack.element() {
+        Some(_) => MonsterWeaknessTab::ElementDamage,
+        None => MonsterWeaknessTab::WeaponDamage,
+    };
+
+    app.reset();
+    if !app.monster_list.select_by_id(entry.monster_id) {
+        return;
+    }
+
+    app.monster_list.reset();
+    app.monster_list
+        .monster_weakness_tab_state
+        .show(tab, entry.row);
+    app.monster_list.focus(MonsterPane::Weakness);
+    app.current_screen = CurrentScreen::Monster;
+}
+
+/// Open the profile of the monster selected in the hunt log.
+fn open_hunt_log_monster(app: &mut AppState) {
+    let Some(record) = app.hunt_log.selected_record() else {
+        return;
+    };
+
+    app.reset();
+    if !app.monster_list.select_by_id(record.monster_id) {
+        return;
+    }
+
+    app.monster_list.reset();
+    app.monster_list.focus(MonsterPane::Monsters);
+    app.current_screen = CurrentScreen::Monster;
+}
+
+/// Select the monster picked in the recent popup, clearing the search when it is not listed.
+fn open_recent_monster(app: &mut AppState) {
+    let recent = app.monster_list.recent_monsters();
+    let Some(monster) = app
+        .recent
+        .list_state
+        .selected()
+        .and_then(|idx| recent.get(idx))
+    else {
+        return;
+    };
+
+    app.recent.close();
+    if !app.monster_list.select_by_id(monster.id) {
+        app.reset();
+        if !app.monster_list.select_by_id(monster.id) {
+            return;
+        }
+    }
+
+    app.monster_list.reset();
+    app.monster_list.focus(MonsterPane::Monsters);
+    app.monster_list.record_visit();
+}
+
+/// Show where the selected crafting material drops on the Materials screen.
+fn open_weapon_material(app: &mut AppState) {
+    let Some(material) = app.weapon_list.selected_material().cloned() else {
+        return;
+    };
+    // materials no monster drops are not listed
+    if app.material_list.index.find(&material.material).is_none() {
+        return;
+    }
+
+    app.reset();
+    if app.material_list.select_material(&material.material) {
+        app.current_screen = CurrentScreen::Material;
+    }
+}
+
+/// Show where the selected armor material drops on the Materials screen.
+fn open_armor_material(app: &mut AppState) {
+    let Some(material) = app.armor_list.selected_material().cloned() else {
+        return;
+    };
+    // materials no monster drops are not listed
+    if app.material_list.index.find(&material.material).is_none() {
+        return;
+    }
+
+    app.reset();
+    if app.material_list.select_material(&material.material) {
+        app.current_screen = CurrentScreen::Material;
+    }
+}
+
 fn select_screen(app: &mut AppState) -> CurrentScreen {
     match app.main_menu {
         MainMenuOption::Monster => {
             app.monster_list.reset();
             CurrentScreen::Monster
         }
-        MainMenuOption::Quest => CurrentScreen::Quest,
-        MainMenuOption::Weapon => CurrentScreen::Weapon,
-        MainMenuOption::Armor => CurrentScreen::Armor,
+        MainMenuOption::Material => {
+            app.material_list.reset();
+            CurrentScreen::Material
+        }
+        MainMenuOption::Quest => {
+            app.quest_list.reset();
+            CurrentScreen::Quest
+        }
+        MainMenuOption::Weapon => {
+            app.weapon_list.reset();
+            CurrentScreen::Weapon
+        }
+        MainMenuOption::Armor => {
+            app.armor_list.reset();
+            CurrentScreen::Armor
+        }
+        MainMenuOption::WeakTo => {
+            app.weak_to.reset();
+            CurrentScreen::WeakTo
+        }
+        MainMenuOption::HuntLog => {
+            app.hunt_log.reset();
+            CurrentScreen::HuntLog
+        }
     }
 }
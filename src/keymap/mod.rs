@@ -0,0 +1,464 @@
+pub mod key;
+
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use key::{KeyChord, KeyList, KeySequence};
+use serde::{
+    de::{Unexpected, Visitor},
+    Deserialize,
+};
+
+/// Something a key can be bound to. What it does depends on the screen.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Action {
+    Quit,
+    Back,
+    Down,
+    Up,
+    Left,
+    Right,
+    Select,
+    NextPane,
+    PrevPane,
+    Search,
+    ToggleWeaponElement,
+    ToggleAilmentItem,
+    CycleDropSource,
+    ToggleDropRank,
+    Calculator,
+    FilterType,
+    FilterLevel,
+    FilterRank,
+    FilterKind,
+    TogglePiece,
+    ClearSet,
+    ToggleRankBy,
+    MarkCompare,
+    Compare,
+    ToggleFavorite,
+    FilterFavorites,
+    Recent,
+    EditNotes,
+    Stopwatch,
+    StopStopwatch,
+    RecordHunt,
+}
+
+impl Action {
+    pub const ALL: [Action; 31] = [
+        Action::Quit,
+        Action::Back,
+        Action::Down,
+        Action::Up,
+        Action::Left,
+        Action::Right,
+        Action::Select,
+        Action::NextPane,
+        Action::PrevPane,
+        Action::Search,
+        Action::ToggleWeaponElement,
+        Action::ToggleAilmentItem,
+        Action::CycleDropSource,
+        Action::ToggleDropRank,
+        Action::Calculator,
+        Action::FilterType,
+        Action::FilterLevel,
+        Action::FilterRank,
+        Action::FilterKind,
+        Action::TogglePiece,
+        Action::ClearSet,
+        Action::ToggleRankBy,
+        Action::MarkCompare,
+        Action::Compare,
+        Action::ToggleFavorite,
+        Action::FilterFavorites,
+        Action::Recent,
+        Action::EditNotes,
+        Action::Stopwatch,
+        Action::StopStopwatch,
+        Action::RecordHunt,
+    ];
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|action| action.key() == key)
+    }
+
+    pub fn key(&self) -> &'static str {
+        match self {
+            Action::Quit => "quit",
+            Action::Back => "back",
+            Action::Down => "down",
+            Action::Up => "up",
+            Action::Left => "left",
+            Action::Right => "right",
+            Action::Select => "select",
+            Action::NextPane => "next-pane",
+            Action::PrevPane => "prev-pane",
+            Action::Search => "search",
+            Action::ToggleWeaponElement => "toggle-weapon-element",
+            Action::ToggleAilmentItem => "toggle-ailment-item",
+            Action::CycleDropSource => "cycle-drop-source",
+            Action::ToggleDropRank => "toggle-drop-rank",
+            Action::Calculator => "calculator",
+            Action::FilterType => "filter-type",
+            Action::FilterLevel => "filter-level",
+            Action::FilterRank => "filter-rank",
+            Action::FilterKind => "filter-kind",
+            Action::TogglePiece => "toggle-piece",
+            Action::ClearSet => "clear-set",
+            Action::ToggleRankBy => "toggle-rank-by",
+            Action::MarkCompare => "mark-compare",
+            Action::Compare => "compare",
+            Action::ToggleFavorite => "toggle-favorite",
+            Action::FilterFavorites => "filter-favorites",
+            Action::Recent => "recent",
+            Action::EditNotes => "edit-notes",
+            Action::Stopwatch => "stopwatch",
+            Action::StopStopwatch => "stop-stopwatch",
+            Action::RecordHunt => "record-hunt",
+        }
+    }
+}
+
+impl Display for Action {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.key())
+    }
+}
+
+impl<'de> Deserialize<'de> for Action {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(ActionVisitor)
+    }
+}
+
+struct ActionVisitor;
+
+impl<'de> Visitor<'de> for ActionVisitor {
+    type Value = Action;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("Action")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Action::from_key(v).ok_or_else(|| E::invalid_value(Unexpected::Str(v), &self))
+    }
+}
+
+/// Where a binding applies, a table of the keymap file. Screen bindings win over global ones.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Scope {
+    Global,
+    Main,
+    Monster,
+    Calculator,
+    Compare,
+    Recent,
+    RecordHunt,
+    Material,
+    Quest,
+    Weapon,
+    Armor,
+    WeakTo,
+    HuntLog,
+}
+
+impl Scope {
+    pub const ALL: [Scope; 13] = [
+        Scope::Global,
+        Scope::Main,
+        Scope::Monster,
+        Scope::Calculator,
+        Scope::Compare,
+        Scope::Recent,
+        Scope::RecordHunt,
+        Scope::Material,
+        Scope::Quest,
+        Scope::Weapon,
+        Scope::Armor,
+        Scope::WeakTo,
+        Scope::HuntLog,
+    ];
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|scope| scope.key() == key)
+    }
+
+    pub fn key(&self) -> &'static str {
+        match self {
+            Scope::Global => "global",
+            Scope::Main => "main",
+            Scope::Monster => "monster",
+            Scope::Calculator => "calculator",
+            Scope::Compare => "compare",
+            Scope::Recent => "recent",
+            Scope::RecordHunt => "record-hunt",
+            Scope::Material => "material",
+            Scope::Quest => "quest",
+            Scope::Weapon => "weapon",
+            Scope::Armor => "armor",
+            Scope::WeakTo => "weak-to",
+            Scope::HuntLog => "hunt-log",
+        }
+    }
+}
+
+impl Display for Scope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.key())
+    }
+}
+
+impl<'de> Deserialize<'de> for Scope {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(ScopeVisitor)
+    }
+}
+
+struct ScopeVisitor;
+
+impl<'de> Visitor<'de> for ScopeVisitor {
+    type Value = Scope;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("Keymap Scope")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Scope::from_key(v).ok_or_else(|| E::invalid_value(Unexpected::Str(v), &self))
+    }
+}
+
+const DEFAULT_BINDINGS: &[(Scope, Action, &[&str])] = &[
+    (Scope::Global, Action::Quit, &["q"]),
+    (Scope::Global, Action::Back, &["Esc"]),
+    (Scope::Global, Action::Down, &["j", "Down"]),
+    (Scope::Global, Action::Up, &["k", "Up"]),
+    (Scope::Global, Action::Left, &["h", "Left"]),
+    (Scope::Global, Action::Right, &["l", "Right"]),
+    (Scope::Global, Action::Select, &["Enter"]),
+    (Scope::Global, Action::NextPane, &["Tab"]),
+    (Scope::Global, Action::PrevPane, &["S-Tab"]),
+    (Scope::Global, Action::Search, &["/"]),
+    (Scope::Monster, Action::ToggleWeaponElement, &["4"]),
+    (Scope::Monster, Action::ToggleAilmentItem, &["$"]),
+    (Scope::Monster, Action::CycleDropSource, &["5"]),
+    (Scope::Monster, Action::ToggleDropRank, &["%"]),
+    (Scope::Monster, Action::Calculator, &["c"]),
+    (Scope::Monster, Action::MarkCompare, &["m"]),
+    (Scope::Monster, Action::Compare, &["v"]),
+    (Scope::Monster, Action::ToggleFavorite, &["f"]),
+    (Scope::Monster, Action::FilterFavorites, &["F"]),
+    (Scope::Monster, Action::Recent, &["r"]),
+    (Scope::Monster, Action::EditNotes, &["e"]),
+    (Scope::Monster, Action::Stopwatch, &["t"]),
+    (Scope::Monster, Action::StopStopwatch, &["T"]),
+    (Scope::Monster, Action::RecordHunt, &["H"]),
+    (Scope::Calculator, Action::Calculator, &["c"]),
+    (Scope::Compare, Action::Compare, &["v"]),
+    (Scope::Recent, Action::Recent, &["r"]),
+    (Scope::RecordHunt, Action::RecordHunt, &["H"]),
+    (Scope::Quest, Action::FilterType, &["t"]),
+    (Scope::Quest, Action::FilterLevel, &["s"]),
+    (Scope::Weapon, Action::FilterType, &["t"]),
+    (Scope::Armor, Action::TogglePiece, &["a"]),
+    (Scope::Armor, Action::ClearSet, &["x"]),
+    (Scope::Armor, Action::FilterRank, &["r"]),
+    (Scope::Armor, Action::FilterKind, &["t"]),
+    (Scope::WeakTo, Action::ToggleRankBy, &["s"]),
+];
+
+/// Result of looking up the keys pressed so far.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Lookup {
+    Action(Action),
+    /// The keys start a longer sequence, wait for the next one.
+    Pending,
+    Unbound,
+}
+
+/// Key bindings by scope, the defaults with the user keymap file applied on top.
+#[derive(Clone, Debug)]
+pub struct Keymap {
+    scopes: HashMap<Scope, Vec<(Action, Vec<KeySequence>)>>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        let mut keymap = Self {
+            scopes: HashMap::new(),
+        };
+
+        for (scope, action, keys) in DEFAULT_BINDINGS {
+            let keys = keys.iter().filter_map(|k| KeySequence::parse(k)).collect();
+            keymap
+                .scopes
+                .entry(*scope)
+                .or_default()
+                .push((*action, keys));
+        }
+
+        keymap
+    }
+}
+
+impl Keymap {
+    /// Read the keymap from `path`, or from the default path when there is a file.
+    pub fn load(path: Option<&Path>) -> Result<Self, KeymapError> {
+        match path {
+            Some(path) => Self::read(path),
+            None => match default_keymap_path() {
+                Some(path) if path.is_file() => Self::read(&path),
+                _ => Ok(Self::default()),
+            },
+        }
+    }
+
+    fn read(path: &Path) -> Result<Self, KeymapError> {
+        let content = fs::read_to_string(path).map_err(|source| KeymapError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+        Self::parse(&content).map_err(|source| KeymapError::Parse {
+            path: path.to_path_buf(),
+            source: Box::new(source),
+        })
+    }
+
+    /// The defaults overridden by the tables of a keymap file.
+    pub fn parse(content: &str) -> Result<Self, toml::de::Error> {
+        let file: HashMap<Scope, HashMap<Action, KeyList>> = toml::from_str(content)?;
+        let mut keymap = Self::default();
+
+        for (scope, actions) in file {
+            for (action, KeyList(keys)) in actions {
+                keymap.bind(scope, action, keys);
+            }
+        }
+
+        Ok(keymap)
+    }
+
+    /// Replace the keys of `action` in `scope`, taking them away from the other actions there.
+    pub fn bind(&mut self, scope: Scope, action: Action, keys: Vec<KeySequence>) {
+        let bindings = self.scopes.entry(scope).or_default();
+        for (_, bound) in bindings.iter_mut() {
+            bound.retain(|sequence| !keys.contains(sequence));
+        }
+
+        match bindings.iter_mut().find(|(a, _)| *a == action) {
+            Some((_, bound)) => *bound = keys,
+            None => bindings.push((action, keys)),
+        }
+    }
+
+    /// Bindings in effect on `scope`: its own, then the global ones it does not override.
+    fn bindings(&self, scope: Scope) -> impl Iterator<Item = (Action, &KeySequence)> {
+        let own = self
+            .scopes
+            .get(&scope)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        let global = match scope {
+            Scope::Global => &[],
+            _ => self
+                .scopes
+                .get(&Scope::Global)
+                .map(Vec::as_slice)
+                .unwrap_or_default(),
+        };
+
+        own.iter()
+            .chain(
+                global
+                    .iter()
+                    .filter(|(action, _)| own.iter().all(|(a, _)| a != action)),
+            )
+            .flat_map(|(action, keys)| keys.iter().map(|sequence| (*action, sequence)))
+    }
+
+    /// Match the keys pressed so far, a complete binding wins over a longer one.
+    pub fn lookup(&self, scope: Scope, keys: &[KeyChord]) -> Lookup {
+        let mut pending = false;
+        for (action, sequence) in self.bindings(scope) {
+            if sequence.0 == keys {
+                return Lookup::Action(action);
+            }
+            pending |= sequence.starts_with(keys);
+        }
+
+        if pending {
+            Lookup::Pending
+        } else {
+            Lookup::Unbound
+        }
+    }
+
+    /// Keys that trigger `action` on `scope`, leaving out the ones shadowed by another binding.
+    pub fn keys(&self, scope: Scope, action: Action) -> Vec<&KeySequence> {
+        self.bindings(scope)
+            .filter(|(a, sequence)| {
+                *a == action && self.lookup(scope, &sequence.0) == Lookup::Action(action)
+            })
+            .map(|(_, sequence)| sequence)
+            .collect()
+    }
+}
+
+/// `$XDG_CONFIG_HOME/monsterex/keymap.toml` (or the platform equivalent).
+pub fn default_keymap_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("monsterex").join("keymap.toml"))
+}
+
+#[derive(Debug)]
+pub enum KeymapError {
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+    Parse {
+        path: PathBuf,
+        source: Box<toml::de::Error>,
+    },
+}
+
+impl Display for KeymapError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            KeymapError::Io { path, source } => {
+                write!(f, "cannot read keymap {}: {}", path.display(), source)
+            }
+            KeymapError::Parse { path, source } => {
+                write!(f, "invalid keymap {}: {}", path.display(), source)
+            }
+        }
+    }
+}
+
+impl std::error::Error for KeymapError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            KeymapError::Io { source, .. } => Some(source),
+            KeymapError::Parse { source, .. } => Some(source.as_ref()),
+        }
+    }
+}
@@ -1,7 +1,17 @@
+pub mod cli;
+pub mod command;
+pub mod data;
 pub mod monster;
 pub mod keybinding;
+pub mod keymap;
+pub mod theme;
+pub mod mouse;
 pub mod state;
 pub mod tui;
+pub mod session;
 pub mod ui;
+pub mod util;
 pub mod widget;
 pub mod screen;
+pub mod weapon;
+pub mod armor;
@@ -2,13 +2,39 @@ use std::{
     io,
     path::{Path, PathBuf},
     rc::Rc,
+    time::Duration,
 };
 
 use anyhow::Result;
+use clap::Parser;
 use monsterex::{
-    keybinding::handle_key,
-    monster::monster::MonsterEntity,
-    state::{monster::MonsterListState, AppState},
+    cli::{Cli, Command},
+    command::{
+        check_data,
+        export::{self, ExportOptions},
+        iconify::{self, IconifyOptions},
+        show::{self, ShowOptions},
+        weak_to::{self, WeakToOptions},
+    },
+    data::{
+        hunt_log::{default_hunt_log_path, HuntLog},
+        material_index::MaterialIndex,
+        notes::{default_notes_path, Notes},
+        quest_index::QuestIndex,
+        store::StoreError,
+        DataSource,
+    },
+    keybinding::{handle_key, KeyHandleResult},
+    keymap::Keymap,
+    monster::{icon::IconSource, monster::MonsterEntity},
+    mouse::handle_mouse,
+    session::{default_session_path, Session},
+    state::{
+        armor::ArmorListState, hunt_log::HuntLogState, material::MaterialListState,
+        monster::MonsterListState, quest::QuestListState, weak_to::WeakToState,
+        weapon::WeaponListState, AppState,
+    },
+    theme::{glyph::GlyphSet, Theme},
     tui::Tui,
     ui::ui,
 };
@@ -18,57 +44,203 @@ use ratatui::{
     crossterm::event::{self, Event},
     Terminal,
 };
-use serde_json::from_str;
 
 fn main() -> Result<()> {
-    let Ok(monster) = load_data() else {
-        println!("load data error");
-        std::process::exit(2);
+    let cli = Cli::parse();
+
+    // converting images does not need the monster data
+    if let Some(Command::Iconify {
+        input,
+        output,
+        width,
+        alpha_threshold,
+        background,
+    }) = cli.command
+    {
+        let options = IconifyOptions {
+            input,
+            output,
+            width,
+            alpha_threshold,
+            background,
+        };
+        if let Err(err) = iconify::run(options) {
+            eprintln!("error: {err:#}");
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    let mode = cli.validation_mode();
+    let icons = IconSource::resolve(cli.icons);
+    let data = match DataSource::resolve(cli.data).load(mode) {
+        Ok(data) => data,
+        Err(err) => {
+            eprintln!("error: {err}");
+            std::process::exit(2);
+        }
+    };
+
+    match cli.command {
+        Some(Command::CheckData) => {
+            std::process::exit(check_data::run(&data, &icons));
+        }
+        Some(Command::Export {
+            format,
+            output,
+            monster,
+            search,
+        }) => {
+            let options = ExportOptions {
+                format,
+                output,
+                monster,
+                search,
+            };
+            // exported along with the monsters
+            let (notes, _) = load_user_file(cli.notes.or_else(default_notes_path), Notes::load);
+            if let Err(err) = export::run(&data.monsters, &notes, options) {
+                eprintln!("error: {err:#}");
+                std::process::exit(1);
+            }
+            return Ok(());
+        }
+        Some(Command::Show { monster, format }) => {
+            // the profile is colored with the theme
+            let theme = load_theme(cli.theme.as_deref());
+            if let Err(err) = show::run(&data.monsters, &theme, ShowOptions { monster, format }) {
+                eprintln!("error: {err:#}");
+                std::process::exit(1);
+            }
+            return Ok(());
+        }
+        Some(Command::WeakTo { attack, by, limit }) => {
+            let options = WeakToOptions { attack, by, limit };
+            if let Err(err) = weak_to::run(&data.monsters, options) {
+                eprintln!("error: {err:#}");
+                std::process::exit(1);
+            }
+            return Ok(());
+        }
+        Some(Command::Iconify { .. }) | None => {}
+    }
+
+    let keymap = match Keymap::load(cli.keymap.as_deref()) {
+        Ok(keymap) => keymap,
+        Err(err) => {
+            eprintln!("error: {err}");
+            std::process::exit(2);
+        }
     };
 
+    let theme = load_theme(cli.theme.as_deref());
+    let glyphs = cli
+        .glyphs
+        .or(theme.glyphs)
+        .unwrap_or_else(GlyphSet::detect)
+        .glyphs();
+
+    let (notes, notes_path) = load_user_file(cli.notes.or_else(default_notes_path), Notes::load);
+    let (hunt_log, hunt_log_path) =
+        load_user_file(cli.hunt_log.or_else(default_hunt_log_path), HuntLog::load);
+
+    data.warnings
+        .iter()
+        .for_each(|issue| eprintln!("warning: {issue}"));
+    let monster = data.monsters;
+
     let bundle: Vec<Rc<MonsterEntity>> = monster.into_iter().map(Rc::new).collect();
+    let material_index = MaterialIndex::build(bundle.iter().map(Rc::as_ref));
+    let weapon_list_state = WeaponListState::new(data.weapons, material_index.clone());
+    let armor_list_state = ArmorListState::new(data.armor, material_index.clone());
+    let material_list_state = MaterialListState::new(material_index);
+    let quest_list_state = QuestListState::new(QuestIndex::build(&bundle));
+    let weak_to_state = WeakToState::new(bundle.clone());
     let Some(monster_list_state) = MonsterListState::new(bundle) else {
         panic!("No Monster Data");
     };
 
-    let monster_list_state = monster_list_state.path(get_asset_dir_path()?);
+    let monster_list_state = monster_list_state.icons(icons).notes(notes, notes_path);
+
+    let (session, session_path) = load_user_file(default_session_path(), Session::load);
 
     // setup terimnal
     let mut tui = Tui::init()?;
 
     // create app instance
-    let app = AppState {
+    let mut app = AppState {
         monster_list: monster_list_state,
+        material_list: material_list_state,
+        quest_list: quest_list_state,
+        weapon_list: weapon_list_state,
+        armor_list: armor_list_state,
+        weak_to: weak_to_state,
+        hunt_log: HuntLogState::new(hunt_log, hunt_log_path),
+        keymap,
+        theme,
+        glyphs,
         ..Default::default()
     };
 
+    app.restore_session(session, cli.restore);
+
     // println!("{:?}", app.monster_list.habitat_page_state);
-    run_app(&mut tui.terminal, app)?;
+    run_app(&mut tui.terminal, &mut app)?;
+    drop(tui);
+
+    if let Some(path) = session_path {
+        if let Err(err) = app.session().save(&path) {
+            eprintln!("warning: {err}");
+        }
+    }
     Ok(())
 }
 
-fn run_app<B>(terminal: &mut Terminal<B>, mut app: AppState) -> io::Result<()>
+fn load_theme(spec: Option<&str>) -> Theme {
+    match Theme::load(spec) {
+        Ok(theme) => theme,
+        Err(err) => {
+            eprintln!("error: {err}");
+            std::process::exit(2);
+        }
+    }
+}
+
+/// Read a user file like the saved state, or warn and go without it. The path of a file that
+/// cannot be read is dropped, so it is left alone rather than overwritten.
+fn load_user_file<T: Default>(
+    path: Option<PathBuf>,
+    load: fn(&Path) -> Result<T, StoreError>,
+) -> (T, Option<PathBuf>) {
+    match path.as_deref().map(load).transpose() {
+        Ok(value) => (value.unwrap_or_default(), path),
+        Err(err) => {
+            eprintln!("warning: {err}");
+            (T::default(), None)
+        }
+    }
+}
+
+fn run_app<B>(terminal: &mut Terminal<B>, app: &mut AppState) -> io::Result<()>
 where
     B: Backend,
 {
     loop {
-        terminal.draw(|f| ui(f, &mut app))?;
+        terminal.draw(|f| ui(f, app))?;
 
-        if let Event::Key(event) = event::read()? {
-            if handle_key(event, &mut app).exit() {
-                return Ok(());
-            }
+        // redraw the running stopwatch every tick
+        if app.hunt_log.stopwatch.is_running() && !event::poll(Duration::from_millis(250))? {
+            continue;
         }
-    }
-}
 
-fn get_asset_dir_path() -> Result<PathBuf> {
-    let asset_path = Path::new("icons");
-    let current_dir = std::env::current_dir()?;
-    Ok(current_dir.join(asset_path))
-}
+        let result = match event::read()? {
+            Event::Key(event) => handle_key(event, app),
+            Event::Mouse(event) => handle_mouse(event, app),
+            _ => KeyHandleResult::Continue,
+        };
 
-fn load_data() -> Result<Vec<MonsterEntity>, ()> {
-    let monster = from_str(include_str!("../data/monster.json")).expect("load monster data error");
-    Ok(monster)
+        if result.exit() {
+            return Ok(());
+        }
+    }
 }
@@ -0,0 +1,129 @@
+use std::collections::BTreeMap;
+
+use super::monster::{ElementDamageData, ItemWeakness, MonsterEntity, StatusAilments};
+
+/// A part both monsters have, matched by name.
+pub struct PartPair<'a> {
+    pub part: &'a str,
+    pub left: &'a ElementDamageData,
+    pub right: &'a ElementDamageData,
+}
+
+/// Parts of `left` that `right` has too, in the order of `left`.
+pub fn matching_parts<'a>(left: &'a MonsterEntity, right: &'a MonsterEntity) -> Vec<PartPair<'a>> {
+    left.weaknesses
+        .dmg_data
+        .iter()
+        .filter_map(|l| {
+            right
+                .weaknesses
+                .dmg_data
+                .iter()
+                .find(|r| r.monster_part.eq_ignore_ascii_case(&l.monster_part))
+                .map(|r| PartPair {
+                    part: &l.monster_part,
+                    left: &l.element,
+                    right: &r.element,
+                })
+        })
+        .collect()
+}
+
+/// Effectiveness of every ailment listed by either monster.
+pub fn ailment_effs(
+    left: &MonsterEntity,
+    right: &MonsterEntity,
+) -> Vec<(StatusAilments, Option<u8>, Option<u8>)> {
+    let eff = |monster: &MonsterEntity, ailment: &StatusAilments| {
+        monster
+            .weaknesses
+            .ailment_data
+            .status
+            .iter()
+            .find(|s| s.ailment.key() == ailment.key())
+            .map(|s| s.eff)
+    };
+
+    let mut ailments: Vec<&StatusAilments> = Vec::new();
+    for s in left
+        .weaknesses
+        .ailment_data
+        .status
+        .iter()
+        .chain(&right.weaknesses.ailment_data.status)
+    {
+        if ailments.iter().all(|a| a.key() != s.ailment.key()) {
+            ailments.push(&s.ailment);
+        }
+    }
+
+    ailments
+        .into_iter()
+        .map(|a| (a.clone(), eff(left, a), eff(right, a)))
+        .collect()
+}
+
+/// Whether every item listed by either monster works on it.
+pub fn item_effectiveness(
+    left: &MonsterEntity,
+    right: &MonsterEntity,
+) -> Vec<(ItemWeakness, Option<bool>, Option<bool>)> {
+    let effective = |monster: &MonsterEntity, item: &ItemWeakness| {
+        monster
+            .weaknesses
+            .ailment_data
+            .items
+            .iter()
+            .find(|i| i.item.key() == item.key())
+            .map(|i| i.is_effective)
+    };
+
+    let mut items: Vec<&ItemWeakness> = Vec::new();
+    for i in left
+        .weaknesses
+        .ailment_data
+        .items
+        .iter()
+        .chain(&right.weaknesses.ailment_data.items)
+    {
+        if items.iter().all(|item| item.key() != i.item.key()) {
+            items.push(&i.item);
+        }
+    }
+
+    items
+        .into_iter()
+        .map(|i| (i.clone(), effective(left, i), effective(right, i)))
+        .collect()
+}
+
+/// A material both monsters drop, with the best chance of each over every rank and source.
+pub struct SharedDrop<'a> {
+    pub material: &'a str,
+    pub left: u8,
+    pub right: u8,
+}
+
+/// Materials dropped by both monsters, by name.
+pub fn shared_drops<'a>(left: &'a MonsterEntity, right: &'a MonsterEntity) -> Vec<SharedDrop<'a>> {
+    let best_chances = |monster: &'a MonsterEntity| {
+        let mut chances: BTreeMap<&'a str, u8> = BTreeMap::new();
+        for entry in monster.drops.entries() {
+            let chance = chances.entry(entry.material).or_default();
+            *chance = (*chance).max(entry.percentage);
+        }
+        chances
+    };
+
+    let right_chances = best_chances(right);
+    best_chances(left)
+        .into_iter()
+        .filter_map(|(material, left)| {
+            right_chances.get(material).map(|right| SharedDrop {
+                material,
+                left,
+                right: *right,
+            })
+        })
+        .collect()
+}
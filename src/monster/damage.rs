@@ -0,0 +1,101 @@
+use crate::{
+    util::cycle,
+    weapon::{DamageType, Sharpness, WeaponType},
+};
+
+use super::monster::{DamageData, MonsterElements};
+
+/// Hitzone values in the data are stored divided by 5 (a 45 hitzone is written as 9).
+const HITZONE_SCALE: f32 = 5.0;
+
+const ATTACK_ELEMENTS: [MonsterElements; 6] = [
+    MonsterElements::None,
+    MonsterElements::Fire,
+    MonsterElements::Water,
+    MonsterElements::Thunder,
+    MonsterElements::Ice,
+    MonsterElements::Dragon,
+];
+
+/// Weapon stats the calculator works with.
+#[derive(Clone, Debug)]
+pub struct Loadout {
+    pub weapon: WeaponType,
+    pub attack: u16,
+    pub element: MonsterElements,
+    pub element_value: u16,
+    pub sharpness: Sharpness,
+}
+
+impl Default for Loadout {
+    fn default() -> Self {
+        Self {
+            weapon: WeaponType::default(),
+            attack: 200,
+            element: MonsterElements::None,
+            element_value: 0,
+            sharpness: Sharpness::default(),
+        }
+    }
+}
+
+impl Loadout {
+    pub fn next_element(&mut self) {
+        self.element = cycle(&ATTACK_ELEMENTS, self.element.clone(), 1);
+    }
+
+    pub fn prev_element(&mut self) {
+        self.element = cycle(
+            &ATTACK_ELEMENTS,
+            self.element.clone(),
+            ATTACK_ELEMENTS.len() - 1,
+        );
+    }
+
+    fn sharpness_multipliers(&self) -> (f32, f32) {
+        if self.weapon.uses_sharpness() {
+            (
+                self.sharpness.raw_multiplier(),
+                self.sharpness.element_multiplier(),
+            )
+        } else {
+            (1.0, 1.0)
+        }
+    }
+
+    /// Damage of a single 100 motion value hit on `part`.
+    pub fn effective_damage(&self, part: &DamageData) -> PartDamage {
+        let (raw_sharpness, element_sharpness) = self.sharpness_multipliers();
+
+        let raw_hitzone = match self.weapon.damage_type() {
+            DamageType::Cut => part.weapon.cut_damage,
+            DamageType::Blunt => part.weapon.blunt_damage,
+            DamageType::Ammo => part.weapon.ammo_damage,
+        };
+
+        let element_hitzone = part.element.get(&self.element);
+
+        let raw = self.attack as f32 * hitzone_ratio(raw_hitzone) * raw_sharpness;
+        // displayed element is ten times the element applied per hit
+        let element =
+            self.element_value as f32 / 10.0 * hitzone_ratio(element_hitzone) * element_sharpness;
+
+        PartDamage { raw, element }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PartDamage {
+    pub raw: f32,
+    pub element: f32,
+}
+
+impl PartDamage {
+    pub fn total(&self) -> f32 {
+        self.raw + self.element
+    }
+}
+
+fn hitzone_ratio(value: u8) -> f32 {
+    value as f32 * HITZONE_SCALE / 100.0
+}
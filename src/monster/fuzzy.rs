@@ -0,0 +1,158 @@
+const MATCH_SCORE: i64 = 16;
+const WORD_START_BONUS: i64 = 10;
+const FIRST_CHAR_BONUS: i64 = 6;
+const CONSECUTIVE_BONUS: i64 = 8;
+const GAP_START_PENALTY: i64 = 3;
+const GAP_EXTEND_PENALTY: i64 = 1;
+const MAX_GAP_PENALTY: i64 = 12;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FuzzyMatch {
+    pub score: i64,
+    /// Char positions in the text, ascending.
+    pub indices: Vec<usize>,
+}
+
+/// Case-insensitive subsequence match of `pattern` in `text`, whitespace in
+/// the pattern is ignored. Matches on word starts and runs of consecutive
+/// characters score higher, so "rthls" finds "Rathalos" and "rath" ranks
+/// "Rathian" above "Guardian Rathalos".
+pub fn fuzzy_match(pattern: &str, text: &str) -> Option<FuzzyMatch> {
+    let pattern: Vec<char> = pattern
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(fold_case)
+        .collect();
+    let text: Vec<char> = text.chars().map(fold_case).collect();
+
+    if pattern.is_empty() {
+        return Some(FuzzyMatch::default());
+    }
+
+    // find where the first full match ends, then walk back from there to the
+    // latest possible start so the match is as tight as possible
+    let mut p = 0;
+    let mut end = None;
+    for (i, c) in text.iter().enumerate() {
+        if *c == pattern[p] {
+            p += 1;
+            if p == pattern.len() {
+                end = Some(i);
+                break;
+            }
+        }
+    }
+    let end = end?;
+
+    let mut p = pattern.len();
+    let mut start = end;
+    for i in (0..=end).rev() {
+        if text[i] == pattern[p - 1] {
+            p -= 1;
+            if p == 0 {
+                start = i;
+                break;
+            }
+        }
+    }
+
+    let mut indices = Vec::with_capacity(pattern.len());
+    let mut p = 0;
+    for (i, c) in text.iter().enumerate().take(end + 1).skip(start) {
+        if p < pattern.len() && *c == pattern[p] {
+            indices.push(i);
+            p += 1;
+        }
+    }
+
+    let score = score(&text, &indices);
+    Some(FuzzyMatch { score, indices })
+}
+
+fn score(text: &[char], indices: &[usize]) -> i64 {
+    let mut score = 0;
+    let mut prev: Option<usize> = None;
+
+    for &i in indices {
+        score += MATCH_SCORE;
+
+        if i == 0 {
+            score += WORD_START_BONUS + FIRST_CHAR_BONUS;
+        } else if is_separator(text[i - 1]) {
+            score += WORD_START_BONUS;
+        }
+
+        if let Some(prev) = prev {
+            let gap = (i - prev - 1) as i64;
+            if gap == 0 {
+                score += CONSECUTIVE_BONUS;
+            } else {
+                score -= (GAP_START_PENALTY + gap * GAP_EXTEND_PENALTY).min(MAX_GAP_PENALTY);
+            }
+        }
+
+        prev = Some(i);
+    }
+
+    score
+}
+
+fn is_separator(c: char) -> bool {
+    c.is_whitespace() || matches!(c, '-' | '_' | '\'')
+}
+
+/// Lowercase without changing the char count, so indices line up with the text.
+fn fold_case(c: char) -> char {
+    c.to_lowercase().next().unwrap_or(c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn score(pattern: &str, text: &str) -> i64 {
+        fuzzy_match(pattern, text).unwrap().score
+    }
+
+    #[test]
+    fn abbreviation_ranks_the_closest_name_first() {
+        let names = ["Guardian Rathalos", "Rathian", "Rathalos", "Arkveld"];
+        let mut ranked: Vec<(&str, i64)> = names
+            .iter()
+            .filter_map(|name| fuzzy_match("rthls", name).map(|m| (*name, m.score)))
+            .collect();
+        ranked.sort_by_key(|(_, score)| -score);
+
+        assert_eq!(
+            ranked.iter().map(|(name, _)| *name).collect::<Vec<&str>>(),
+            ["Rathalos", "Guardian Rathalos"]
+        );
+    }
+
+    #[test]
+    fn word_starts_and_runs_score_higher() {
+        assert!(score("rath", "Rathian") > score("rath", "Guardian Rathalos"));
+        assert!(score("rath", "Rathalos") > score("rath", "Rxaxtxh"));
+    }
+
+    #[test]
+    fn highlight_indices() {
+        let m = fuzzy_match("rthls", "Rathalos").unwrap();
+        assert_eq!(m.indices, [0, 2, 3, 5, 7]);
+
+        // the match is as tight as possible
+        let m = fuzzy_match("rath", "Guardian Rathalos").unwrap();
+        assert_eq!(m.indices, [9, 10, 11, 12]);
+
+        // case and spaces in the pattern are ignored
+        let m = fuzzy_match("R  TH", "Rathalos").unwrap();
+        assert_eq!(m.indices, [0, 2, 3]);
+    }
+
+    #[test]
+    fn no_match() {
+        assert_eq!(fuzzy_match("xyz", "Rathalos"), None);
+        assert_eq!(fuzzy_match("solhtar", "Rathalos"), None);
+        assert_eq!(fuzzy_match("", "Rathalos"), Some(FuzzyMatch::default()));
+    }
+}
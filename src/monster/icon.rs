@@ -0,0 +1,149 @@
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs,
+    path::PathBuf,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    thread,
+};
+
+use ansi_to_tui::IntoText;
+use ratatui::text::Text;
+
+/// Directory holding the icon files, next to the executable or in the working directory.
+const ICON_DIR: &str = "icons";
+
+#[cfg(feature = "embed-icons")]
+static EMBEDDED_ICONS: include_dir::Dir = include_dir::include_dir!("$CARGO_MANIFEST_DIR/icons");
+
+/// Where the `icon_code` files are read from.
+#[derive(Debug, Clone)]
+pub enum IconSource {
+    Dir(PathBuf),
+    /// The `icons/` directory baked into the binary.
+    #[cfg(feature = "embed-icons")]
+    Embedded,
+}
+
+impl IconSource {
+    /// Pick the icon source in order: the `--icons` argument, an `icons`
+    /// directory next to the executable, one in the working directory and
+    /// finally the embedded copy.
+    pub fn resolve(cli_dir: Option<PathBuf>) -> Self {
+        if let Some(dir) = cli_dir {
+            return IconSource::Dir(dir);
+        }
+
+        let exe_dir = std::env::current_exe()
+            .ok()
+            .and_then(|exe| exe.parent().map(|dir| dir.join(ICON_DIR)));
+
+        let found = [exe_dir.clone(), Some(PathBuf::from(ICON_DIR))]
+            .into_iter()
+            .flatten()
+            .find(|dir| dir.is_dir());
+        if let Some(dir) = found {
+            return IconSource::Dir(dir);
+        }
+
+        #[cfg(feature = "embed-icons")]
+        return IconSource::Embedded;
+
+        // a missing directory still resolves, every icon then shows the placeholder
+        #[cfg(not(feature = "embed-icons"))]
+        IconSource::Dir(exe_dir.unwrap_or_else(|| PathBuf::from(ICON_DIR)))
+    }
+
+    pub fn read(&self, code: &str) -> Option<Vec<u8>> {
+        match self {
+            IconSource::Dir(dir) => fs::read(dir.join(code)).ok(),
+            #[cfg(feature = "embed-icons")]
+            IconSource::Embedded => EMBEDDED_ICONS
+                .get_file(code)
+                .map(|file| file.contents().to_vec()),
+        }
+    }
+
+    pub fn contains(&self, code: &str) -> bool {
+        match self {
+            IconSource::Dir(dir) => dir.join(code).is_file(),
+            #[cfg(feature = "embed-icons")]
+            IconSource::Embedded => EMBEDDED_ICONS.get_file(code).is_some(),
+        }
+    }
+}
+
+impl Default for IconSource {
+    fn default() -> Self {
+        IconSource::Dir(PathBuf::from(ICON_DIR))
+    }
+}
+
+impl Display for IconSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IconSource::Dir(dir) => write!(f, "{}", dir.display()),
+            #[cfg(feature = "embed-icons")]
+            IconSource::Embedded => write!(f, "embedded icons"),
+        }
+    }
+}
+
+type IconMap = HashMap<String, Option<Arc<Text<'static>>>>;
+
+/// Parsed ANSI icons keyed by `icon_code`. Each icon file is read and parsed
+/// once; a missing or broken file is remembered as `None` so it is not retried
+/// on every frame either.
+#[derive(Clone, Default)]
+pub struct IconCache {
+    source: IconSource,
+    icons: Arc<Mutex<IconMap>>,
+}
+
+impl IconCache {
+    pub fn new(source: IconSource) -> Self {
+        Self {
+            source,
+            icons: Arc::default(),
+        }
+    }
+
+    /// Parse `codes` on a background thread so browsing does not wait on disk.
+    pub fn preload(&self, codes: Vec<String>) {
+        let source = self.source.clone();
+        let icons = Arc::clone(&self.icons);
+
+        thread::spawn(move || {
+            for code in codes {
+                if lock(&icons).contains_key(&code) {
+                    continue;
+                }
+
+                let icon = load(&source, &code);
+                lock(&icons).entry(code).or_insert(icon);
+            }
+        });
+    }
+
+    /// The icon for `code`, parsed now if the preload has not reached it yet.
+    pub fn get(&self, code: &str) -> Option<Arc<Text<'static>>> {
+        if let Some(icon) = lock(&self.icons).get(code) {
+            return icon.clone();
+        }
+
+        let icon = load(&self.source, code);
+        lock(&self.icons)
+            .entry(code.to_string())
+            .or_insert(icon)
+            .clone()
+    }
+}
+
+fn lock(icons: &Mutex<IconMap>) -> MutexGuard<'_, IconMap> {
+    icons.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn load(source: &IconSource, code: &str) -> Option<Arc<Text<'static>>> {
+    let buffer = source.read(code)?;
+    buffer.into_text().ok().map(Arc::new)
+}
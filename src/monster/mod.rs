@@ -1,9 +1,14 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
+pub mod compare;
+pub mod damage;
+pub mod fuzzy;
+pub mod icon;
 #[allow(clippy::module_inception)]
 pub mod monster;
+pub mod query;
 
-#[derive(Deserialize, Default, Clone)]
+#[derive(Serialize, Deserialize, Default, Clone)]
 pub struct MonsterDescText {
     pub original: String,
     pub desc: Vec<String>,
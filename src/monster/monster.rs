@@ -1,13 +1,14 @@
 use std::fmt::Display;
 
 use ratatui::style::Color;
-use serde::{de::Visitor, Deserialize};
+use serde::{de::Visitor, Deserialize, Serialize};
 
 use super::MonsterDescText;
+use crate::theme::{glyph::Glyphs, Theme};
 
 pub type MonsterDescriptionAbility = [String; 2];
 
-#[derive(Deserialize, Clone, Default)]
+#[derive(Serialize, Deserialize, Clone, Default)]
 pub struct MonsterEntity {
     pub id: u16,
     pub name: MonsterName,
@@ -21,6 +22,21 @@ pub struct MonsterEntity {
     pub drops: MonsterDrops,
 }
 
+impl MonsterEntity {
+    /// Whether `key` is this monster's id, name, alias or icon code (case-insensitive).
+    pub fn is_named(&self, key: &str) -> bool {
+        let key = key.trim();
+        self.id.to_string() == key
+            || self.name.name.eq_ignore_ascii_case(key)
+            || self
+                .name
+                .aliases
+                .iter()
+                .any(|a| a.eq_ignore_ascii_case(key))
+            || self.icon_code.eq_ignore_ascii_case(key)
+    }
+}
+
 #[derive(Default, Clone)]
 pub enum Roarskind {
     Weak,
@@ -29,6 +45,27 @@ pub enum Roarskind {
     None,
 }
 
+impl Roarskind {
+    pub fn from_key(key: &str) -> Option<Self> {
+        use Roarskind::*;
+        match key {
+            "weak" => Some(Weak),
+            "strong" => Some(Strong),
+            "none" => Some(None),
+            _ => Option::None,
+        }
+    }
+
+    pub fn key(&self) -> &'static str {
+        use Roarskind::*;
+        match self {
+            Weak => "weak",
+            Strong => "strong",
+            None => "none",
+        }
+    }
+}
+
 impl Display for Roarskind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         use Roarskind::*;
@@ -40,6 +77,15 @@ impl Display for Roarskind {
     }
 }
 
+impl Serialize for Roarskind {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.key())
+    }
+}
+
 impl<'de> Deserialize<'de> for Roarskind {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -62,18 +108,13 @@ impl<'de> Visitor<'de> for RoarskindVisitor {
     where
         E: serde::de::Error,
     {
-        use Roarskind::*;
-
-        match v {
-            "weak" => Ok(Weak),
-            "strong" => Ok(Strong),
-            _ => Ok(None),
-        }
+        Ok(Roarskind::from_key(v).unwrap_or_default())
     }
 }
 
 #[derive(Default, Clone)]
 pub enum WindPressureKind {
+    Dragon,
     Strong,
     Weak,
     Minor,
@@ -81,10 +122,36 @@ pub enum WindPressureKind {
     None,
 }
 
+impl WindPressureKind {
+    pub fn from_key(key: &str) -> Option<Self> {
+        use WindPressureKind::*;
+        match key {
+            "dragon" => Some(Dragon),
+            "strong" => Some(Strong),
+            "weak" => Some(Weak),
+            "minor" => Some(Minor),
+            "none" => Some(None),
+            _ => Option::None,
+        }
+    }
+
+    pub fn key(&self) -> &'static str {
+        use WindPressureKind::*;
+        match self {
+            Dragon => "dragon",
+            Strong => "strong",
+            Weak => "weak",
+            Minor => "minor",
+            None => "none",
+        }
+    }
+}
+
 impl Display for WindPressureKind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         use WindPressureKind::*;
         match self {
+            Dragon => write!(f, "Dragon"),
             Strong => write!(f, "Strong"),
             Weak => write!(f, "Weak"),
             Minor => write!(f, "Minor"),
@@ -93,6 +160,15 @@ impl Display for WindPressureKind {
     }
 }
 
+impl Serialize for WindPressureKind {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.key())
+    }
+}
+
 impl<'de> Deserialize<'de> for WindPressureKind {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -115,13 +191,7 @@ impl<'de> Visitor<'de> for WindPressureKindVisitor {
     where
         E: serde::de::Error,
     {
-        use WindPressureKind::*;
-        match v {
-            "strong" => Ok(Strong),
-            "weak" => Ok(Weak),
-            "minor" => Ok(Minor),
-            _ => Ok(None),
-        }
+        Ok(WindPressureKind::from_key(v).unwrap_or_default())
     }
 }
 
@@ -134,6 +204,29 @@ pub enum TremorKind {
     None,
 }
 
+impl TremorKind {
+    pub fn from_key(key: &str) -> Option<Self> {
+        use TremorKind::*;
+        match key {
+            "strong" => Some(Strong),
+            "weak" => Some(Weak),
+            "minor" => Some(Minor),
+            "none" => Some(None),
+            _ => Option::None,
+        }
+    }
+
+    pub fn key(&self) -> &'static str {
+        use TremorKind::*;
+        match self {
+            Strong => "strong",
+            Weak => "weak",
+            Minor => "minor",
+            None => "none",
+        }
+    }
+}
+
 impl Display for TremorKind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         use TremorKind::*;
@@ -146,6 +239,15 @@ impl Display for TremorKind {
     }
 }
 
+impl Serialize for TremorKind {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.key())
+    }
+}
+
 impl<'de> Deserialize<'de> for TremorKind {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -168,18 +270,11 @@ impl<'de> Visitor<'de> for TremorKindVisitor {
     where
         E: serde::de::Error,
     {
-        use TremorKind::*;
-
-        match v {
-            "strong" => Ok(Strong),
-            "weak" => Ok(Weak),
-            "minor" => Ok(Minor),
-            _ => Ok(None),
-        }
+        Ok(TremorKind::from_key(v).unwrap_or_default())
     }
 }
 
-#[derive(Default, Clone, Debug)]
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
 pub enum QuestType {
     #[default]
     Assignments,
@@ -188,6 +283,29 @@ pub enum QuestType {
     Arena,
 }
 
+impl QuestType {
+    pub fn from_key(key: &str) -> Option<Self> {
+        use QuestType::*;
+        match key {
+            "assignments" => Some(Assignments),
+            "optional" => Some(Optional),
+            "event" => Some(Event),
+            "arena" => Some(Arena),
+            _ => None,
+        }
+    }
+
+    pub fn key(&self) -> &'static str {
+        use QuestType::*;
+        match self {
+            Assignments => "assignments",
+            Optional => "optional",
+            Event => "event",
+            Arena => "arena",
+        }
+    }
+}
+
 impl Display for QuestType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         use QuestType::*;
@@ -200,6 +318,15 @@ impl Display for QuestType {
     }
 }
 
+impl Serialize for QuestType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.key())
+    }
+}
+
 impl<'de> Deserialize<'de> for QuestType {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -222,15 +349,7 @@ impl<'de> Visitor<'de> for QuestTypeVisitor {
     where
         E: serde::de::Error,
     {
-        use QuestType::*;
-
-        match v {
-            "assignments" => Ok(Assignments),
-            "optional" => Ok(Optional),
-            "event" => Ok(Event),
-            "arena" => Ok(Arena),
-            _ => Ok(Optional),
-        }
+        Ok(QuestType::from_key(v).unwrap_or(QuestType::Optional))
     }
 }
 
@@ -246,27 +365,55 @@ pub enum StatusAilments {
 }
 
 impl StatusAilments {
-    pub fn icon(&self) -> String {
+    pub fn from_key(key: &str) -> Option<Self> {
+        use StatusAilments::*;
+        match key {
+            "poison" => Some(Poison),
+            "stun" => Some(Stun),
+            "paralysis" => Some(Paralysis),
+            "sleep" => Some(Sleep),
+            "blast" => Some(Blast),
+            "exhaust" => Some(Exhaust),
+            _ => None,
+        }
+    }
+
+    pub fn key(&self) -> &'static str {
         use StatusAilments::*;
         match self {
-            Poison => "󰻊".to_string(),
-            Stun => "󰙴".to_string(),
-            Paralysis => "".to_string(),
-            Sleep => "󰒲".to_string(),
-            Blast => "".to_string(),
-            Exhaust => "".to_string(),
+            Poison => "poison",
+            Stun => "stun",
+            Paralysis => "paralysis",
+            Sleep => "sleep",
+            Blast => "blast",
+            Exhaust => "exhaust",
         }
     }
 
-    pub fn color(&self) -> Color {
+    pub fn icon(&self, glyphs: &Glyphs) -> String {
         use StatusAilments::*;
+        let icons = &glyphs.ailments;
         match self {
-            Poison => Color::Rgb(126, 39, 219),
-            Stun => Color::Yellow,
-            Paralysis => Color::Yellow,
-            Sleep => Color::Cyan,
-            Blast => Color::DarkGray,
-            Exhaust => Color::Gray,
+            Poison => icons.poison,
+            Stun => icons.stun,
+            Paralysis => icons.paralysis,
+            Sleep => icons.sleep,
+            Blast => icons.blast,
+            Exhaust => icons.exhaust,
+        }
+        .to_string()
+    }
+
+    pub fn color(&self, theme: &Theme) -> Color {
+        use StatusAilments::*;
+        let colors = &theme.ailments;
+        match self {
+            Poison => colors.poison,
+            Stun => colors.stun,
+            Paralysis => colors.paralysis,
+            Sleep => colors.sleep,
+            Blast => colors.blast,
+            Exhaust => colors.exhaust,
         }
     }
 }
@@ -285,6 +432,15 @@ impl Display for StatusAilments {
     }
 }
 
+impl Serialize for StatusAilments {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.key())
+    }
+}
+
 impl<'de> Deserialize<'de> for StatusAilments {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -307,17 +463,7 @@ impl<'de> Visitor<'de> for StatusAilmentsVisitor {
     where
         E: serde::de::Error,
     {
-        use StatusAilments::*;
-
-        match v {
-            "poison" => Ok(Poison),
-            "stun" => Ok(Stun),
-            "paralysis" => Ok(Paralysis),
-            "sleep" => Ok(Sleep),
-            "blast" => Ok(Blast),
-            "exhaust" => Ok(Exhaust),
-            _ => Ok(Stun),
-        }
+        Ok(StatusAilments::from_key(v).unwrap_or_default())
     }
 }
 
@@ -332,6 +478,33 @@ pub enum ItemWeakness {
     Dungpod,
 }
 
+impl ItemWeakness {
+    pub fn from_key(key: &str) -> Option<Self> {
+        use ItemWeakness::*;
+        match key {
+            "pitfall" => Some(Pitfall),
+            "shock" => Some(Shock),
+            "meats" => Some(Meats),
+            "flashpod" => Some(Flashpod),
+            "sonicpod" => Some(Sonicpod),
+            "dungpod" => Some(Dungpod),
+            _ => None,
+        }
+    }
+
+    pub fn key(&self) -> &'static str {
+        use ItemWeakness::*;
+        match self {
+            Pitfall => "pitfall",
+            Shock => "shock",
+            Meats => "meats",
+            Flashpod => "flashpod",
+            Sonicpod => "sonicpod",
+            Dungpod => "dungpod",
+        }
+    }
+}
+
 impl Display for ItemWeakness {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         use ItemWeakness::*;
@@ -346,6 +519,15 @@ impl Display for ItemWeakness {
     }
 }
 
+impl Serialize for ItemWeakness {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.key())
+    }
+}
+
 impl<'de> Deserialize<'de> for ItemWeakness {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -368,53 +550,43 @@ impl<'de> Visitor<'de> for ItemWeaknessVisitor {
     where
         E: serde::de::Error,
     {
-        use ItemWeakness::*;
-
-        match v {
-            "pitfall" => Ok(Pitfall),
-            "shock" => Ok(Shock),
-            "meats" => Ok(Meats),
-            "flashpod" => Ok(Flashpod),
-            "sonicpod" => Ok(Sonicpod),
-            "dungpod" => Ok(Dungpod),
-            _ => Ok(Flashpod),
-        }
+        Ok(ItemWeakness::from_key(v).unwrap_or_default())
     }
 }
 
-#[derive(Deserialize, Default, Clone, Debug)]
+#[derive(Serialize, Deserialize, Default, Clone, Debug)]
 pub struct MonsterQuestData {
     pub quest_type: QuestType,
     pub level: u8,
     pub name: String,
 }
 
-#[derive(Deserialize, Clone, Default)]
+#[derive(Serialize, Deserialize, Clone, Default)]
 pub struct MonsterStatusAilment {
     pub ailment: StatusAilments,
     pub eff: u8,
 }
 
-#[derive(Deserialize, Clone, Default)]
+#[derive(Serialize, Deserialize, Clone, Default)]
 pub struct MonsterStatusItem {
     pub item: ItemWeakness,
     pub is_effective: bool,
 }
 
-#[derive(Deserialize, Clone, Default)]
+#[derive(Serialize, Deserialize, Clone, Default)]
 pub struct StatusAilmentItem {
     pub status: [MonsterStatusAilment; 6],
     pub items: [MonsterStatusItem; 6],
 }
 
-#[derive(Deserialize, Clone, Default)]
+#[derive(Serialize, Deserialize, Clone, Default)]
 pub struct WeaponDamageData {
     pub cut_damage: u8,
     pub blunt_damage: u8,
     pub ammo_damage: u8,
 }
 
-#[derive(Deserialize, Clone, Default)]
+#[derive(Serialize, Deserialize, Clone, Default)]
 pub struct ElementDamageData {
     pub fire_damage: u8,
     pub water_damage: u8,
@@ -423,38 +595,72 @@ pub struct ElementDamageData {
     pub dragon_damage: u8,
 }
 
-#[derive(Deserialize, Clone, Default)]
+impl ElementDamageData {
+    /// Hitzone of `element`, 0 for elements without a hitzone.
+    pub fn get(&self, element: &MonsterElements) -> u8 {
+        match element {
+            MonsterElements::Fire => self.fire_damage,
+            MonsterElements::Water => self.water_damage,
+            MonsterElements::Thunder => self.thunder_damage,
+            MonsterElements::Ice => self.ice_damage,
+            MonsterElements::Dragon => self.dragon_damage,
+            _ => 0,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Default)]
 pub struct DamageData {
     pub monster_part: String,
     pub weapon: WeaponDamageData,
     pub element: ElementDamageData,
 }
 
-#[derive(Deserialize, Clone, Default)]
+#[derive(Serialize, Deserialize, Clone, Default)]
 pub struct MonsterWeaknesses {
     pub dmg_data: Vec<DamageData>,
     pub ailment_data: StatusAilmentItem,
 }
 
-#[derive(Deserialize, Default, Clone)]
+impl MonsterWeaknesses {
+    /// Best hitzone of `element` over every part.
+    pub fn max_element_hitzone(&self, element: &MonsterElements) -> u8 {
+        self.dmg_data
+            .iter()
+            .map(|d| d.element.get(element))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Whether `element` has the best hitzone of all elements.
+    pub fn is_weak_to(&self, element: &MonsterElements) -> bool {
+        let hitzone = self.max_element_hitzone(element);
+        hitzone > 0
+            && HITZONE_ELEMENTS
+                .iter()
+                .all(|e| self.max_element_hitzone(e) <= hitzone)
+    }
+}
+
+#[derive(Serialize, Deserialize, Default, Clone)]
 pub struct MaterialDrop {
     pub material: String,
     pub percentage: u8,
 }
 
-#[derive(Deserialize, Default, Clone)]
+#[derive(Serialize, Deserialize, Default, Clone)]
 pub struct DropWithPart {
     pub part: String,
     pub percentage: u8,
 }
 
-#[derive(Deserialize, Default, Clone)]
+#[derive(Serialize, Deserialize, Default, Clone)]
 pub struct MaterialDropWithPart {
     pub material: String,
     pub carve: Vec<DropWithPart>,
 }
 
-#[derive(Deserialize, Clone, Default)]
+#[derive(Serialize, Deserialize, Clone, Default)]
 pub struct MonsterMaterialsDrop {
     pub target: Vec<MaterialDrop>,
     pub broken_part: Vec<MaterialDropWithPart>,
@@ -462,13 +668,136 @@ pub struct MonsterMaterialsDrop {
     pub carve: Vec<MaterialDropWithPart>,
 }
 
-#[derive(Default, Clone, Deserialize)]
+#[derive(Default, Clone, Serialize, Deserialize)]
 pub struct MonsterDrops {
     pub low_rank: MonsterMaterialsDrop,
     pub high_rank: MonsterMaterialsDrop,
 }
 
-#[derive(Deserialize, Default, Clone)]
+impl MonsterDrops {
+    /// Every drop of both ranks flattened into one row per material, source and part.
+    pub fn entries(&self) -> Vec<DropEntry<'_>> {
+        [
+            (DropRank::Low, &self.low_rank),
+            (DropRank::High, &self.high_rank),
+        ]
+        .into_iter()
+        .flat_map(|(rank, drops)| drops.entries(rank))
+        .collect()
+    }
+}
+
+impl MonsterMaterialsDrop {
+    fn entries(&self, rank: DropRank) -> Vec<DropEntry<'_>> {
+        let mut entries = plain_entries(rank, DropSource::Target, &self.target);
+        entries.extend(part_entries(
+            rank,
+            DropSource::BrokenPart,
+            &self.broken_part,
+        ));
+        entries.extend(plain_entries(
+            rank,
+            DropSource::WoundDestroy,
+            &self.wound_destroy,
+        ));
+        entries.extend(part_entries(rank, DropSource::Carve, &self.carve));
+        entries
+    }
+}
+
+fn plain_entries(rank: DropRank, source: DropSource, drops: &[MaterialDrop]) -> Vec<DropEntry<'_>> {
+    drops
+        .iter()
+        .enumerate()
+        .map(|(row, d)| DropEntry {
+            rank,
+            source,
+            row,
+            material: &d.material,
+            part: None,
+            percentage: d.percentage,
+        })
+        .collect()
+}
+
+fn part_entries(
+    rank: DropRank,
+    source: DropSource,
+    drops: &[MaterialDropWithPart],
+) -> Vec<DropEntry<'_>> {
+    drops
+        .iter()
+        .enumerate()
+        .flat_map(|(row, d)| {
+            d.carve.iter().map(move |c| DropEntry {
+                rank,
+                source,
+                row,
+                material: &d.material,
+                part: Some(&c.part),
+                percentage: c.percentage,
+            })
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DropRank {
+    Low,
+    High,
+}
+
+impl DropRank {
+    pub fn toggle(self) -> Self {
+        match self {
+            DropRank::Low => DropRank::High,
+            DropRank::High => DropRank::Low,
+        }
+    }
+}
+
+impl Display for DropRank {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DropRank::Low => write!(f, "Low Rank"),
+            DropRank::High => write!(f, "High Rank"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DropSource {
+    Target,
+    BrokenPart,
+    WoundDestroy,
+    Carve,
+}
+
+impl Display for DropSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use DropSource::*;
+        match self {
+            Target => write!(f, "Target"),
+            BrokenPart => write!(f, "Broken Part"),
+            WoundDestroy => write!(f, "Wound Destroy"),
+            Carve => write!(f, "Carve"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct DropEntry<'a> {
+    pub rank: DropRank,
+    pub source: DropSource,
+    /// Row of the material in its `source` table.
+    pub row: usize,
+    pub material: &'a str,
+    pub part: Option<&'a str>,
+    pub percentage: u8,
+}
+
+#[derive(Serialize, Deserialize, Default, Clone)]
 pub struct MonsterHabitatData {
     pub region: String,
     pub starting_area: Vec<u8>,
@@ -476,7 +805,7 @@ pub struct MonsterHabitatData {
     pub resting_area: u8,
 }
 
-#[derive(Deserialize, Default, Clone)]
+#[derive(Serialize, Deserialize, Default, Clone)]
 pub struct MonsterBasicInfo {
     #[serde(rename = "type")]
     pub m_type: String,
@@ -506,36 +835,85 @@ pub enum MonsterStatusEffects {
     MinorBubbleBlight,
     MajorBubbleBlight,
     Webbed,
-    NotRegister,
+    /// A key that is not in the dataset, kept so it is written back unchanged.
+    NotRegister(String),
     #[default]
     None,
 }
 
 impl MonsterStatusEffects {
-    // TODO: fix color: change the dark color to brighter one
-    pub fn color(&self) -> Color {
+    pub fn from_key(key: &str) -> Option<Self> {
+        use MonsterStatusEffects::*;
+        match key {
+            "fireblight" => Some(Fireblight),
+            "blastblight" => Some(Blastblight),
+            "waterblight" => Some(Waterblight),
+            "thunderblight" => Some(Thunderblight),
+            "iceblight" => Some(Iceblight),
+            "frostblight" => Some(Frostblight),
+            "dragonblight" => Some(Dragonblight),
+            "frenzy" => Some(Frenzy),
+            "paralysis" => Some(Paralysis),
+            "sleep" => Some(Sleep),
+            "poison" => Some(Poison),
+            "stench" => Some(Stench),
+            "defense down" => Some(DefenseDown),
+            "bleeding" => Some(Bleeding),
+            "flash" => Some(Flash),
+            "minor bubbleblight" => Some(MinorBubbleBlight),
+            "major bubbleblight" => Some(MajorBubbleBlight),
+            "webbed" => Some(Webbed),
+            "none" => Some(None),
+            _ => Option::None,
+        }
+    }
+
+    pub fn key(&self) -> &str {
+        use MonsterStatusEffects::*;
+        match self {
+            Fireblight => "fireblight",
+            Blastblight => "blastblight",
+            Waterblight => "waterblight",
+            Thunderblight => "thunderblight",
+            Iceblight => "iceblight",
+            Frostblight => "frostblight",
+            Dragonblight => "dragonblight",
+            Frenzy => "frenzy",
+            Paralysis => "paralysis",
+            Sleep => "sleep",
+            Poison => "poison",
+            Stench => "stench",
+            DefenseDown => "defense down",
+            Bleeding => "bleeding",
+            Flash => "flash",
+            MinorBubbleBlight => "minor bubbleblight",
+            MajorBubbleBlight => "major bubbleblight",
+            Webbed => "webbed",
+            NotRegister(key) => key,
+            None => "none",
+        }
+    }
+
+    pub fn color(&self, theme: &Theme) -> Color {
         use MonsterStatusEffects::*;
         match self {
-            Fireblight => Color::Red,
-            Blastblight => Color::Rgb(217, 98, 24),
-            Waterblight => Color::Rgb(66, 135, 245),
-            Thunderblight => Color::Yellow,
-            Iceblight => Color::Cyan,
-            Frostblight => Color::White,
-            Dragonblight => Color::Rgb(154, 88, 237),
-            Frenzy => Color::Rgb(150, 0, 237),
-            Paralysis => Color::Yellow,
-            Sleep => Color::Cyan,
-            Poison => Color::Rgb(126, 39, 219),
-            Stench => Color::Rgb(172, 191, 2),
-            DefenseDown => Color::LightBlue,
-            Bleeding => Color::Red,
-            Flash => Color::White,
-            MinorBubbleBlight => Color::White,
-            MajorBubbleBlight => Color::White,
-            Webbed => Color::White,
-            NotRegister => Color::White,
-            None => Color::Black,
+            Fireblight => theme.elements.fire,
+            Blastblight => theme.effects.blastblight,
+            Waterblight => theme.elements.water,
+            Thunderblight => theme.elements.thunder,
+            Iceblight => theme.elements.ice,
+            Frostblight => theme.effects.frostblight,
+            Dragonblight => theme.elements.dragon,
+            Frenzy => theme.effects.frenzy,
+            Paralysis => theme.ailments.paralysis,
+            Sleep => theme.ailments.sleep,
+            Poison => theme.ailments.poison,
+            Stench => theme.effects.stench,
+            DefenseDown => theme.effects.defense_down,
+            Bleeding => theme.effects.bleeding,
+            Flash | MinorBubbleBlight | MajorBubbleBlight | Webbed | NotRegister(_) | None => {
+                theme.effects.other
+            }
         }
     }
 }
@@ -562,12 +940,21 @@ impl Display for MonsterStatusEffects {
             MinorBubbleBlight => write!(f, "Minor Bubble Blight"),
             MajorBubbleBlight => write!(f, "Major Bubble Blight"),
             Webbed => write!(f, "Webbed"),
-            NotRegister => write!(f, "Not Register"),
+            NotRegister(_) => write!(f, "Not Register"),
             None => write!(f, "None"),
         }
     }
 }
 
+impl Serialize for MonsterStatusEffects {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.key())
+    }
+}
+
 impl<'de> Deserialize<'de> for MonsterStatusEffects {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -590,40 +977,30 @@ impl<'de> Visitor<'de> for MonsterStatusEffectsVisitor {
     where
         E: serde::de::Error,
     {
-        use MonsterStatusEffects::*;
+        Ok(MonsterStatusEffects::from_key(v)
+            .unwrap_or_else(|| MonsterStatusEffects::NotRegister(v.to_string())))
+    }
+}
 
-        match v {
-            "fireblight" => Ok(Fireblight),
-            "blastblight" => Ok(Blastblight),
-            "waterblight" => Ok(Waterblight),
-            "thunderblight" => Ok(Thunderblight),
-            "iceblight" => Ok(Iceblight),
-            "frostblight" => Ok(Frostblight),
-            "dragonblight" => Ok(Dragonblight),
-            "frenzy" => Ok(Frenzy),
-            "paralysis" => Ok(Paralysis),
-            "sleep" => Ok(Sleep),
-            "poison" => Ok(Poison),
-            "stench" => Ok(Stench),
-            "defense down" => Ok(DefenseDown),
-            "bleeding" => Ok(Bleeding),
-            "flash" => Ok(Flash),
-            "minor bubbleblight" => Ok(MinorBubbleBlight),
-            "major bubbleblight" => Ok(MajorBubbleBlight),
-            "webbed" => Ok(Webbed),
-            "none" => Ok(None),
-            _ => Ok(NotRegister),
-        }
-    }
-}
-
-#[derive(Deserialize, Default, Clone)]
+#[derive(Serialize, Deserialize, Default, Clone)]
 pub struct MonsterName {
     pub name: String,
     pub aka: String,
+    /// Extra names the search matches, e.g. "rath" or "kut-ku".
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
 }
 
-#[derive(Debug, Clone, Default)]
+/// Elements with a column in the hitzone table.
+pub const HITZONE_ELEMENTS: [MonsterElements; 5] = [
+    MonsterElements::Fire,
+    MonsterElements::Water,
+    MonsterElements::Thunder,
+    MonsterElements::Ice,
+    MonsterElements::Dragon,
+];
+
+#[derive(Debug, Clone, Default, PartialEq)]
 pub enum MonsterElements {
     Fire,
     Water,
@@ -636,32 +1013,61 @@ pub enum MonsterElements {
 }
 
 impl MonsterElements {
-    // TODO: fix color: change the dark color to brighter one
-    pub fn color(&self) -> Color {
+    pub fn from_key(key: &str) -> Option<Self> {
+        use MonsterElements::*;
+        match key {
+            "fire" => Some(Fire),
+            "water" => Some(Water),
+            "thunder" => Some(Thunder),
+            "ice" => Some(Ice),
+            "dragon" => Some(Dragon),
+            "poison" => Some(Poison),
+            "none" => Some(None),
+            _ => Option::None,
+        }
+    }
+
+    pub fn key(&self) -> &'static str {
         use MonsterElements::*;
         match self {
-            Fire => Color::Red,
-            Water => Color::Rgb(66, 135, 245),
-            Thunder => Color::Yellow,
-            Ice => Color::Cyan,
-            Dragon => Color::Rgb(154, 88, 237),
-            Poison => Color::Rgb(126, 39, 219),
-            None => Color::Black,
+            Fire => "fire",
+            Water => "water",
+            Thunder => "thunder",
+            Ice => "ice",
+            Dragon => "dragon",
+            Poison => "poison",
+            None => "none",
         }
     }
 
-    pub fn icon(&self) -> String {
+    pub fn color(&self, theme: &Theme) -> Color {
         use MonsterElements::*;
+        let colors = &theme.elements;
         match self {
-            Fire => "󰈸".to_string(),
-            Water => "󰖌".to_string(),
-            Thunder => "".to_string(),
-            Ice => "".to_string(),
-            Dragon => " ".to_string(),
-            Poison => "󰻊".to_string(),
-            None => "".to_string(),
+            Fire => colors.fire,
+            Water => colors.water,
+            Thunder => colors.thunder,
+            Ice => colors.ice,
+            Dragon => colors.dragon,
+            Poison => colors.poison,
+            None => colors.none,
         }
     }
+
+    pub fn icon(&self, glyphs: &Glyphs) -> String {
+        use MonsterElements::*;
+        let icons = &glyphs.elements;
+        match self {
+            Fire => icons.fire,
+            Water => icons.water,
+            Thunder => icons.thunder,
+            Ice => icons.ice,
+            Dragon => icons.dragon,
+            Poison => icons.poison,
+            None => "",
+        }
+        .to_string()
+    }
 }
 
 impl Display for MonsterElements {
@@ -679,6 +1085,15 @@ impl Display for MonsterElements {
     }
 }
 
+impl Serialize for MonsterElements {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.key())
+    }
+}
+
 impl<'de> Deserialize<'de> for MonsterElements {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -701,18 +1116,7 @@ impl<'de> Visitor<'de> for MonsterElementsVisitor {
     where
         E: serde::de::Error,
     {
-        use MonsterElements::*;
-
-        match v {
-            "fire" => Ok(Fire),
-            "water" => Ok(Water),
-            "thunder" => Ok(Thunder),
-            "ice" => Ok(Ice),
-            "dragon" => Ok(Dragon),
-            "poison" => Ok(Poison),
-            "none" => Ok(None),
-            _ => Ok(None),
-        }
+        Ok(MonsterElements::from_key(v).unwrap_or_default())
     }
 }
 
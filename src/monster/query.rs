@@ -0,0 +1,537 @@
+use std::{fmt::Display, iter::Peekable, str::Chars, vec::IntoIter};
+
+use super::{
+    fuzzy::{fuzzy_match, FuzzyMatch},
+    monster::{
+        ItemWeakness, MonsterElements, MonsterEntity, MonsterStatusEffects, Roarskind, TremorKind,
+        WindPressureKind, HITZONE_ELEMENTS,
+    },
+};
+
+const FIELDS: &str = "name, element, type, weak, region, ailment, roar, wind, tremor, drops, notes";
+
+/// A parsed search box query.
+///
+/// Terms are separated by spaces and all have to match. `OR` (or `|`) between
+/// terms matches either side, `-term` (or `NOT term`) excludes matches and
+/// parentheses group terms. A term is either plain text, fuzzy matched against
+/// the monster name, title and aliases, or `field:value` where the value can be quoted to keep spaces,
+/// e.g. `type:"flying wyvern" -weak:fire (region:wyveria OR drops:plate)`.
+pub enum Query {
+    Filter(Filter),
+    Not(Box<Query>),
+    And(Vec<Query>),
+    Or(Vec<Query>),
+}
+
+pub enum Filter {
+    /// Fuzzy match on `name`, `aka` or one of the aliases.
+    Name(String),
+    /// One of the monster elements.
+    Element(MonsterElements),
+    /// `basic_info.m_type` contains the text.
+    Type(String),
+    Weak(Weakness),
+    /// A habitat region contains the text.
+    Region(String),
+    /// One of the status effects the monster inflicts.
+    Ailment(MonsterStatusEffects),
+    Roar(Roarskind),
+    Wind(WindPressureKind),
+    Tremor(TremorKind),
+    /// A dropped material contains the text.
+    Drops(String),
+    /// The personal notes on the monster contain the text.
+    Notes(String),
+}
+
+pub enum Weakness {
+    /// The element with the best hitzone.
+    Element(MonsterElements),
+    /// An item that works on the monster.
+    Item(ItemWeakness),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum QueryError {
+    UnclosedQuote,
+    UnknownField(String),
+    EmptyValue(String),
+    UnknownValue { field: String, value: String },
+    Unexpected(String),
+    UnclosedParen,
+    MissingTerm(String),
+}
+
+impl Display for QueryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use QueryError::*;
+        match self {
+            UnclosedQuote => write!(f, "missing closing `\"`"),
+            UnknownField(field) => write!(f, "unknown field `{field}:` (try {FIELDS})"),
+            EmptyValue(field) => write!(f, "`{field}:` needs a value"),
+            UnknownValue { field, value } => write!(f, "unknown {field} `{value}`"),
+            Unexpected(token) => write!(f, "unexpected `{token}`"),
+            UnclosedParen => write!(f, "missing closing `)`"),
+            MissingTerm(op) => write!(f, "`{op}` needs a term after it"),
+        }
+    }
+}
+
+impl std::error::Error for QueryError {}
+
+impl Query {
+    pub fn parse(query: &str) -> Result<Self, QueryError> {
+        let tokens = tokenize(query)?;
+        if tokens.is_empty() {
+            return Ok(Query::And(Vec::new()));
+        }
+
+        let mut parser = Parser {
+            tokens: tokens.into_iter().peekable(),
+        };
+
+        let query = parser.parse_or()?;
+        match parser.peek() {
+            Some(token) => Err(QueryError::Unexpected(token.to_string())),
+            None => Ok(query),
+        }
+    }
+
+    pub fn matches(&self, monster: &MonsterEntity) -> bool {
+        self.rank(monster).is_some()
+    }
+
+    /// How well `monster` matches, `None` when it does not.
+    pub fn rank(&self, monster: &MonsterEntity) -> Option<FuzzyMatch> {
+        self.rank_with_notes(monster, "")
+    }
+
+    /// Like `rank`, with `notes` searched by `notes:` terms.
+    pub fn rank_with_notes(&self, monster: &MonsterEntity, notes: &str) -> Option<FuzzyMatch> {
+        match self {
+            Query::Filter(Filter::Name(name)) => name_match(name, monster),
+            Query::Filter(filter) => filter.matches(monster, notes).then(FuzzyMatch::default),
+            Query::Not(query) => match query.rank_with_notes(monster, notes) {
+                Some(_) => None,
+                None => Some(FuzzyMatch::default()),
+            },
+            Query::And(queries) => queries.iter().try_fold(FuzzyMatch::default(), |acc, q| {
+                let rank = q.rank_with_notes(monster, notes)?;
+                let mut indices = acc.indices;
+                indices.extend(rank.indices);
+                indices.sort_unstable();
+                indices.dedup();
+
+                Some(FuzzyMatch {
+                    score: acc.score + rank.score,
+                    indices,
+                })
+            }),
+            Query::Or(queries) => queries
+                .iter()
+                .filter_map(|q| q.rank_with_notes(monster, notes))
+                .max_by_key(|rank| rank.score),
+        }
+    }
+}
+
+/// Best match over the name, title and aliases. Matches on the name win ties
+/// and are what the monster list highlights.
+fn name_match(pattern: &str, monster: &MonsterEntity) -> Option<FuzzyMatch> {
+    let on_name = fuzzy_match(pattern, &monster.name.name);
+    let other_score = std::iter::once(&monster.name.aka)
+        .chain(monster.name.aliases.iter())
+        .filter_map(|text| fuzzy_match(pattern, text))
+        .map(|m| m.score - 1)
+        .max();
+
+    match (on_name, other_score) {
+        (Some(name), Some(score)) => Some(FuzzyMatch {
+            score: name.score.max(score),
+            indices: name.indices,
+        }),
+        (Some(name), None) => Some(name),
+        (None, score) => score.map(|score| FuzzyMatch {
+            score,
+            indices: Vec::new(),
+        }),
+    }
+}
+
+impl Filter {
+    fn parse(field: Option<&str>, value: String) -> Result<Self, QueryError> {
+        let Some(field) = field else {
+            return Ok(Filter::Name(value));
+        };
+
+        let field = field.to_lowercase();
+        if value.is_empty() {
+            return match field_known(&field) {
+                true => Err(QueryError::EmptyValue(field)),
+                false => Err(QueryError::UnknownField(field)),
+            };
+        }
+
+        let key = value.to_lowercase();
+        let unknown = || QueryError::UnknownValue {
+            field: field.clone(),
+            value: value.clone(),
+        };
+
+        let filter = match field.as_str() {
+            "name" => Filter::Name(value),
+            "element" => Filter::Element(MonsterElements::from_key(&key).ok_or_else(unknown)?),
+            "type" => Filter::Type(key),
+            "weak" => Filter::Weak(
+                MonsterElements::from_key(&key)
+                    .filter(|e| HITZONE_ELEMENTS.contains(e))
+                    .map(Weakness::Element)
+                    .or_else(|| ItemWeakness::from_key(&key).map(Weakness::Item))
+                    .ok_or_else(unknown)?,
+            ),
+            "region" => Filter::Region(key),
+            "ailment" => Filter::Ailment(MonsterStatusEffects::from_key(&key).ok_or_else(unknown)?),
+            "roar" => Filter::Roar(Roarskind::from_key(&key).ok_or_else(unknown)?),
+            "wind" => Filter::Wind(WindPressureKind::from_key(&key).ok_or_else(unknown)?),
+            "tremor" => Filter::Tremor(TremorKind::from_key(&key).ok_or_else(unknown)?),
+            "drops" => Filter::Drops(key),
+            "notes" => Filter::Notes(key),
+            _ => return Err(QueryError::UnknownField(field)),
+        };
+
+        Ok(filter)
+    }
+
+    fn matches(&self, monster: &MonsterEntity, notes: &str) -> bool {
+        let contains = |text: &str, needle: &str| text.to_lowercase().contains(needle);
+        let info = &monster.basic_info;
+
+        match self {
+            Filter::Name(name) => name_match(name, monster).is_some(),
+            Filter::Element(element) => monster.elements.contains(element),
+            Filter::Type(m_type) => contains(&info.m_type, m_type),
+            Filter::Weak(Weakness::Element(element)) => monster.weaknesses.is_weak_to(element),
+            Filter::Weak(Weakness::Item(item)) => monster
+                .weaknesses
+                .ailment_data
+                .items
+                .iter()
+                .any(|i| i.is_effective && i.item.key() == item.key()),
+            Filter::Region(region) => monster.habitats.iter().any(|h| contains(&h.region, region)),
+            Filter::Ailment(effect) => info.status_effect.iter().any(|s| s.key() == effect.key()),
+            Filter::Roar(roar) => info.roar.key() == roar.key(),
+            Filter::Wind(wind) => info.wind_pressure.key() == wind.key(),
+            Filter::Tremor(tremor) => info.tremor.key() == tremor.key(),
+            Filter::Drops(material) => monster
+                .drops
+                .entries()
+                .iter()
+                .any(|e| contains(e.material, material)),
+            Filter::Notes(text) => contains(notes, text),
+        }
+    }
+}
+
+fn field_known(field: &str) -> bool {
+    FIELDS.split(", ").any(|f| f == field)
+}
+
+enum Token {
+    LParen,
+    RParen,
+    And,
+    Or,
+    Not,
+    Term {
+        field: Option<String>,
+        value: String,
+    },
+}
+
+impl Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::LParen => write!(f, "("),
+            Token::RParen => write!(f, ")"),
+            Token::And => write!(f, "AND"),
+            Token::Or => write!(f, "OR"),
+            Token::Not => write!(f, "NOT"),
+            Token::Term {
+                field: Some(field),
+                value,
+            } => write!(f, "{field}:{value}"),
+            Token::Term { field: None, value } => write!(f, "{value}"),
+        }
+    }
+}
+
+fn tokenize(query: &str) -> Result<Vec<Token>, QueryError> {
+    let mut tokens = Vec::new();
+    let mut chars = query.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        match c {
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            '(' => {
+                chars.next();
+                tokens.push(Token::LParen);
+            }
+            ')' => {
+                chars.next();
+                tokens.push(Token::RParen);
+            }
+            '|' => {
+                chars.next();
+                tokens.push(Token::Or);
+            }
+            '-' | '!' => {
+                chars.next();
+                tokens.push(Token::Not);
+            }
+            _ => tokens.push(read_term(&mut chars)?),
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Read a word up to the next space or parenthesis. Quoted parts keep spaces.
+fn read_term(chars: &mut Peekable<Chars<'_>>) -> Result<Token, QueryError> {
+    let mut field = None;
+    let mut value = String::new();
+    let mut quoted = false;
+
+    while let Some(&c) = chars.peek() {
+        match c {
+            c if c.is_whitespace() => break,
+            '(' | ')' => break,
+            '"' => {
+                chars.next();
+                quoted = true;
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some(c) => value.push(c),
+                        None => return Err(QueryError::UnclosedQuote),
+                    }
+                }
+            }
+            ':' if field.is_none() && !quoted => {
+                chars.next();
+                field = Some(std::mem::take(&mut value));
+            }
+            c => {
+                chars.next();
+                value.push(c);
+            }
+        }
+    }
+
+    let token = match (field.is_none() && !quoted, value.as_str()) {
+        (true, "AND") => Token::And,
+        (true, "OR") => Token::Or,
+        (true, "NOT") => Token::Not,
+        _ => Token::Term { field, value },
+    };
+
+    Ok(token)
+}
+
+struct Parser {
+    tokens: Peekable<IntoIter<Token>>,
+}
+
+impl Parser {
+    fn peek(&mut self) -> Option<&Token> {
+        self.tokens.peek()
+    }
+
+    fn advance(&mut self) -> Option<Token> {
+        self.tokens.next()
+    }
+
+    fn parse_or(&mut self) -> Result<Query, QueryError> {
+        let mut queries = vec![self.parse_and()?];
+
+        while let Some(Token::Or) = self.peek() {
+            self.advance();
+            queries.push(self.parse_operand("OR")?);
+        }
+
+        Ok(collapse(queries, Query::Or))
+    }
+
+    fn parse_and(&mut self) -> Result<Query, QueryError> {
+        let mut queries = vec![self.parse_unary()?];
+
+        loop {
+            match self.peek() {
+                None | Some(Token::RParen) | Some(Token::Or) => break,
+                Some(Token::And) => {
+                    self.advance();
+                    queries.push(self.parse_operand("AND")?);
+                }
+                Some(_) => queries.push(self.parse_unary()?),
+            }
+        }
+
+        Ok(collapse(queries, Query::And))
+    }
+
+    /// The term right after a binary operator.
+    fn parse_operand(&mut self, op: &str) -> Result<Query, QueryError> {
+        match self.peek() {
+            None | Some(Token::RParen) => Err(QueryError::MissingTerm(op.to_string())),
+            _ => self.parse_unary(),
+        }
+    }
+
+    fn parse_unary(&mut self) -> Result<Query, QueryError> {
+        match self.advance() {
+            Some(Token::Not) => match self.peek() {
+                None | Some(Token::RParen) => Err(QueryError::MissingTerm(String::from("NOT"))),
+                _ => Ok(Query::Not(Box::new(self.parse_unary()?))),
+            },
+            Some(Token::LParen) => {
+                let query = self.parse_or()?;
+                match self.advance() {
+                    Some(Token::RParen) => Ok(query),
+                    _ => Err(QueryError::UnclosedParen),
+                }
+            }
+            Some(Token::Term { field, value }) => {
+                Ok(Query::Filter(Filter::parse(field.as_deref(), value)?))
+            }
+            Some(token) => Err(QueryError::Unexpected(token.to_string())),
+            // only reachable right after an opening parenthesis
+            None => Err(QueryError::UnclosedParen),
+        }
+    }
+}
+
+fn collapse(mut queries: Vec<Query>, combine: fn(Vec<Query>) -> Query) -> Query {
+    if queries.len() == 1 {
+        queries.remove(0)
+    } else {
+        combine(queries)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monster::monster::{MonsterHabitatData, MonsterName};
+
+    fn monster(name: &str, m_type: &str, region: &str) -> MonsterEntity {
+        let mut monster = MonsterEntity {
+            name: MonsterName {
+                name: name.to_string(),
+                ..Default::default()
+            },
+            habitats: vec![MonsterHabitatData {
+                region: region.to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        monster.basic_info.m_type = m_type.to_string();
+        monster
+    }
+
+    fn matches(query: &str, monster: &MonsterEntity) -> bool {
+        Query::parse(query).unwrap().matches(monster)
+    }
+
+    #[test]
+    fn and_binds_tighter_than_or() {
+        let rathalos = monster("Rathalos", "Flying Wyvern", "Windward Plains");
+        let arkveld = monster("Arkveld", "Flying Wyvern", "Ruins of Wyveria");
+
+        // `a b OR c` is `(a b) OR c`
+        let query = Query::parse("region:plains type:wyvern OR name:arkveld").unwrap();
+        assert!(matches!(&query, Query::Or(queries) if matches!(queries[0], Query::And(_))));
+        assert!(query.matches(&rathalos));
+        assert!(query.matches(&arkveld));
+
+        assert!(!matches(
+            "region:plains (type:wyvern OR name:arkveld)",
+            &arkveld
+        ));
+        assert!(matches(
+            "region:plains AND type:wyvern | name:arkveld",
+            &arkveld
+        ));
+    }
+
+    #[test]
+    fn not_applies_to_the_next_term_only() {
+        let rathalos = monster("Rathalos", "Flying Wyvern", "Windward Plains");
+
+        assert!(matches("-region:ruins type:wyvern", &rathalos));
+        assert!(!matches("NOT region:plains OR name:arkveld", &rathalos));
+        assert!(matches("NOT (region:ruins OR name:arkveld)", &rathalos));
+    }
+
+    #[test]
+    fn quoted_values_keep_spaces() {
+        let rathalos = monster("Rathalos", "Flying Wyvern", "Windward Plains");
+        let doshaguma = monster("Doshaguma", "Fanged Beast", "Windward Plains");
+
+        assert!(matches("type:\"flying wyvern\"", &rathalos));
+        assert!(!matches("type:\"flying wyvern\"", &doshaguma));
+        assert!(matches(
+            "region:\"windward plains\" \"doshaguma\"",
+            &doshaguma
+        ));
+
+        // quoted operators are plain terms
+        let query = Query::parse("\"OR\"").unwrap();
+        assert!(matches!(query, Query::Filter(Filter::Name(name)) if name == "OR"));
+    }
+
+    #[test]
+    fn invalid_queries() {
+        let error = |query: &str| Query::parse(query).err();
+
+        assert_eq!(error("type:\"flying"), Some(QueryError::UnclosedQuote));
+        assert_eq!(
+            error("colour:red"),
+            Some(QueryError::UnknownField(String::from("colour")))
+        );
+        assert_eq!(
+            error("type:"),
+            Some(QueryError::EmptyValue(String::from("type")))
+        );
+        assert_eq!(
+            error("element:wind"),
+            Some(QueryError::UnknownValue {
+                field: String::from("element"),
+                value: String::from("wind"),
+            })
+        );
+        assert_eq!(error("(rath"), Some(QueryError::UnclosedParen));
+        assert_eq!(
+            error("rath)"),
+            Some(QueryError::Unexpected(String::from(")")))
+        );
+        assert_eq!(
+            error("rath OR"),
+            Some(QueryError::MissingTerm(String::from("OR")))
+        );
+        assert_eq!(
+            error("rath -"),
+            Some(QueryError::MissingTerm(String::from("NOT")))
+        );
+    }
+
+    #[test]
+    fn empty_query_matches_everything() {
+        assert!(matches(
+            "",
+            &monster("Rathalos", "Flying Wyvern", "Windward Plains")
+        ));
+    }
+}
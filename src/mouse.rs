@@ -0,0 +1,38 @@
+use ratatui::{
+    crossterm::event::{MouseButton, MouseEvent, MouseEventKind},
+    layout::Position,
+};
+
+use crate::{
+    keybinding::KeyHandleResult,
+    state::{AppState, CurrentScreen, InputMode},
+};
+
+/// Route a mouse event to the pane under the pointer, hit-tested against the last render.
+pub fn handle_mouse(event: MouseEvent, app: &mut AppState) -> KeyHandleResult {
+    if !matches!(app.current_screen, CurrentScreen::Monster)
+        || matches!(app.tui_state.input_mode, InputMode::Editing)
+        || app.monster_list.notes_state.editing
+        || app.calculator.open
+        || app.compare.open
+        || app.recent.open
+        || app.hunt_log.record.open
+    {
+        return KeyHandleResult::Continue;
+    }
+
+    let position = Position::new(event.column, event.row);
+    let monster_list = &mut app.monster_list;
+
+    match event.kind {
+        MouseEventKind::Down(MouseButton::Left) => monster_list.click(position),
+        MouseEventKind::ScrollDown | MouseEventKind::ScrollUp => {
+            if let Some(pane) = monster_list.pane_at(position) {
+                monster_list.scroll_pane(pane, event.kind == MouseEventKind::ScrollDown);
+            }
+        }
+        _ => {}
+    }
+
+    KeyHandleResult::Continue
+}
@@ -0,0 +1,40 @@
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    Frame,
+};
+
+use crate::{
+    state::AppState,
+    widget::{
+        armor::{builder::SetBuilder, detail::ArmorDetail, list::ArmorList},
+        monster::search::Search,
+    },
+};
+
+pub fn render_armor_screen(frame: &mut Frame, chunk: Rect, state: &mut AppState) {
+    let [detail, builder] =
+        Layout::vertical([Constraint::Min(1), Constraint::Length(9)]).areas(chunk);
+
+    frame.render_stateful_widget(
+        ArmorDetail(&state.theme, &state.glyphs),
+        detail,
+        &mut state.armor_list,
+    );
+    frame.render_widget(SetBuilder(&state.armor_list.builder, &state.theme), builder);
+}
+
+pub fn render_armor_selection(frame: &mut Frame, chunk: Rect, state: &mut AppState) {
+    let [search, armor_list] =
+        Layout::vertical([Constraint::Length(3), Constraint::Min(1)]).areas(chunk);
+
+    frame.render_stateful_widget(Search, search, state);
+    if let Some((x, y)) = state.tui_state.cursor {
+        frame.set_cursor_position((x, y))
+    };
+
+    frame.render_stateful_widget(
+        ArmorList(&state.theme, &state.glyphs),
+        armor_list,
+        &mut state.armor_list,
+    );
+}
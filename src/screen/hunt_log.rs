@@ -0,0 +1,25 @@
+use ratatui::{layout::Rect, Frame};
+
+use crate::{
+    state::AppState,
+    widget::hunt_log::{overview::HuntLogOverview, recent::RecentHunts},
+};
+
+pub fn render_hunt_log_screen(frame: &mut Frame, chunk: Rect, state: &mut AppState) {
+    frame.render_stateful_widget(
+        HuntLogOverview(&state.monster_list.bundle, &state.theme, &state.glyphs),
+        chunk,
+        &mut state.hunt_log,
+    );
+}
+
+pub fn render_hunt_log_selection(frame: &mut Frame, chunk: Rect, state: &mut AppState) {
+    frame.render_widget(
+        RecentHunts {
+            log: &state.hunt_log.log,
+            bundle: &state.monster_list.bundle,
+            theme: &state.theme,
+        },
+        chunk,
+    );
+}
@@ -4,21 +4,32 @@ use ratatui::{
     Frame,
 };
 
-use crate::{state::AppState, widget::menu_selection::MenuSelection};
+use crate::{state::AppState, theme::glyph::GlyphSet, widget::menu_selection::MenuSelection};
 
 pub struct MainScreen;
 
-pub fn render_main_screen(f: &mut Frame<'_>, chunk: Rect, state: &mut AppState) {
-    let title_monsterdex = Paragraph::new(
-        "
+const TITLE: &str = "
 ███╗   ███╗ █████╗ ███╗  ██╗ ██████╗████████╗███████╗██████╗ ███████╗██╗  ██╗
 ████╗ ████║██╔══██╗████╗ ██║██╔════╝╚══██╔══╝██╔════╝██╔══██╗██╔════╝╚██╗██╔╝
 ██╔████╔██║██║  ██║██╔██╗██║╚█████╗    ██║   █████╗  ██████╔╝█████╗   ╚███╔╝ 
 ██║╚██╔╝██║██║  ██║██║╚████║ ╚═══██╗   ██║   ██╔══╝  ██╔══██╗██╔══╝   ██╔██╗ 
 ██║ ╚═╝ ██║╚█████╔╝██║ ╚███║██████╔╝   ██║   ███████╗██║  ██║███████╗██╔╝╚██╗
 ╚═╝     ╚═╝ ╚════╝ ╚═╝  ╚══╝╚═════╝    ╚═╝   ╚══════╝╚═╝  ╚═╝╚══════╝╚═╝  ╚═╝
-        ",
-    );
+        ";
+
+const ASCII_TITLE: &str = r"
+ __  __  ___  _   _ ____ _____ _____ ____  _______  __
+|  \/  |/ _ \| \ | / ___|_   _| ____|  _ \| ____\ \/ /
+| |\/| | | | |  \| \___ \ | | |  _| | |_) |  _|  \  /
+| |  | | |_| | |\  |___) || | | |___|  _ <| |___ /  \
+|_|  |_|\___/|_| \_|____/ |_| |_____|_| \_\_____/_/\_\
+";
+
+pub fn render_main_screen(f: &mut Frame<'_>, chunk: Rect, state: &mut AppState) {
+    let title_monsterdex = Paragraph::new(match state.glyphs.set {
+        GlyphSet::Ascii => ASCII_TITLE,
+        _ => TITLE,
+    });
 
     let [title, main_menu_chunk] = Layout::vertical([Constraint::Max(10), Constraint::Min(10)])
         .margin(5)
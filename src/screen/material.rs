@@ -0,0 +1,36 @@
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    Frame,
+};
+
+use crate::{
+    state::AppState,
+    widget::{
+        material::{list::MaterialList, sources::MaterialSourceTable},
+        monster::search::Search,
+    },
+};
+
+pub fn render_material_screen(frame: &mut Frame, chunk: Rect, state: &mut AppState) {
+    frame.render_stateful_widget(
+        MaterialSourceTable(&state.theme, &state.glyphs),
+        chunk,
+        &mut state.material_list,
+    );
+}
+
+pub fn render_material_selection(frame: &mut Frame, chunk: Rect, state: &mut AppState) {
+    let [search, material_list] =
+        Layout::vertical([Constraint::Length(3), Constraint::Min(1)]).areas(chunk);
+
+    frame.render_stateful_widget(Search, search, state);
+    if let Some((x, y)) = state.tui_state.cursor {
+        frame.set_cursor_position((x, y))
+    };
+
+    frame.render_stateful_widget(
+        MaterialList(&state.theme),
+        material_list,
+        &mut state.material_list,
+    );
+}
@@ -1,2 +1,8 @@
+pub mod armor;
+pub mod hunt_log;
 pub mod main_s;
+pub mod material;
 pub mod monster;
+pub mod quest;
+pub mod weak_to;
+pub mod weapon;
@@ -5,11 +5,28 @@ use ratatui::{
 
 use crate::{
     state::AppState,
-    widget::monster::{search::Search, selection::MonsterSelection, MonsterProfileWidget},
+    widget::monster::{
+        calculator::DamageCalculator, compare::MonsterCompare, recent::RecentMonsters,
+        record_hunt::RecordHunt, search::Search, selection::MonsterSelection, MonsterProfileWidget,
+    },
 };
 
 pub fn render_monster_screen(frame: &mut Frame, chunk: Rect, state: &mut AppState) {
-    frame.render_stateful_widget(MonsterProfileWidget, chunk, &mut state.monster_list);
+    // nothing is selected in an empty search
+    let record = (!state.monster_list.list_items().is_empty()).then(|| {
+        state
+            .hunt_log
+            .log
+            .monster_record(state.monster_list.current_data.id)
+    });
+    frame.render_stateful_widget(
+        MonsterProfileWidget(record, &state.theme, &state.glyphs),
+        chunk,
+        &mut state.monster_list,
+    );
+    if let Some((x, y)) = state.monster_list.notes_state.cursor {
+        frame.set_cursor_position((x, y))
+    };
 }
 
 pub fn render_monster_selection(frame: &mut Frame, chunk: Rect, state: &mut AppState) {
@@ -21,5 +38,51 @@ pub fn render_monster_selection(frame: &mut Frame, chunk: Rect, state: &mut AppS
         frame.set_cursor_position((x, y))
     };
 
-    frame.render_stateful_widget(MonsterSelection, monster_list, &mut state.monster_list);
+    frame.render_stateful_widget(
+        MonsterSelection(&state.theme, &state.glyphs),
+        monster_list,
+        &mut state.monster_list,
+    );
+}
+
+pub fn render_damage_calculator(frame: &mut Frame, chunk: Rect, state: &mut AppState) {
+    frame.render_stateful_widget(
+        DamageCalculator(
+            state.monster_list.get_damage_data(),
+            &state.theme,
+            &state.glyphs,
+        ),
+        chunk,
+        &mut state.calculator,
+    );
+}
+
+pub fn render_monster_compare(frame: &mut Frame, chunk: Rect, state: &mut AppState) {
+    frame.render_stateful_widget(MonsterCompare(&state.theme), chunk, &mut state.compare);
+}
+
+pub fn render_recent_monsters(frame: &mut Frame, chunk: Rect, state: &mut AppState) {
+    frame.render_stateful_widget(
+        RecentMonsters(state.monster_list.recent_monsters(), &state.theme),
+        chunk,
+        &mut state.recent,
+    );
+}
+
+pub fn render_record_hunt(frame: &mut Frame, chunk: Rect, state: &mut AppState) {
+    let record = state
+        .hunt_log
+        .log
+        .monster_record(state.hunt_log.record.monster_id);
+    let name = state
+        .monster_list
+        .bundle
+        .iter()
+        .find(|monster| monster.id == record.monster_id)
+        .map(|monster| monster.name.name.clone())
+        .unwrap_or_default();
+    frame.render_widget(
+        RecordHunt::new(name, &state.hunt_log.record, record, &state.theme),
+        chunk,
+    );
 }
@@ -0,0 +1,36 @@
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    Frame,
+};
+
+use crate::{
+    state::AppState,
+    widget::{
+        monster::search::Search,
+        quest::{detail::QuestDetail, list::QuestList},
+    },
+};
+
+pub fn render_quest_screen(frame: &mut Frame, chunk: Rect, state: &mut AppState) {
+    frame.render_stateful_widget(
+        QuestDetail(&state.theme, &state.glyphs),
+        chunk,
+        &mut state.quest_list,
+    );
+}
+
+pub fn render_quest_selection(frame: &mut Frame, chunk: Rect, state: &mut AppState) {
+    let [search, quest_list] =
+        Layout::vertical([Constraint::Length(3), Constraint::Min(1)]).areas(chunk);
+
+    frame.render_stateful_widget(Search, search, state);
+    if let Some((x, y)) = state.tui_state.cursor {
+        frame.set_cursor_position((x, y))
+    };
+
+    frame.render_stateful_widget(
+        QuestList(&state.theme, &state.glyphs),
+        quest_list,
+        &mut state.quest_list,
+    );
+}
@@ -0,0 +1,22 @@
+use ratatui::{layout::Rect, Frame};
+
+use crate::{
+    state::AppState,
+    widget::weak_to::{list::AttackList, ranking::WeakToRanking},
+};
+
+pub fn render_weak_to_screen(frame: &mut Frame, chunk: Rect, state: &mut AppState) {
+    frame.render_stateful_widget(
+        WeakToRanking(&state.theme, &state.glyphs),
+        chunk,
+        &mut state.weak_to,
+    );
+}
+
+pub fn render_weak_to_selection(frame: &mut Frame, chunk: Rect, state: &mut AppState) {
+    frame.render_stateful_widget(
+        AttackList(&state.theme, &state.glyphs),
+        chunk,
+        &mut state.weak_to,
+    );
+}
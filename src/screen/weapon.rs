@@ -0,0 +1,36 @@
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    Frame,
+};
+
+use crate::{
+    state::AppState,
+    widget::{
+        monster::search::Search,
+        weapon::{detail::WeaponDetail, list::WeaponList},
+    },
+};
+
+pub fn render_weapon_screen(frame: &mut Frame, chunk: Rect, state: &mut AppState) {
+    frame.render_stateful_widget(
+        WeaponDetail(&state.theme, &state.glyphs),
+        chunk,
+        &mut state.weapon_list,
+    );
+}
+
+pub fn render_weapon_selection(frame: &mut Frame, chunk: Rect, state: &mut AppState) {
+    let [search, weapon_list] =
+        Layout::vertical([Constraint::Length(3), Constraint::Min(1)]).areas(chunk);
+
+    frame.render_stateful_widget(Search, search, state);
+    if let Some((x, y)) = state.tui_state.cursor {
+        frame.set_cursor_position((x, y))
+    };
+
+    frame.render_stateful_widget(
+        WeaponList(&state.theme, &state.glyphs),
+        weapon_list,
+        &mut state.weapon_list,
+    );
+}
@@ -0,0 +1,42 @@
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::data::{
+    app_data_dir,
+    store::{load_json, save_json, StoreError},
+};
+
+/// Monsters kept in the recent list, the oldest one is dropped first.
+pub const RECENT_LIMIT: usize = 10;
+
+/// What the app remembers between runs, by monster id.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct Session {
+    /// Starred monsters.
+    pub favorites: Vec<u16>,
+    /// Last viewed monsters, the most recent first.
+    pub recent: Vec<u16>,
+    /// Key of the screen open on exit.
+    pub last_screen: Option<String>,
+    /// Monster selected on exit.
+    pub last_monster: Option<u16>,
+}
+
+impl Session {
+    /// Read the session at `path`, an empty one when there is no file yet.
+    pub fn load(path: &Path) -> Result<Self, StoreError> {
+        load_json("state", path)
+    }
+
+    /// Write the session to `path`, creating its directory.
+    pub fn save(&self, path: &Path) -> Result<(), StoreError> {
+        save_json("state", self, path)
+    }
+}
+
+/// `$XDG_DATA_HOME/monsterex/state.json` (or the platform equivalent).
+pub fn default_session_path() -> Option<PathBuf> {
+    app_data_dir().map(|dir| dir.join("state.json"))
+}
@@ -0,0 +1,105 @@
+use std::rc::Rc;
+
+use crate::{
+    armor::{ArmorEntity, ArmorKind, ArmorRank, ArmorSet},
+    data::material_index::MaterialIndex,
+    util::next_filter,
+    weapon::CraftingMaterial,
+};
+
+use super::list_detail::{ListDetailState, ListEntry};
+
+impl ListEntry for Rc<ArmorEntity> {
+    fn detail_len(&self) -> usize {
+        self.materials.len()
+    }
+}
+
+/// Armor pieces, the crafting materials of the selected one as details, and the set builder.
+#[derive(Default)]
+pub struct ArmorListState {
+    /// Every piece grouped by rank and set, head to legs.
+    pub list: ListDetailState<Rc<ArmorEntity>>,
+    pub filter_query: String,
+    pub rank_filter: Option<ArmorRank>,
+    pub kind_filter: Option<ArmorKind>,
+    pub materials: MaterialIndex,
+    pub builder: ArmorSet,
+}
+
+impl ArmorListState {
+    pub fn new(armor: Vec<ArmorEntity>, materials: MaterialIndex) -> Self {
+        Self {
+            list: ListDetailState::new(grouped_pieces(armor)),
+            materials,
+            ..Default::default()
+        }
+    }
+
+    pub fn selected_material(&self) -> Option<&CraftingMaterial> {
+        self.list
+            .current_data
+            .materials
+            .get(self.list.selected_detail()?)
+    }
+
+    /// Add the selected piece to the set builder, or remove it when already worn.
+    pub fn toggle_piece(&mut self) {
+        if self.list.list_items().is_empty() {
+            return;
+        }
+
+        let piece = Rc::clone(&self.list.current_data);
+        self.builder.toggle(&piece);
+    }
+
+    /// Cycle low rank, high rank, then back to every rank.
+    pub fn cycle_rank_filter(&mut self) {
+        self.rank_filter = next_filter(&ArmorRank::ALL, self.rank_filter);
+        self.apply_filter();
+    }
+
+    /// Cycle head to legs, then back to every kind.
+    pub fn cycle_kind_filter(&mut self) {
+        self.kind_filter = next_filter(&ArmorKind::ALL, self.kind_filter);
+        self.apply_filter();
+    }
+
+    pub(crate) fn set_list_filter(&mut self, filter: String) {
+        self.filter_query = filter;
+        self.apply_filter();
+    }
+
+    fn apply_filter(&mut self) {
+        let query = self.filter_query.to_lowercase();
+        let filtered =
+            !query.is_empty() || self.rank_filter.is_some() || self.kind_filter.is_some();
+        self.list.filter(filtered, |piece| {
+            (piece.name.to_lowercase().contains(&query)
+                || piece.set.to_lowercase().contains(&query))
+                && self.rank_filter.is_none_or(|r| piece.rank == r)
+                && self.kind_filter.is_none_or(|k| piece.kind == k)
+        });
+    }
+
+    pub fn reset(&mut self) {
+        self.list.reset();
+    }
+}
+
+/// Order pieces by rank, then set in data order, then head to legs.
+fn grouped_pieces(armor: Vec<ArmorEntity>) -> Vec<Rc<ArmorEntity>> {
+    let mut sets: Vec<String> = vec![];
+    for piece in &armor {
+        if !sets.contains(&piece.set) {
+            sets.push(piece.set.clone());
+        }
+    }
+
+    let mut pieces: Vec<Rc<ArmorEntity>> = armor.into_iter().map(Rc::new).collect();
+    pieces.sort_by_key(|piece| {
+        let set_order = sets.iter().position(|s| *s == piece.set);
+        (piece.rank, set_order, piece.kind, piece.id)
+    });
+    pieces
+}
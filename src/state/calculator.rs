@@ -0,0 +1,109 @@
+use crate::monster::damage::Loadout;
+
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub enum CalculatorField {
+    #[default]
+    Weapon,
+    Attack,
+    Element,
+    ElementValue,
+    Sharpness,
+}
+
+impl CalculatorField {
+    pub fn next(self) -> Self {
+        use CalculatorField::*;
+        match self {
+            Weapon => Attack,
+            Attack => Element,
+            Element => ElementValue,
+            ElementValue => Sharpness,
+            Sharpness => Weapon,
+        }
+    }
+
+    pub fn prev(self) -> Self {
+        use CalculatorField::*;
+        match self {
+            Weapon => Sharpness,
+            Attack => Weapon,
+            Element => Attack,
+            ElementValue => Element,
+            Sharpness => ElementValue,
+        }
+    }
+}
+
+const MAX_STAT_VALUE: u16 = 9999;
+
+/// The damage calculator popup. The loadout is kept while switching monsters.
+#[derive(Default)]
+pub struct CalculatorState {
+    pub open: bool,
+    pub loadout: Loadout,
+    pub field: CalculatorField,
+}
+
+impl CalculatorState {
+    pub fn toggle(&mut self) {
+        self.open = !self.open;
+    }
+
+    pub fn next_field(&mut self) {
+        self.field = self.field.next();
+    }
+
+    pub fn prev_field(&mut self) {
+        self.field = self.field.prev();
+    }
+
+    pub fn increase(&mut self) {
+        let loadout = &mut self.loadout;
+        match self.field {
+            CalculatorField::Weapon => loadout.weapon = loadout.weapon.next(),
+            CalculatorField::Attack => loadout.attack = step_up(loadout.attack),
+            CalculatorField::Element => loadout.next_element(),
+            CalculatorField::ElementValue => loadout.element_value = step_up(loadout.element_value),
+            CalculatorField::Sharpness => loadout.sharpness = loadout.sharpness.next(),
+        }
+    }
+
+    pub fn decrease(&mut self) {
+        let loadout = &mut self.loadout;
+        match self.field {
+            CalculatorField::Weapon => loadout.weapon = loadout.weapon.prev(),
+            CalculatorField::Attack => loadout.attack = loadout.attack.saturating_sub(10),
+            CalculatorField::Element => loadout.prev_element(),
+            CalculatorField::ElementValue => {
+                loadout.element_value = loadout.element_value.saturating_sub(10)
+            }
+            CalculatorField::Sharpness => loadout.sharpness = loadout.sharpness.prev(),
+        }
+    }
+
+    /// Type a digit into the focused number field.
+    pub fn push_digit(&mut self, digit: u32) {
+        if let Some(value) = self.focused_number() {
+            let typed = (*value as u32) * 10 + digit;
+            *value = typed.min(MAX_STAT_VALUE as u32) as u16;
+        }
+    }
+
+    pub fn pop_digit(&mut self) {
+        if let Some(value) = self.focused_number() {
+            *value /= 10;
+        }
+    }
+
+    fn focused_number(&mut self) -> Option<&mut u16> {
+        match self.field {
+            CalculatorField::Attack => Some(&mut self.loadout.attack),
+            CalculatorField::ElementValue => Some(&mut self.loadout.element_value),
+            _ => None,
+        }
+    }
+}
+
+fn step_up(value: u16) -> u16 {
+    value.saturating_add(10).min(MAX_STAT_VALUE)
+}
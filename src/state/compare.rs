@@ -0,0 +1,41 @@
+use std::rc::Rc;
+
+use crate::monster::monster::MonsterEntity;
+
+use super::monster::ScrollableParagraphState;
+
+/// The comparison popup, the marked monster on the left and the selected one on the right.
+#[derive(Default)]
+pub struct CompareState {
+    pub open: bool,
+    /// Marked on the Monster screen, kept until it is marked again.
+    pub marked: Option<Rc<MonsterEntity>>,
+    /// Selected when the popup was opened.
+    pub other: Option<Rc<MonsterEntity>>,
+    pub scroll: ScrollableParagraphState,
+}
+
+impl CompareState {
+    /// Mark `monster`, or clear the mark when it is the marked one.
+    pub fn toggle_mark(&mut self, monster: &Rc<MonsterEntity>) {
+        self.marked = match &self.marked {
+            Some(marked) if marked.id == monster.id => None,
+            _ => Some(Rc::clone(monster)),
+        };
+    }
+
+    /// Compare the marked monster with `monster`, nothing to compare until one is marked.
+    pub fn open(&mut self, monster: &Rc<MonsterEntity>) {
+        if self.marked.is_none() {
+            return;
+        }
+
+        self.other = Some(Rc::clone(monster));
+        self.scroll.reset();
+        self.open = true;
+    }
+
+    pub fn close(&mut self) {
+        self.open = false;
+    }
+}
@@ -0,0 +1,203 @@
+use std::{
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use ratatui::widgets::TableState;
+
+use crate::{
+    data::hunt_log::{Hunt, HuntLog, MonsterRecord, Outcome},
+    monster::monster::DropRank,
+};
+
+/// Times a hunt on the monster it was started on.
+#[derive(Default)]
+pub struct Stopwatch {
+    /// `None` while the stopwatch is stopped.
+    pub monster_id: Option<u16>,
+    started: Option<Instant>,
+    /// Time counted before the last pause.
+    elapsed: Duration,
+}
+
+impl Stopwatch {
+    /// Start on monster `id`, or pause and resume a started stopwatch.
+    pub fn toggle(&mut self, id: u16) {
+        match (self.monster_id, self.started.take()) {
+            (None, _) => {
+                self.monster_id = Some(id);
+                self.started = Some(Instant::now());
+            }
+            (Some(_), Some(started)) => self.elapsed += started.elapsed(),
+            (Some(_), None) => self.started = Some(Instant::now()),
+        }
+    }
+
+    pub fn pause(&mut self) {
+        if let Some(started) = self.started.take() {
+            self.elapsed += started.elapsed();
+        }
+    }
+
+    pub fn stop(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.started.is_some()
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+            + self
+                .started
+                .map_or(Duration::ZERO, |started| started.elapsed())
+    }
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub enum RecordField {
+    #[default]
+    Outcome,
+    Rank,
+}
+
+/// The popup recording a finished hunt.
+pub struct RecordHuntState {
+    pub open: bool,
+    pub field: RecordField,
+    pub monster_id: u16,
+    pub outcome: Outcome,
+    pub rank: DropRank,
+    /// Stopwatch time in seconds, `None` when the hunt was not timed.
+    pub time: Option<u64>,
+}
+
+impl Default for RecordHuntState {
+    fn default() -> Self {
+        Self {
+            open: false,
+            field: RecordField::default(),
+            monster_id: 0,
+            outcome: Outcome::default(),
+            rank: DropRank::High,
+            time: None,
+        }
+    }
+}
+
+impl RecordHuntState {
+    pub fn toggle_field(&mut self) {
+        self.field = match self.field {
+            RecordField::Outcome => RecordField::Rank,
+            RecordField::Rank => RecordField::Outcome,
+        };
+    }
+
+    /// Switch the value of the selected field.
+    pub fn toggle_value(&mut self) {
+        match self.field {
+            RecordField::Outcome => self.outcome = self.outcome.toggle(),
+            RecordField::Rank => self.rank = self.rank.toggle(),
+        }
+    }
+}
+
+/// The hunt log with its screen, and the stopwatch and popup filling it.
+#[derive(Default)]
+pub struct HuntLogState {
+    pub log: HuntLog,
+    /// Where `log` is saved after each hunt, nowhere without a path.
+    pub path: Option<PathBuf>,
+    /// Why the log could not be saved.
+    pub error: Option<String>,
+    pub stopwatch: Stopwatch,
+    pub record: RecordHuntState,
+    /// Hunted monsters on the Hunt Log screen.
+    pub table_state: TableState,
+}
+
+impl HuntLogState {
+    pub fn new(log: HuntLog, path: Option<PathBuf>) -> Self {
+        Self {
+            log,
+            path,
+            table_state: TableState::default().with_selected(Some(0)),
+            ..Default::default()
+        }
+    }
+
+    /// Record a hunt on the stopwatch's monster, else on `monster_id`. The stopwatch pauses.
+    pub fn open_record(&mut self, monster_id: u16) {
+        self.stopwatch.pause();
+
+        let record = &mut self.record;
+        record.monster_id = self.stopwatch.monster_id.unwrap_or(monster_id);
+        record.time = self
+            .stopwatch
+            .monster_id
+            .map(|_| self.stopwatch.elapsed().as_secs());
+        record.field = RecordField::Outcome;
+        record.open = true;
+    }
+
+    pub fn close_record(&mut self) {
+        self.record.open = false;
+    }
+
+    /// Log the hunt of the popup and save the log, the stopwatch is done with.
+    pub fn save_record(&mut self) {
+        let record = &self.record;
+        self.log.record(Hunt::new(
+            record.monster_id,
+            record.rank,
+            record.outcome,
+            record.time,
+        ));
+        self.record.open = false;
+        self.stopwatch.stop();
+
+        self.error = self
+            .path
+            .as_deref()
+            .and_then(|path| self.log.save(path).err())
+            .map(|err| err.to_string());
+    }
+
+    pub fn next(&mut self) {
+        let len = self.log.monster_records().len();
+        if len == 0 {
+            return;
+        }
+
+        let idx = match self.table_state.selected() {
+            Some(i) if i < len - 1 => i + 1,
+            _ => 0,
+        };
+        self.table_state.select(Some(idx));
+    }
+
+    pub fn prev(&mut self) {
+        let len = self.log.monster_records().len();
+        if len == 0 {
+            return;
+        }
+
+        let idx = match self.table_state.selected() {
+            Some(i) if i > 0 => i - 1,
+            _ => len - 1,
+        };
+        self.table_state.select(Some(idx));
+    }
+
+    pub fn selected_record(&self) -> Option<MonsterRecord> {
+        self.log
+            .monster_records()
+            .get(self.table_state.selected()?)
+            .copied()
+    }
+
+    pub fn reset(&mut self) {
+        self.table_state.select(Some(0));
+    }
+}
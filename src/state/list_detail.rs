@@ -0,0 +1,156 @@
+use ratatui::widgets::{ListState, ScrollbarState, TableState};
+
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub enum ListPane {
+    #[default]
+    List,
+    /// The table of the selected item, like the sources of a material.
+    Detail,
+}
+
+/// An item of a list screen, the rows of its detail table counted.
+pub trait ListEntry: Clone + Default {
+    fn detail_len(&self) -> usize;
+}
+
+/// A searchable list and the detail table of its selected item, the Material, Quest,
+/// Weapon and Armor screens.
+#[derive(Default)]
+pub struct ListDetailState<T> {
+    pub list_state: ListState,
+    pub list_scrollbar_state: ScrollbarState,
+    pub detail_table_state: TableState,
+    pub focused_pane: ListPane,
+    /// Every item in list order.
+    pub items: Vec<T>,
+    pub filtered_list: Vec<T>,
+    /// Whether `filtered_list` is listed instead of `items`.
+    filtered: bool,
+    pub current_data: T,
+}
+
+impl<T: ListEntry> ListDetailState<T> {
+    pub fn new(items: Vec<T>) -> Self {
+        let current_data = items.first().cloned().unwrap_or_default();
+        let filtered_list = Vec::with_capacity(items.len());
+
+        Self {
+            items,
+            current_data,
+            filtered_list,
+            list_state: ListState::default().with_selected(Some(0)),
+            detail_table_state: TableState::default().with_selected(Some(0)),
+            ..Default::default()
+        }
+    }
+
+    pub fn list_items(&self) -> &Vec<T> {
+        if self.filtered {
+            &self.filtered_list
+        } else {
+            &self.items
+        }
+    }
+
+    pub fn next(&mut self) {
+        if self.list_items().is_empty() {
+            return;
+        }
+
+        let idx = match self.list_state.selected() {
+            Some(i) if i < self.list_items().len() - 1 => i + 1,
+            _ => 0,
+        };
+
+        self.select(idx);
+    }
+
+    pub fn prev(&mut self) {
+        if self.list_items().is_empty() {
+            return;
+        }
+
+        let idx = match self.list_state.selected() {
+            Some(i) if i > 0 => i - 1,
+            _ => self.list_items().len() - 1,
+        };
+
+        self.select(idx);
+    }
+
+    pub fn select(&mut self, idx: usize) {
+        self.list_state.select(Some(idx));
+        self.list_scrollbar_state = self
+            .list_scrollbar_state
+            .content_length(self.list_items().len())
+            .position(idx);
+
+        self.current_data = self.list_items().get(idx).cloned().unwrap_or_default();
+        self.detail_table_state.select(Some(0));
+    }
+
+    pub fn next_detail(&mut self) {
+        let len = self.current_data.detail_len();
+        if len == 0 {
+            return;
+        }
+
+        let idx = match self.detail_table_state.selected() {
+            Some(i) if i < len - 1 => i + 1,
+            _ => 0,
+        };
+        self.detail_table_state.select(Some(idx));
+    }
+
+    pub fn prev_detail(&mut self) {
+        let len = self.current_data.detail_len();
+        if len == 0 {
+            return;
+        }
+
+        let idx = match self.detail_table_state.selected() {
+            Some(i) if i > 0 => i - 1,
+            _ => len - 1,
+        };
+        self.detail_table_state.select(Some(idx));
+    }
+
+    /// Move the selection of the focused pane one step.
+    pub fn scroll(&mut self, forward: bool) {
+        match (self.focused_pane, forward) {
+            (ListPane::List, true) => self.next(),
+            (ListPane::List, false) => self.prev(),
+            (ListPane::Detail, true) => self.next_detail(),
+            (ListPane::Detail, false) => self.prev_detail(),
+        }
+    }
+
+    /// Row of the detail table that is selected.
+    pub fn selected_detail(&self) -> Option<usize> {
+        self.detail_table_state.selected()
+    }
+
+    pub fn toggle_focus(&mut self) {
+        self.focused_pane = match self.focused_pane {
+            ListPane::List => ListPane::Detail,
+            ListPane::Detail => ListPane::List,
+        };
+    }
+
+    /// List the items `keep` accepts, or every item when not `filtered`.
+    pub fn filter(&mut self, filtered: bool, keep: impl Fn(&T) -> bool) {
+        self.filtered = filtered;
+        if filtered {
+            self.filtered_list.clear();
+            self.filtered_list
+                .extend(self.items.iter().filter(|item| keep(item)).cloned());
+        }
+
+        self.select(0);
+    }
+
+    pub fn reset(&mut self) {
+        self.focused_pane = ListPane::List;
+        self.detail_table_state.select(Some(0));
+    }
+}
@@ -0,0 +1,64 @@
+use std::rc::Rc;
+
+use crate::data::material_index::{MaterialEntry, MaterialIndex, MaterialSource};
+
+use super::list_detail::{ListDetailState, ListEntry, ListPane};
+
+impl ListEntry for Rc<MaterialEntry> {
+    fn detail_len(&self) -> usize {
+        self.sources.len()
+    }
+}
+
+/// Materials, the sources of the selected one as details.
+#[derive(Default)]
+pub struct MaterialListState {
+    pub list: ListDetailState<Rc<MaterialEntry>>,
+    pub filter_query: String,
+    pub index: MaterialIndex,
+}
+
+impl MaterialListState {
+    pub fn new(index: MaterialIndex) -> Self {
+        Self {
+            list: ListDetailState::new(index.materials.clone()),
+            index,
+            ..Default::default()
+        }
+    }
+
+    pub fn selected_source(&self) -> Option<&MaterialSource> {
+        self.list
+            .current_data
+            .sources
+            .get(self.list.selected_detail()?)
+    }
+
+    /// Select `material` in the unfiltered list and focus its sources.
+    pub fn select_material(&mut self, material: &str) -> bool {
+        let Some(idx) = self
+            .list
+            .list_items()
+            .iter()
+            .position(|entry| entry.material == material)
+        else {
+            return false;
+        };
+
+        self.list.select(idx);
+        self.list.focused_pane = ListPane::Detail;
+        true
+    }
+
+    pub(crate) fn set_list_filter(&mut self, filter: String) {
+        self.filter_query = filter;
+        let query = self.filter_query.to_lowercase();
+        self.list.filter(!query.is_empty(), |item| {
+            item.material.to_lowercase().contains(&query)
+        });
+    }
+
+    pub fn reset(&mut self) {
+        self.list.reset();
+    }
+}
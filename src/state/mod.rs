@@ -1,8 +1,33 @@
+pub mod armor;
+pub mod calculator;
+pub mod compare;
+pub mod hunt_log;
+pub mod list_detail;
+pub mod material;
 pub mod monster;
+pub mod quest;
+pub mod recent;
 pub mod tui;
+pub mod weak_to;
+pub mod weapon;
 
+use armor::ArmorListState;
+use calculator::CalculatorState;
+use compare::CompareState;
+use hunt_log::HuntLogState;
+use material::MaterialListState;
 use monster::MonsterListState;
+use quest::QuestListState;
+use recent::RecentState;
 use tui_input::Input;
+use weak_to::WeakToState;
+use weapon::WeaponListState;
+
+use crate::{
+    keymap::{key::KeyChord, Keymap, Scope},
+    session::Session,
+    theme::{glyph::Glyphs, Theme},
+};
 
 use self::tui::TuiState;
 
@@ -10,26 +35,35 @@ use self::tui::TuiState;
 pub enum MainMenuOption {
     #[default]
     Monster,
+    Material,
     Quest,
     Weapon,
     Armor,
+    WeakTo,
+    HuntLog,
 }
 
 impl MainMenuOption {
     pub fn next(self) -> Self {
         match self {
-            MainMenuOption::Monster => MainMenuOption::Quest,
+            MainMenuOption::Monster => MainMenuOption::Material,
+            MainMenuOption::Material => MainMenuOption::Quest,
             MainMenuOption::Quest => MainMenuOption::Weapon,
             MainMenuOption::Weapon => MainMenuOption::Armor,
-            MainMenuOption::Armor => MainMenuOption::Monster,
+            MainMenuOption::Armor => MainMenuOption::WeakTo,
+            MainMenuOption::WeakTo => MainMenuOption::HuntLog,
+            MainMenuOption::HuntLog => MainMenuOption::Monster,
         }
     }
     pub fn prev(self) -> Self {
         match self {
-            MainMenuOption::Monster => MainMenuOption::Armor,
-            MainMenuOption::Quest => MainMenuOption::Monster,
+            MainMenuOption::Monster => MainMenuOption::HuntLog,
+            MainMenuOption::Material => MainMenuOption::Monster,
+            MainMenuOption::Quest => MainMenuOption::Material,
             MainMenuOption::Weapon => MainMenuOption::Quest,
             MainMenuOption::Armor => MainMenuOption::Weapon,
+            MainMenuOption::WeakTo => MainMenuOption::Armor,
+            MainMenuOption::HuntLog => MainMenuOption::WeakTo,
         }
     }
 }
@@ -39,14 +73,62 @@ pub enum CurrentScreen {
     #[default]
     Main,
     Monster,
+    Material,
     Quest,
     Weapon,
     Armor,
+    WeakTo,
+    HuntLog,
+}
+
+impl CurrentScreen {
+    pub const ALL: [CurrentScreen; 8] = [
+        CurrentScreen::Main,
+        CurrentScreen::Monster,
+        CurrentScreen::Material,
+        CurrentScreen::Quest,
+        CurrentScreen::Weapon,
+        CurrentScreen::Armor,
+        CurrentScreen::WeakTo,
+        CurrentScreen::HuntLog,
+    ];
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|screen| screen.key() == key)
+    }
+
+    pub fn key(&self) -> &'static str {
+        match self {
+            CurrentScreen::Main => "main",
+            CurrentScreen::Monster => "monster",
+            CurrentScreen::Material => "material",
+            CurrentScreen::Quest => "quest",
+            CurrentScreen::Weapon => "weapon",
+            CurrentScreen::Armor => "armor",
+            CurrentScreen::WeakTo => "weak-to",
+            CurrentScreen::HuntLog => "hunt-log",
+        }
+    }
+
+    /// The menu entry opening this screen.
+    pub fn menu_option(&self) -> MainMenuOption {
+        match self {
+            CurrentScreen::Main | CurrentScreen::Monster => MainMenuOption::Monster,
+            CurrentScreen::Material => MainMenuOption::Material,
+            CurrentScreen::Quest => MainMenuOption::Quest,
+            CurrentScreen::Weapon => MainMenuOption::Weapon,
+            CurrentScreen::Armor => MainMenuOption::Armor,
+            CurrentScreen::WeakTo => MainMenuOption::WeakTo,
+            CurrentScreen::HuntLog => MainMenuOption::HuntLog,
+        }
+    }
 }
 
 #[derive(Default)]
 pub struct KeyHandleState {
     pub input: Input,
+    /// Keys of a sequence typed so far.
+    pub pending: Vec<KeyChord>,
 }
 
 #[derive(Default)]
@@ -54,6 +136,8 @@ pub enum InputMode {
     #[default]
     Normal,
     Editing,
+    /// Typing into the notes of the selected monster.
+    Notes,
 }
 
 #[derive(Default)]
@@ -63,12 +147,80 @@ pub struct AppState {
     pub current_screen: CurrentScreen,
     pub main_menu: MainMenuOption,
     pub monster_list: MonsterListState,
+    pub material_list: MaterialListState,
+    pub quest_list: QuestListState,
+    pub weapon_list: WeaponListState,
+    pub armor_list: ArmorListState,
+    pub weak_to: WeakToState,
+    pub calculator: CalculatorState,
+    pub compare: CompareState,
+    pub recent: RecentState,
+    pub hunt_log: HuntLogState,
+    pub keymap: Keymap,
+    pub theme: Theme,
+    pub glyphs: Glyphs,
 }
 
 impl AppState {
+    /// Keymap table of what is on screen, the calculator, compare, recent and record hunt popups have their own.
+    pub fn keymap_scope(&self) -> Scope {
+        match self.current_screen {
+            CurrentScreen::Main => Scope::Main,
+            CurrentScreen::Monster if self.calculator.open => Scope::Calculator,
+            CurrentScreen::Monster if self.compare.open => Scope::Compare,
+            CurrentScreen::Monster if self.recent.open => Scope::Recent,
+            CurrentScreen::Monster if self.hunt_log.record.open => Scope::RecordHunt,
+            CurrentScreen::Monster => Scope::Monster,
+            CurrentScreen::Material => Scope::Material,
+            CurrentScreen::Quest => Scope::Quest,
+            CurrentScreen::Weapon => Scope::Weapon,
+            CurrentScreen::Armor => Scope::Armor,
+            CurrentScreen::WeakTo => Scope::WeakTo,
+            CurrentScreen::HuntLog => Scope::HuntLog,
+        }
+    }
+
     pub fn reset(&mut self) {
         self.tui_state.input_mode = InputMode::Normal;
         self.key_handle.input.reset();
+        self.monster_list.favorites_only = false;
         self.monster_list.set_list_filter(String::from(""));
+        self.material_list.set_list_filter(String::from(""));
+        self.quest_list.set_list_filter(String::from(""));
+        self.weapon_list.set_list_filter(String::from(""));
+        self.armor_list.set_list_filter(String::from(""));
+    }
+
+    /// Favorites, recent monsters and what is on screen, to be saved for the next run.
+    pub fn session(&self) -> Session {
+        Session {
+            favorites: self.monster_list.favorites.iter().copied().collect(),
+            recent: self.monster_list.recent.clone(),
+            last_screen: Some(self.current_screen.key().to_string()),
+            // nothing is selected in an empty search
+            last_monster: (!self.monster_list.list_items().is_empty())
+                .then_some(self.monster_list.current_data.id),
+        }
+    }
+
+    /// Take the favorites and recent monsters of `session`, with `restore` its screen and monster too.
+    pub fn restore_session(&mut self, session: Session, restore: bool) {
+        self.monster_list.favorites = session.favorites.into_iter().collect();
+        self.monster_list.recent = session.recent;
+        if !restore {
+            return;
+        }
+
+        if let Some(id) = session.last_monster {
+            self.monster_list.select_by_id(id);
+        }
+        if let Some(screen) = session
+            .last_screen
+            .as_deref()
+            .and_then(CurrentScreen::from_key)
+        {
+            self.current_screen = screen;
+            self.main_menu = screen.menu_option();
+        }
     }
 }
@@ -1,23 +1,78 @@
-use std::{path::PathBuf, rc::Rc};
+use std::{
+    collections::{BTreeSet, HashMap},
+    path::PathBuf,
+    rc::Rc,
+    sync::Arc,
+};
 
 use ratatui::{
-    layout::Rect,
+    crossterm::event::{Event, KeyCode, KeyEvent},
+    layout::{Position, Rect},
+    text::Text,
     widgets::{ListState, ScrollbarState, TableState},
 };
+use tui_input::{backend::crossterm::EventHandler, Input};
 
-use crate::monster::{
+use crate::{
+    data::notes::Notes,
     monster::{
-        DamageData, MonsterBasicInfo, MonsterElements, MonsterEntity, MonsterHabitatData,
-        MonsterMaterialsDrop, MonsterName, MonsterQuestData, StatusAilmentItem,
+        icon::{IconCache, IconSource},
+        monster::{
+            DamageData, DropRank, DropSource, MonsterBasicInfo, MonsterElements, MonsterEntity,
+            MonsterHabitatData, MonsterMaterialsDrop, MonsterName, MonsterQuestData,
+            StatusAilmentItem,
+        },
+        query::{Query, QueryError},
+        MonsterDescText,
     },
-    MonsterDescText,
+    session::RECENT_LIMIT,
+    util::cycle,
 };
 
+/// Panes of the Monster screen in focus order.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MonsterPane {
+    #[default]
+    Monsters,
+    Description,
+    Notes,
+    Habitats,
+    Quests,
+    Drops,
+    Weakness,
+}
+
+impl MonsterPane {
+    pub const ALL: [MonsterPane; 7] = [
+        MonsterPane::Monsters,
+        MonsterPane::Description,
+        MonsterPane::Notes,
+        MonsterPane::Habitats,
+        MonsterPane::Quests,
+        MonsterPane::Drops,
+        MonsterPane::Weakness,
+    ];
+
+    pub fn next(self) -> Self {
+        cycle(&Self::ALL, self, 1)
+    }
+
+    pub fn prev(self) -> Self {
+        cycle(&Self::ALL, self, Self::ALL.len() - 1)
+    }
+
+    /// Position of the pane in the focus order.
+    pub fn index(self) -> usize {
+        self as usize
+    }
+}
+
 #[derive(Default)]
 pub struct ScrollableParagraphState {
     pub scrollbar_state: ScrollbarState,
     pub position: usize,
     pub height: usize,
+    pub focused: bool,
 }
 
 impl ScrollableParagraphState {
@@ -49,11 +104,100 @@ impl ScrollableParagraphState {
     }
 }
 
+/// The notes pane, scrolled like the description or edited line by line.
+#[derive(Default)]
+pub struct MonsterNotesState {
+    pub scroll: ScrollableParagraphState,
+    pub focused: bool,
+    pub editing: bool,
+    /// Monster whose notes are being edited.
+    pub monster_id: u16,
+    /// Lines being edited, the one at `row` mirrors `input`.
+    pub lines: Vec<String>,
+    pub row: usize,
+    pub input: Input,
+    /// Terminal cursor of the last render while editing.
+    pub cursor: Option<(u16, u16)>,
+    /// Why the notes could not be saved.
+    pub error: Option<String>,
+}
+
+impl MonsterNotesState {
+    /// Start editing `text` of monster `id`, the cursor at its end.
+    pub fn edit(&mut self, id: u16, text: &str) {
+        self.monster_id = id;
+        self.lines = text.split('\n').map(String::from).collect();
+        self.row = self.lines.len() - 1;
+        self.input = Input::new(self.lines[self.row].clone());
+        self.editing = true;
+    }
+
+    pub fn text(&self) -> String {
+        self.lines.join("\n")
+    }
+
+    /// Edit with a key: `Enter` breaks the line, `Up`/`Down` move between lines and
+    /// `Backspace`/`Delete` join them at either end. Other keys go to the line input.
+    pub fn key(&mut self, event: KeyEvent) {
+        let len = self.input.value().chars().count();
+        match event.code {
+            KeyCode::Enter => self.break_line(),
+            KeyCode::Up if self.row > 0 => self.move_to(self.row - 1),
+            KeyCode::Down if self.row + 1 < self.lines.len() => self.move_to(self.row + 1),
+            KeyCode::Backspace if self.input.cursor() == 0 && self.row > 0 => {
+                self.move_to(self.row - 1);
+                self.join_next();
+            }
+            KeyCode::Delete if self.input.cursor() == len && self.row + 1 < self.lines.len() => {
+                self.join_next()
+            }
+            _ => {
+                self.input.handle_event(&Event::Key(event));
+                self.lines[self.row] = self.input.value().to_string();
+            }
+        }
+    }
+
+    fn break_line(&mut self) {
+        let line = self.input.value();
+        let at = line
+            .char_indices()
+            .nth(self.input.cursor())
+            .map_or(line.len(), |(i, _)| i);
+        let (before, after) = (line[..at].to_string(), line[at..].to_string());
+
+        self.lines[self.row] = before;
+        self.row += 1;
+        self.lines.insert(self.row, after.clone());
+        self.input = Input::new(after).with_cursor(0);
+    }
+
+    /// Append the next line to the current one, the cursor at its end.
+    fn join_next(&mut self) {
+        let next = self.lines.remove(self.row + 1);
+        let cursor = self.lines[self.row].chars().count();
+        self.lines[self.row].push_str(&next);
+        self.input = Input::new(self.lines[self.row].clone()).with_cursor(cursor);
+    }
+
+    /// Go to line `row`, keeping the column when it is long enough.
+    fn move_to(&mut self, row: usize) {
+        let cursor = self.input.cursor();
+        self.row = row;
+        self.input = Input::new(self.lines[row].clone()).with_cursor(cursor);
+    }
+
+    pub fn reset(&mut self) {
+        self.scroll.reset();
+    }
+}
+
 #[derive(Default)]
 pub struct MonsterOnQuestListState {
     pub list_state: ListState,
     pub scrollbar_state: ScrollbarState,
     pub list_len: u16,
+    pub focused: bool,
 }
 
 impl MonsterOnQuestListState {
@@ -64,6 +208,10 @@ impl MonsterOnQuestListState {
     }
 
     pub fn next(&mut self) {
+        if self.list_len == 0 {
+            return;
+        }
+
         let idx = if let Some(i) = self.list_state.selected() {
             if i as u16 == self.list_len - 1 {
                 0
@@ -78,6 +226,10 @@ impl MonsterOnQuestListState {
     }
 
     pub fn prev(&mut self) {
+        if self.list_len == 0 {
+            return;
+        }
+
         let idx: usize = if let Some(i) = self.list_state.selected() {
             if i == 0 {
                 self.list_len as usize - 1
@@ -123,6 +275,10 @@ pub struct MonsterWeaknessTabState {
     pub table_state: TableState,
     pub scrollbar_state: ScrollbarState,
     pub damage_data_len: u16,
+    pub focused: bool,
+    /// Title spans of the last render, for mouse clicks.
+    pub ailment_item_tab_areas: [Rect; 2],
+    pub weapon_elm_tab_areas: [Rect; 2],
 }
 
 impl MonsterWeaknessTabState {
@@ -144,6 +300,30 @@ impl MonsterWeaknessTabState {
         self.scrollbar_state = self.scrollbar_state.position(0);
     }
 
+    /// Switch to the tab whose title span is at `position`, returns false when there is none.
+    pub fn click_tab(&mut self, position: Position) -> bool {
+        use MonsterStatusAndItemWeaknessTab::*;
+        use MonsterWeaknessTab::*;
+
+        if let Some(i) = hit(&self.ailment_item_tab_areas, position) {
+            self.selected_status_item_tab = if i == 0 { Status } else { Item };
+        } else if let Some(i) = hit(&self.weapon_elm_tab_areas, position) {
+            self.selected_weapon_elm_tab = if i == 0 { WeaponDamage } else { ElementDamage };
+            self.table_state.select(Some(0));
+            self.scrollbar_state = self.scrollbar_state.position(0);
+        } else {
+            return false;
+        }
+
+        true
+    }
+
+    /// Open the `tab` hitzones with `row` selected.
+    pub fn show(&mut self, tab: MonsterWeaknessTab, row: usize) {
+        self.selected_weapon_elm_tab = tab;
+        self.select(row);
+    }
+
     pub fn reset(&mut self) {
         self.table_state.select(Some(0));
         self.scrollbar_state = self.scrollbar_state.position(0);
@@ -160,6 +340,10 @@ impl MonsterWeaknessTabState {
     }
 
     pub fn next(&mut self) {
+        if self.damage_data_len == 0 {
+            return;
+        }
+
         let idx = if let Some(id) = self.table_state.selected() {
             if id == (self.damage_data_len as usize) - 1 {
                 0
@@ -174,6 +358,10 @@ impl MonsterWeaknessTabState {
     }
 
     pub fn prev(&mut self) {
+        if self.damage_data_len == 0 {
+            return;
+        }
+
         let idx = if let Some(id) = self.table_state.selected() {
             if id == 0 {
                 (self.damage_data_len as usize) - 1
@@ -200,6 +388,15 @@ pub enum MonsterDropRankTab {
     HighRank,
 }
 
+impl From<DropRank> for MonsterDropRankTab {
+    fn from(rank: DropRank) -> Self {
+        match rank {
+            DropRank::Low => MonsterDropRankTab::LowRank,
+            DropRank::High => MonsterDropRankTab::HighRank,
+        }
+    }
+}
+
 #[derive(Default)]
 pub enum MaterialSourceTab {
     #[default]
@@ -209,6 +406,17 @@ pub enum MaterialSourceTab {
     Carve,
 }
 
+impl From<DropSource> for MaterialSourceTab {
+    fn from(source: DropSource) -> Self {
+        match source {
+            DropSource::Target => MaterialSourceTab::Target,
+            DropSource::BrokenPart => MaterialSourceTab::BrokenPart,
+            DropSource::WoundDestroy => MaterialSourceTab::WoundDestroy,
+            DropSource::Carve => MaterialSourceTab::Carve,
+        }
+    }
+}
+
 #[derive(Default)]
 pub struct MonsterDropTabState {
     pub selected_rank_tab: MonsterDropRankTab,
@@ -225,6 +433,10 @@ pub struct MonsterDropTabState {
     pub high_broken_part_len: u16,
     pub high_wound_destroy_len: u16,
     pub high_carve_len: u16,
+    pub focused: bool,
+    /// Title spans of the last render, for mouse clicks.
+    pub rank_tab_areas: [Rect; 2],
+    pub source_tab_areas: [Rect; 4],
 }
 
 impl MonsterDropTabState {
@@ -262,6 +474,29 @@ impl MonsterDropTabState {
         self.scrollbar_state = self.scrollbar_state.position(0);
     }
 
+    /// Switch to the tab whose title span is at `position`, returns false when there is none.
+    pub fn click_tab(&mut self, position: Position) -> bool {
+        use MaterialSourceTab::*;
+        use MonsterDropRankTab::*;
+
+        if let Some(i) = hit(&self.rank_tab_areas, position) {
+            self.selected_rank_tab = if i == 0 { LowRank } else { HighRank };
+        } else if let Some(i) = hit(&self.source_tab_areas, position) {
+            self.selected_source_tab = match i {
+                0 => Target,
+                1 => BrokenPart,
+                2 => WoundDestroy,
+                _ => Carve,
+            };
+        } else {
+            return false;
+        }
+
+        self.table_state.select(Some(0));
+        self.scrollbar_state = self.scrollbar_state.position(0);
+        true
+    }
+
     pub fn set_scrollbar_height(&mut self, area: Rect) {
         if self.get_current_data_use_len() * 3 > area.height - 2 {
             self.scrollbar_state = self
@@ -273,6 +508,10 @@ impl MonsterDropTabState {
     }
 
     pub fn next(&mut self) {
+        if self.get_current_data_use_len() == 0 {
+            return;
+        }
+
         let idx = if let Some(id) = self.table_state.selected() {
             if id == self.get_current_data_use_len() as usize - 1 {
                 0
@@ -287,6 +526,10 @@ impl MonsterDropTabState {
     }
 
     pub fn prev(&mut self) {
+        if self.get_current_data_use_len() == 0 {
+            return;
+        }
+
         let idx = if let Some(id) = self.table_state.selected() {
             if id == 0 {
                 self.get_current_data_use_len() as usize - 1
@@ -300,6 +543,13 @@ impl MonsterDropTabState {
         self.select(idx);
     }
 
+    /// Open the `rank` and `source` tabs with `row` selected.
+    pub fn show(&mut self, rank: DropRank, source: DropSource, row: usize) {
+        self.selected_rank_tab = rank.into();
+        self.selected_source_tab = source.into();
+        self.select(row);
+    }
+
     fn get_current_data_use_len(&self) -> u16 {
         match self.selected_rank_tab {
             MonsterDropRankTab::LowRank => match self.selected_source_tab {
@@ -334,6 +584,7 @@ impl MonsterDropTabState {
 pub struct ChangeableHabitatPageState {
     pub habitat_total_page: u8,
     pub habitat_current_page: u8,
+    pub focused: bool,
 }
 
 impl ChangeableHabitatPageState {
@@ -375,11 +626,28 @@ pub struct MonsterListState {
     pub monster_on_quest_list_state: MonsterOnQuestListState,
     pub monster_weakness_tab_state: MonsterWeaknessTabState,
     pub monster_drops_tab_state: MonsterDropTabState,
+    pub notes_state: MonsterNotesState,
     pub filter_query: String,
+    /// Set while `filter_query` does not parse, `filtered_list` keeps the last results.
+    pub filter_error: Option<QueryError>,
     pub filtered_list: Vec<Rc<MonsterEntity>>,
+    /// Matched char positions in the monster name, by monster id.
+    pub name_highlights: HashMap<u16, Vec<usize>>,
+    /// Starred monster ids.
+    pub favorites: BTreeSet<u16>,
+    /// Only list favorites, on top of `filter_query`.
+    pub favorites_only: bool,
+    /// Last viewed monster ids, the most recent first.
+    pub recent: Vec<u16>,
+    pub notes: Notes,
+    /// Where `notes` are saved after an edit, nowhere without a path.
+    pub notes_path: Option<PathBuf>,
     pub bundle: Vec<Rc<MonsterEntity>>,
     pub current_data: Rc<MonsterEntity>,
-    pub ascii_asset: PathBuf,
+    pub icons: IconCache,
+    pub focused_pane: MonsterPane,
+    /// Pane areas of the last render by `MonsterPane::index`, for mouse hit-testing.
+    pub pane_areas: [Rect; 7],
 }
 
 impl MonsterListState {
@@ -433,8 +701,22 @@ impl MonsterListState {
         })
     }
 
-    pub fn path(mut self, path: PathBuf) -> Self {
-        self.ascii_asset = path;
+    /// Read icons from `source`, parsing all of them in the background.
+    pub fn icons(mut self, source: IconSource) -> Self {
+        self.icons = IconCache::new(source);
+        self.icons.preload(
+            self.bundle
+                .iter()
+                .map(|monster| monster.icon_code.clone())
+                .collect(),
+        );
+        self
+    }
+
+    /// Start with `notes`, saved to `path` after each edit.
+    pub fn notes(mut self, notes: Notes, path: Option<PathBuf>) -> Self {
+        self.notes = notes;
+        self.notes_path = path;
         self
     }
 
@@ -517,6 +799,17 @@ impl MonsterListState {
         self.update_selected_monster_data();
     }
 
+    /// Select the monster with `id` in the current list, returns false when it is not listed.
+    pub fn select_by_id(&mut self, id: u16) -> bool {
+        match self.list_items().iter().position(|m| m.id == id) {
+            Some(idx) => {
+                self.select(idx);
+                true
+            }
+            None => false,
+        }
+    }
+
     pub fn get_quest_list(&self) -> Vec<MonsterQuestData> {
         self.current_data.quest_list.clone()
     }
@@ -541,8 +834,8 @@ impl MonsterListState {
         self.current_data.elements.clone()
     }
 
-    pub fn get_asset_path(&self) -> PathBuf {
-        self.ascii_asset.join(self.current_data.icon_code.clone())
+    pub fn get_icon(&self) -> Option<Arc<Text<'static>>> {
+        self.icons.get(&self.current_data.icon_code)
     }
 
     pub fn get_damage_data(&self) -> Vec<DamageData> {
@@ -563,35 +856,218 @@ impl MonsterListState {
 
     pub(crate) fn set_list_filter(&mut self, filter: String) {
         self.filter_query.clone_from(&filter);
+        self.filter_error = None;
+
+        let favorites_only = self.favorites_only;
+        let favorites = &self.favorites;
+        let listed = |item: &&Rc<MonsterEntity>| !favorites_only || favorites.contains(&item.id);
 
-        if !filter.is_empty() {
+        if filter.is_empty() {
+            self.name_highlights.clear();
             self.filtered_list.clear();
-            self.filtered_list.extend(
-                self.bundle
-                    .iter()
-                    .filter(|item| {
-                        item.name
-                            .name
-                            .to_lowercase()
-                            .contains(&filter.to_lowercase())
-                    })
-                    .cloned(),
-            );
+            self.filtered_list
+                .extend(self.bundle.iter().filter(listed).cloned());
+        } else {
+            match Query::parse(&filter) {
+                Ok(query) => {
+                    let mut ranked: Vec<_> = self
+                        .bundle
+                        .iter()
+                        .filter(listed)
+                        .filter_map(|item| {
+                            let notes = self.notes.get(item.id).unwrap_or_default();
+                            Some((query.rank_with_notes(item, notes)?, Rc::clone(item)))
+                        })
+                        .collect();
+                    // stable, so equal scores keep the bundle order
+                    ranked.sort_by_key(|(rank, _)| std::cmp::Reverse(rank.score));
+
+                    self.filtered_list.clear();
+                    self.name_highlights.clear();
+                    for (rank, item) in ranked {
+                        self.name_highlights.insert(item.id, rank.indices);
+                        self.filtered_list.push(item);
+                    }
+                }
+                Err(err) => self.filter_error = Some(err),
+            }
         }
 
         self.select(0);
     }
 
     pub fn list_items(&self) -> &Vec<Rc<MonsterEntity>> {
-        if self.filter_query.is_empty() {
+        if self.filter_query.is_empty() && !self.favorites_only {
             &self.bundle
         } else {
             &self.filtered_list
         }
     }
 
+    /// Notes on the selected monster.
+    pub fn current_notes(&self) -> &str {
+        self.notes.get(self.current_data.id).unwrap_or_default()
+    }
+
+    /// Open the selected monster's notes in the editor, false when no monster is listed.
+    pub fn edit_notes(&mut self) -> bool {
+        if self.list_items().is_empty() {
+            return false;
+        }
+
+        self.focus(MonsterPane::Notes);
+        let text = self.current_notes().to_string();
+        self.notes_state.edit(self.current_data.id, &text);
+        true
+    }
+
+    /// Keep the edited notes and write them to `notes_path`.
+    pub fn save_notes(&mut self) {
+        let state = &mut self.notes_state;
+        state.editing = false;
+        state.cursor = None;
+        self.notes.set(state.monster_id, &state.text());
+
+        state.error = self
+            .notes_path
+            .as_deref()
+            .and_then(|path| self.notes.save(path).err())
+            .map(|err| err.to_string());
+    }
+
+    pub fn is_favorite(&self, id: u16) -> bool {
+        self.favorites.contains(&id)
+    }
+
+    /// Star the selected monster, or unstar it. Unstarred, it leaves the favorites list.
+    pub fn toggle_favorite(&mut self) {
+        if self.list_items().is_empty() {
+            return;
+        }
+
+        let id = self.current_data.id;
+        if !self.favorites.remove(&id) {
+            self.favorites.insert(id);
+        }
+
+        if self.favorites_only {
+            let idx = self.list_state.selected().unwrap_or(0);
+            self.set_list_filter(self.filter_query.clone());
+            self.select(idx.min(self.list_items().len().saturating_sub(1)));
+        }
+    }
+
+    /// List only favorites, or every monster again, keeping the selected one if it is listed.
+    pub fn toggle_favorites_only(&mut self) {
+        let id = self.current_data.id;
+        self.favorites_only = !self.favorites_only;
+        self.set_list_filter(self.filter_query.clone());
+        self.select_by_id(id);
+    }
+
+    /// Put the selected monster first in the recent list.
+    pub fn record_visit(&mut self) {
+        if self.list_items().is_empty() {
+            return;
+        }
+
+        let id = self.current_data.id;
+        self.recent.retain(|recent| *recent != id);
+        self.recent.insert(0, id);
+        self.recent.truncate(RECENT_LIMIT);
+    }
+
+    /// Recently viewed monsters still in the data, the most recent first.
+    pub fn recent_monsters(&self) -> Vec<Rc<MonsterEntity>> {
+        self.recent
+            .iter()
+            .filter_map(|id| self.bundle.iter().find(|m| m.id == *id))
+            .cloned()
+            .collect()
+    }
+
+    /// Move the focus ring to `pane`, the pane states track it for their border.
+    pub fn focus(&mut self, pane: MonsterPane) {
+        // looking past the list counts as viewing the monster
+        if pane != MonsterPane::Monsters {
+            self.record_visit();
+        }
+
+        self.focused_pane = pane;
+        self.desc_scrollbar_state.focused = pane == MonsterPane::Description;
+        self.notes_state.focused = pane == MonsterPane::Notes;
+        self.habitat_page_state.focused = pane == MonsterPane::Habitats;
+        self.monster_on_quest_list_state.focused = pane == MonsterPane::Quests;
+        self.monster_drops_tab_state.focused = pane == MonsterPane::Drops;
+        self.monster_weakness_tab_state.focused = pane == MonsterPane::Weakness;
+    }
+
+    pub fn next_pane(&mut self) {
+        self.focus(self.focused_pane.next());
+    }
+
+    pub fn prev_pane(&mut self) {
+        self.focus(self.focused_pane.prev());
+    }
+
+    pub fn pane_at(&self, position: Position) -> Option<MonsterPane> {
+        MonsterPane::ALL
+            .into_iter()
+            .find(|pane| self.pane_areas[pane.index()].contains(position))
+    }
+
+    /// Move the selection or scroll position of `pane` one step.
+    pub fn scroll_pane(&mut self, pane: MonsterPane, forward: bool) {
+        match pane {
+            MonsterPane::Monsters => {
+                self.reset();
+                if forward {
+                    self.next();
+                } else {
+                    self.prev();
+                }
+            }
+            MonsterPane::Description if forward => self.desc_scrollbar_state.scroll_down(),
+            MonsterPane::Description => self.desc_scrollbar_state.scroll_up(),
+            MonsterPane::Notes if forward => self.notes_state.scroll.scroll_down(),
+            MonsterPane::Notes => self.notes_state.scroll.scroll_up(),
+            MonsterPane::Habitats if forward => self.habitat_page_state.next(),
+            MonsterPane::Habitats => self.habitat_page_state.prev(),
+            MonsterPane::Quests if forward => self.monster_on_quest_list_state.next(),
+            MonsterPane::Quests => self.monster_on_quest_list_state.prev(),
+            MonsterPane::Drops if forward => self.monster_drops_tab_state.next(),
+            MonsterPane::Drops => self.monster_drops_tab_state.prev(),
+            MonsterPane::Weakness if forward => self.monster_weakness_tab_state.next(),
+            MonsterPane::Weakness => self.monster_weakness_tab_state.prev(),
+        }
+    }
+
+    /// Handle a click at `position`: switch a tab, select a monster row or focus the pane.
+    pub fn click(&mut self, position: Position) {
+        let Some(pane) = self.pane_at(position) else {
+            return;
+        };
+
+        match pane {
+            MonsterPane::Drops if self.monster_drops_tab_state.click_tab(position) => {}
+            MonsterPane::Weakness if self.monster_weakness_tab_state.click_tab(position) => {}
+            MonsterPane::Monsters => {
+                let area = self.pane_areas[pane.index()];
+                let row = self.list_state.offset() + (position.y - area.y) as usize;
+                if row < self.list_items().len() && self.list_state.selected() != Some(row) {
+                    self.reset();
+                    self.select(row);
+                }
+            }
+            _ => {}
+        }
+
+        self.focus(pane);
+    }
+
     pub fn reset(&mut self) {
         self.desc_scrollbar_state.reset();
+        self.notes_state.reset();
         self.habitat_page_state.reset();
         self.monster_on_quest_list_state.reset();
         self.monster_weakness_tab_state.reset();
@@ -601,3 +1077,8 @@ impl MonsterListState {
         // self.scrollbar_state = self.scrollbar_state.position(0);
     }
 }
+
+/// Index of the area containing `position`.
+fn hit(areas: &[Rect], position: Position) -> Option<usize> {
+    areas.iter().position(|area| area.contains(position))
+}
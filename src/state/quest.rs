@@ -0,0 +1,81 @@
+use std::rc::Rc;
+
+use crate::{
+    data::quest_index::{QuestEntry, QuestIndex, QuestTarget},
+    monster::monster::QuestType,
+    util::next_filter,
+};
+
+use super::list_detail::{ListDetailState, ListEntry};
+
+const QUEST_TYPES: [QuestType; 4] = [
+    QuestType::Assignments,
+    QuestType::Optional,
+    QuestType::Event,
+    QuestType::Arena,
+];
+
+impl ListEntry for Rc<QuestEntry> {
+    fn detail_len(&self) -> usize {
+        self.targets.len()
+    }
+}
+
+/// Quests, the targets of the selected one as details.
+#[derive(Default)]
+pub struct QuestListState {
+    pub list: ListDetailState<Rc<QuestEntry>>,
+    pub filter_query: String,
+    pub type_filter: Option<QuestType>,
+    pub level_filter: Option<u8>,
+    pub index: QuestIndex,
+}
+
+impl QuestListState {
+    pub fn new(index: QuestIndex) -> Self {
+        Self {
+            list: ListDetailState::new(index.quests.clone()),
+            index,
+            ..Default::default()
+        }
+    }
+
+    pub fn selected_target(&self) -> Option<&QuestTarget> {
+        self.list
+            .current_data
+            .targets
+            .get(self.list.selected_detail()?)
+    }
+
+    /// Cycle through all quest types, then back to no type filter.
+    pub fn cycle_type_filter(&mut self) {
+        self.type_filter = next_filter(&QUEST_TYPES, self.type_filter);
+        self.apply_filter();
+    }
+
+    /// Cycle through the star levels in use, then back to no level filter.
+    pub fn cycle_level_filter(&mut self) {
+        self.level_filter = next_filter(&self.index.levels(), self.level_filter);
+        self.apply_filter();
+    }
+
+    pub(crate) fn set_list_filter(&mut self, filter: String) {
+        self.filter_query = filter;
+        self.apply_filter();
+    }
+
+    fn apply_filter(&mut self) {
+        let query = self.filter_query.to_lowercase();
+        let filtered =
+            !query.is_empty() || self.type_filter.is_some() || self.level_filter.is_some();
+        self.list.filter(filtered, |quest| {
+            quest.name.to_lowercase().contains(&query)
+                && self.type_filter.is_none_or(|t| quest.quest_type == t)
+                && self.level_filter.is_none_or(|l| quest.level == l)
+        });
+    }
+
+    pub fn reset(&mut self) {
+        self.list.reset();
+    }
+}
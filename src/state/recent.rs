@@ -0,0 +1,48 @@
+use ratatui::widgets::ListState;
+
+/// The popup listing recently viewed monsters, from `MonsterListState::recent`.
+#[derive(Default)]
+pub struct RecentState {
+    pub open: bool,
+    pub list_state: ListState,
+}
+
+impl RecentState {
+    /// Open on the most recent monster, nothing to show until one is viewed.
+    pub fn open(&mut self, len: usize) {
+        if len == 0 {
+            return;
+        }
+
+        self.list_state.select(Some(0));
+        self.open = true;
+    }
+
+    pub fn close(&mut self) {
+        self.open = false;
+    }
+
+    pub fn next(&mut self, len: usize) {
+        if len == 0 {
+            return;
+        }
+
+        let idx = match self.list_state.selected() {
+            Some(i) if i < len - 1 => i + 1,
+            _ => 0,
+        };
+        self.list_state.select(Some(idx));
+    }
+
+    pub fn prev(&mut self, len: usize) {
+        if len == 0 {
+            return;
+        }
+
+        let idx = match self.list_state.selected() {
+            Some(i) if i > 0 => i - 1,
+            _ => len - 1,
+        };
+        self.list_state.select(Some(idx));
+    }
+}
@@ -0,0 +1,117 @@
+use std::rc::Rc;
+
+use ratatui::widgets::{ListState, TableState};
+
+use crate::{
+    data::weak_to::{rank, Attack, RankBy, WeakToEntry},
+    monster::monster::MonsterEntity,
+};
+
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub enum WeakToPane {
+    #[default]
+    Attacks,
+    Monsters,
+}
+
+#[derive(Default)]
+pub struct WeakToState {
+    pub list_state: ListState,
+    pub table_state: TableState,
+    pub focused_pane: WeakToPane,
+    pub attack: Attack,
+    pub rank_by: RankBy,
+    /// Monsters ranked for `attack`, the weakest first.
+    pub ranking: Vec<WeakToEntry>,
+    bundle: Vec<Rc<MonsterEntity>>,
+}
+
+impl WeakToState {
+    pub fn new(bundle: Vec<Rc<MonsterEntity>>) -> Self {
+        let mut state = Self {
+            bundle,
+            list_state: ListState::default().with_selected(Some(0)),
+            ..Default::default()
+        };
+        state.update_ranking();
+        state
+    }
+
+    pub fn next(&mut self) {
+        let idx = match self.list_state.selected() {
+            Some(i) if i < Attack::ALL.len() - 1 => i + 1,
+            _ => 0,
+        };
+
+        self.select(idx);
+    }
+
+    pub fn prev(&mut self) {
+        let idx = match self.list_state.selected() {
+            Some(i) if i > 0 => i - 1,
+            _ => Attack::ALL.len() - 1,
+        };
+
+        self.select(idx);
+    }
+
+    pub fn select(&mut self, idx: usize) {
+        self.list_state.select(Some(idx));
+        self.attack = Attack::ALL.get(idx).copied().unwrap_or_default();
+        self.update_ranking();
+    }
+
+    pub fn next_monster(&mut self) {
+        if self.ranking.is_empty() {
+            return;
+        }
+
+        let idx = match self.table_state.selected() {
+            Some(i) if i < self.ranking.len() - 1 => i + 1,
+            _ => 0,
+        };
+        self.table_state.select(Some(idx));
+    }
+
+    pub fn prev_monster(&mut self) {
+        if self.ranking.is_empty() {
+            return;
+        }
+
+        let idx = match self.table_state.selected() {
+            Some(i) if i > 0 => i - 1,
+            _ => self.ranking.len() - 1,
+        };
+        self.table_state.select(Some(idx));
+    }
+
+    pub fn selected_entry(&self) -> Option<&WeakToEntry> {
+        self.ranking.get(self.table_state.selected()?)
+    }
+
+    pub fn toggle_focus(&mut self) {
+        self.focused_pane = match self.focused_pane {
+            WeakToPane::Attacks => WeakToPane::Monsters,
+            WeakToPane::Monsters => WeakToPane::Attacks,
+        };
+    }
+
+    pub fn toggle_rank_by(&mut self) {
+        self.rank_by = self.rank_by.toggle();
+        self.update_ranking();
+    }
+
+    fn update_ranking(&mut self) {
+        self.ranking = rank(
+            self.bundle.iter().map(Rc::as_ref),
+            self.attack,
+            self.rank_by,
+        );
+        self.table_state.select(Some(0));
+    }
+
+    pub fn reset(&mut self) {
+        self.focused_pane = WeakToPane::Attacks;
+        self.table_state.select(Some(0));
+    }
+}
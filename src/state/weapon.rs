@@ -0,0 +1,174 @@
+use std::{
+    collections::{HashMap, HashSet},
+    rc::Rc,
+};
+
+use crate::{
+    data::material_index::MaterialIndex,
+    util::next_filter,
+    weapon::{CraftingMaterial, WeaponEntity, WeaponType},
+};
+
+use super::list_detail::{ListDetailState, ListEntry};
+
+/// A weapon in the tree view.
+#[derive(Clone, Default)]
+pub struct WeaponRow {
+    pub weapon: Rc<WeaponEntity>,
+    /// Number of upgrades from the root of its tree.
+    pub depth: usize,
+}
+
+impl ListEntry for WeaponRow {
+    fn detail_len(&self) -> usize {
+        self.weapon.materials.len()
+    }
+}
+
+/// Weapons in tree order, the crafting materials of the selected one as details.
+#[derive(Default)]
+pub struct WeaponListState {
+    /// Every weapon in tree order: by weapon type, tree, then upgrade path.
+    pub list: ListDetailState<WeaponRow>,
+    pub filter_query: String,
+    pub type_filter: Option<WeaponType>,
+    pub materials: MaterialIndex,
+}
+
+impl WeaponListState {
+    pub fn new(weapons: Vec<WeaponEntity>, materials: MaterialIndex) -> Self {
+        Self {
+            list: ListDetailState::new(tree_rows(weapons)),
+            materials,
+            ..Default::default()
+        }
+    }
+
+    pub fn selected_material(&self) -> Option<&CraftingMaterial> {
+        self.list
+            .current_data
+            .weapon
+            .materials
+            .get(self.list.selected_detail()?)
+    }
+
+    pub fn find_weapon(&self, id: u16) -> Option<&Rc<WeaponEntity>> {
+        self.list
+            .items
+            .iter()
+            .find(|row| row.weapon.id == id)
+            .map(|row| &row.weapon)
+    }
+
+    /// Weapons upgraded from the weapon with `id`.
+    pub fn upgrades_of(&self, id: u16) -> impl Iterator<Item = &Rc<WeaponEntity>> {
+        self.list
+            .items
+            .iter()
+            .map(|row| &row.weapon)
+            .filter(move |weapon| weapon.upgrade_from == Some(id))
+    }
+
+    /// Cycle through the weapon types, then back to no type filter.
+    pub fn cycle_type_filter(&mut self) {
+        self.type_filter = next_filter(&WeaponType::ALL, self.type_filter);
+        self.apply_filter();
+    }
+
+    pub(crate) fn set_list_filter(&mut self, filter: String) {
+        self.filter_query = filter;
+        self.apply_filter();
+    }
+
+    fn apply_filter(&mut self) {
+        let query = self.filter_query.to_lowercase();
+        let filtered = !query.is_empty() || self.type_filter.is_some();
+        self.list.filter(filtered, |row| {
+            let weapon = &row.weapon;
+            (weapon.name.to_lowercase().contains(&query)
+                || weapon.tree.to_lowercase().contains(&query))
+                && self.type_filter.is_none_or(|t| weapon.weapon_type == t)
+        });
+    }
+
+    pub fn reset(&mut self) {
+        self.list.reset();
+    }
+}
+
+/// Order weapons by type and tree, each tree depth first from its roots.
+fn tree_rows(weapons: Vec<WeaponEntity>) -> Vec<WeaponRow> {
+    let weapons: Vec<Rc<WeaponEntity>> = weapons.into_iter().map(Rc::new).collect();
+
+    let mut upgrades: HashMap<u16, Vec<&Rc<WeaponEntity>>> = HashMap::new();
+    for weapon in &weapons {
+        if let Some(parent) = weapon.upgrade_from {
+            upgrades.entry(parent).or_default().push(weapon);
+        }
+    }
+
+    // a weapon whose parent is missing starts its own tree
+    let mut roots: Vec<&Rc<WeaponEntity>> = weapons
+        .iter()
+        .filter(|w| {
+            w.upgrade_from
+                .is_none_or(|p| !weapons.iter().any(|o| o.id == p))
+        })
+        .collect();
+    roots.sort_by_key(|w| {
+        let type_order = WeaponType::ALL.iter().position(|t| *t == w.weapon_type);
+        (type_order, w.tree.clone(), w.rarity, w.id)
+    });
+
+    let mut visited = HashSet::new();
+    let mut rows = Vec::with_capacity(weapons.len());
+    // weapons in an upgrade cycle are never roots, the first one met starts the tree
+    for root in roots.into_iter().chain(&weapons) {
+        let mut stack = vec![(root, 0)];
+        while let Some((weapon, depth)) = stack.pop() {
+            if !visited.insert(weapon.id) {
+                continue;
+            }
+            rows.push(WeaponRow {
+                weapon: Rc::clone(weapon),
+                depth,
+            });
+
+            if let Some(children) = upgrades.get(&weapon.id) {
+                stack.extend(children.iter().rev().map(|c| (*c, depth + 1)));
+            }
+        }
+    }
+
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn weapon(id: u16, upgrade_from: Option<u16>) -> WeaponEntity {
+        WeaponEntity {
+            id,
+            upgrade_from,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn upgrade_cycle_is_listed_once() {
+        let weapons = vec![
+            weapon(1, None),
+            weapon(2, Some(1)),
+            weapon(3, Some(4)),
+            weapon(4, Some(3)),
+            weapon(5, Some(4)),
+        ];
+
+        let rows: Vec<(u16, usize)> = tree_rows(weapons)
+            .iter()
+            .map(|row| (row.weapon.id, row.depth))
+            .collect();
+        assert_eq!(rows, [(1, 0), (2, 1), (3, 0), (4, 1), (5, 2)]);
+    }
+}
@@ -0,0 +1,315 @@
+use std::{env, fmt::Display};
+
+use clap::ValueEnum;
+use ratatui::{buffer::Buffer, style::Color};
+use serde::{
+    de::{Unexpected, Visitor},
+    Deserialize, Serialize,
+};
+
+/// Which characters the interface is drawn with.
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GlyphSet {
+    /// Icons from a Nerd Font patched font
+    #[default]
+    NerdFont,
+    /// Symbols found in most monospace fonts
+    Unicode,
+    /// Plain ASCII, borders and icons included
+    Ascii,
+}
+
+impl GlyphSet {
+    pub const ALL: [GlyphSet; 3] = [GlyphSet::NerdFont, GlyphSet::Unicode, GlyphSet::Ascii];
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|set| set.key() == key)
+    }
+
+    pub fn key(&self) -> &'static str {
+        match self {
+            GlyphSet::NerdFont => "nerd-font",
+            GlyphSet::Unicode => "unicode",
+            GlyphSet::Ascii => "ascii",
+        }
+    }
+
+    /// ASCII on the Linux console or a non UTF-8 locale, Nerd Font otherwise.
+    /// Whether the font has the Nerd Font icons cannot be told from here.
+    pub fn detect() -> Self {
+        if env::var("TERM").is_ok_and(|term| term == "linux") {
+            return GlyphSet::Ascii;
+        }
+
+        let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+            .into_iter()
+            .filter_map(|name| env::var(name).ok())
+            .find(|value| !value.is_empty());
+
+        match locale {
+            Some(locale) if !is_utf8(&locale) => GlyphSet::Ascii,
+            _ => GlyphSet::NerdFont,
+        }
+    }
+
+    pub fn glyphs(&self) -> Glyphs {
+        match self {
+            GlyphSet::NerdFont => Glyphs::NERD_FONT,
+            GlyphSet::Unicode => Glyphs::UNICODE,
+            GlyphSet::Ascii => Glyphs::ASCII,
+        }
+    }
+}
+
+fn is_utf8(locale: &str) -> bool {
+    let locale = locale.to_lowercase();
+    locale.contains("utf-8") || locale.contains("utf8")
+}
+
+impl Display for GlyphSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.key())
+    }
+}
+
+impl Serialize for GlyphSet {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.key())
+    }
+}
+
+impl<'de> Deserialize<'de> for GlyphSet {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(GlyphSetVisitor)
+    }
+}
+
+struct GlyphSetVisitor;
+
+impl<'de> Visitor<'de> for GlyphSetVisitor {
+    type Value = GlyphSet;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("one of `nerd-font`, `unicode` or `ascii`")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        GlyphSet::from_key(v).ok_or_else(|| E::invalid_value(Unexpected::Str(v), &self))
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ElementGlyphs {
+    pub fire: &'static str,
+    pub water: &'static str,
+    pub thunder: &'static str,
+    pub ice: &'static str,
+    pub dragon: &'static str,
+    pub poison: &'static str,
+}
+
+#[derive(Clone, Debug)]
+pub struct AilmentGlyphs {
+    pub poison: &'static str,
+    pub stun: &'static str,
+    pub paralysis: &'static str,
+    pub sleep: &'static str,
+    pub blast: &'static str,
+    pub exhaust: &'static str,
+}
+
+/// Every symbol the widgets draw. Icons may be empty, the label next to them is enough.
+#[derive(Clone, Debug)]
+pub struct Glyphs {
+    pub set: GlyphSet,
+    pub elements: ElementGlyphs,
+    pub ailments: AilmentGlyphs,
+    /// Damage types, in the Weapon hitzone header.
+    pub cut: &'static str,
+    pub blunt: &'static str,
+    pub ammo: &'static str,
+    /// Weakness ratings, quest levels and the best part in the calculator.
+    pub star: &'static str,
+    /// Effective and ineffective items.
+    pub check: &'static str,
+    pub cross: &'static str,
+    /// Sharpness gauge.
+    pub bar: &'static str,
+    /// In front of the selected table row.
+    pub marker: &'static str,
+    /// Armor piece worn by the set being built.
+    pub worn: &'static str,
+    pub separator: &'static str,
+    /// Weapon tree branch.
+    pub branch: &'static str,
+}
+
+impl Glyphs {
+    pub const NERD_FONT: Glyphs = Glyphs {
+        set: GlyphSet::NerdFont,
+        elements: ElementGlyphs {
+            fire: "\u{f0238}",
+            water: "\u{f058c}",
+            thunder: "\u{f0e7}",
+            ice: "\u{f2dc}",
+            dragon: "\u{f327} ",
+            poison: "\u{f0eca}",
+        },
+        ailments: AilmentGlyphs {
+            poison: "\u{f0eca}",
+            stun: "\u{f0674}",
+            paralysis: "\u{e315}",
+            sleep: "\u{f04b2}",
+            blast: "\u{f1e2}",
+            exhaust: "\u{e231}",
+        },
+        cut: "\u{f04e5}",
+        blunt: "\u{f08ea}",
+        ammo: "\u{f0cf3}",
+        star: "\u{f005}",
+        check: "\u{f00c}",
+        cross: "\u{f00d}",
+        bar: "█",
+        marker: "█",
+        worn: "◆",
+        separator: "·",
+        branch: "└",
+    };
+
+    pub const UNICODE: Glyphs = Glyphs {
+        set: GlyphSet::Unicode,
+        elements: ElementGlyphs {
+            fire: "♨",
+            water: "≈",
+            thunder: "ϟ",
+            ice: "❄",
+            dragon: "◈",
+            poison: "☠",
+        },
+        ailments: AilmentGlyphs {
+            poison: "☠",
+            stun: "✪",
+            paralysis: "ϟ",
+            sleep: "☾",
+            blast: "✹",
+            exhaust: "∿",
+        },
+        cut: "",
+        blunt: "",
+        ammo: "",
+        star: "★",
+        check: "✔",
+        cross: "✘",
+        bar: "█",
+        marker: "█",
+        worn: "◆",
+        separator: "·",
+        branch: "└",
+    };
+
+    pub const ASCII: Glyphs = Glyphs {
+        set: GlyphSet::Ascii,
+        elements: ElementGlyphs {
+            fire: "",
+            water: "",
+            thunder: "",
+            ice: "",
+            dragon: "",
+            poison: "",
+        },
+        ailments: AilmentGlyphs {
+            poison: "",
+            stun: "",
+            paralysis: "",
+            sleep: "",
+            blast: "",
+            exhaust: "",
+        },
+        cut: "",
+        blunt: "",
+        ammo: "",
+        star: "*",
+        check: "o",
+        cross: "x",
+        bar: "#",
+        marker: "|",
+        worn: "*",
+        separator: "-",
+        branch: "`",
+    };
+
+    /// `label(icon)`, or just `label` without an icon.
+    pub fn labeled(label: &str, icon: &str) -> String {
+        if icon.is_empty() {
+            label.to_string()
+        } else {
+            format!("{}({})", label, icon)
+        }
+    }
+
+    /// Replace what the widgets draw with ratatui's own symbols, borders, scrollbars, the
+    /// block letter titles and the monster icons, when the set is ASCII.
+    pub fn apply(&self, buf: &mut Buffer) {
+        if self.set != GlyphSet::Ascii {
+            return;
+        }
+
+        for cell in buf.content.iter_mut() {
+            let mut chars = cell.symbol().chars();
+            let (Some(c), None) = (chars.next(), chars.next()) else {
+                continue;
+            };
+            if c.is_ascii() {
+                continue;
+            }
+
+            // a half block is two icon pixels, paint the cell with one of them
+            if matches!(c, '▀' | '▄') {
+                if cell.bg == Color::Reset {
+                    cell.bg = cell.fg;
+                }
+                cell.set_char(' ');
+                continue;
+            }
+
+            let ascii = ascii_fallback(c);
+            if ascii != c {
+                cell.set_char(ascii);
+            }
+        }
+    }
+}
+
+impl Default for Glyphs {
+    fn default() -> Self {
+        GlyphSet::default().glyphs()
+    }
+}
+
+fn ascii_fallback(c: char) -> char {
+    match c {
+        '═' => '=',
+        '─' | '━' | '┄' | '┈' | '╌' => '-',
+        '│' | '┃' | '║' | '┆' | '┊' | '╎' => '|',
+        '\u{2500}'..='\u{257f}' => '+',
+        '█' | '▓' | '▒' => '#',
+        '░' | '\u{2580}'..='\u{259f}' => ' ',
+        '↑' | '▲' => '^',
+        '↓' | '▼' => 'v',
+        '←' | '◄' => '<',
+        '→' | '►' => '>',
+        '…' => '.',
+        '\u{2000}'..='\u{200a}' => ' ',
+        '\u{25a0}'..='\u{27bf}' | '\u{e000}'..='\u{f8ff}' | '\u{f0000}'..='\u{ffffd}' => '*',
+        c => c,
+    }
+}
@@ -0,0 +1,427 @@
+pub mod glyph;
+
+use std::{
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use glyph::{GlyphSet, Glyphs};
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::Span,
+};
+use serde::{Deserialize, Serialize};
+
+/// Colors of the interface, by what they mean rather than where they are used.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct UiColors {
+    /// Values, unselected tabs and menu entries.
+    pub text: Color,
+    /// Secondary text like list subtitles and key hints.
+    pub muted: Color,
+    /// Placeholders, tree lines and inactive entries.
+    pub dim: Color,
+    /// Selected tabs and titles, focused borders.
+    pub accent: Color,
+    /// Search matches, best values and the current page.
+    pub highlight: Color,
+    /// Background of the selected list item.
+    pub selection_bg: Color,
+    /// Background of the selected table row.
+    pub row_bg: Color,
+    /// Selection bar of tables and effectiveness stars.
+    pub marker: Color,
+    pub positive: Color,
+    pub negative: Color,
+    /// Search input text.
+    pub input: Color,
+    /// Screen label and scrollbar thumbs.
+    pub surface: Color,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ElementColors {
+    pub fire: Color,
+    pub water: Color,
+    pub thunder: Color,
+    pub ice: Color,
+    pub dragon: Color,
+    pub poison: Color,
+    pub none: Color,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AilmentColors {
+    pub poison: Color,
+    pub stun: Color,
+    pub paralysis: Color,
+    pub sleep: Color,
+    pub blast: Color,
+    pub exhaust: Color,
+}
+
+/// Status effects without an element or ailment of the same kind.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct EffectColors {
+    pub blastblight: Color,
+    pub frostblight: Color,
+    pub frenzy: Color,
+    pub stench: Color,
+    pub defense_down: Color,
+    pub bleeding: Color,
+    pub other: Color,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SharpnessColors {
+    pub red: Color,
+    pub orange: Color,
+    pub yellow: Color,
+    pub green: Color,
+    pub blue: Color,
+    pub white: Color,
+    pub purple: Color,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Theme {
+    pub ui: UiColors,
+    pub elements: ElementColors,
+    pub ailments: AilmentColors,
+    pub effects: EffectColors,
+    pub sharpness: SharpnessColors,
+    /// Glyph set to draw with, unless `--glyphs` is given.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub glyphs: Option<GlyphSet>,
+}
+
+impl Theme {
+    pub const BUILTIN: [&'static str; 3] = ["dark", "light", "high-contrast"];
+
+    pub fn builtin(name: &str) -> Option<Self> {
+        match name {
+            "dark" => Some(Self::dark()),
+            "light" => Some(Self::light()),
+            "high-contrast" => Some(Self::high_contrast()),
+            _ => None,
+        }
+    }
+
+    pub fn dark() -> Self {
+        Self {
+            ui: UiColors {
+                text: Color::White,
+                muted: Color::Gray,
+                dim: Color::DarkGray,
+                accent: Color::Rgb(54, 127, 222),
+                highlight: Color::Rgb(199, 159, 0),
+                selection_bg: Color::Rgb(29, 46, 69),
+                row_bg: Color::Rgb(16, 33, 56),
+                marker: Color::Yellow,
+                positive: Color::Green,
+                negative: Color::Red,
+                input: Color::Green,
+                surface: Color::DarkGray,
+            },
+            elements: ElementColors {
+                fire: Color::Red,
+                water: Color::Rgb(66, 135, 245),
+                thunder: Color::Yellow,
+                ice: Color::Cyan,
+                dragon: Color::Rgb(154, 88, 237),
+                poison: Color::Rgb(126, 39, 219),
+                none: Color::Gray,
+            },
+            ailments: AilmentColors {
+                poison: Color::Rgb(126, 39, 219),
+                stun: Color::Yellow,
+                paralysis: Color::Yellow,
+                sleep: Color::Cyan,
+                blast: Color::DarkGray,
+                exhaust: Color::Gray,
+            },
+            effects: EffectColors {
+                blastblight: Color::Rgb(217, 98, 24),
+                frostblight: Color::White,
+                frenzy: Color::Rgb(150, 0, 237),
+                stench: Color::Rgb(172, 191, 2),
+                defense_down: Color::LightBlue,
+                bleeding: Color::Red,
+                other: Color::White,
+            },
+            sharpness: SharpnessColors {
+                red: Color::Rgb(200, 40, 40),
+                orange: Color::Rgb(230, 120, 30),
+                yellow: Color::Rgb(220, 200, 40),
+                green: Color::Rgb(70, 180, 60),
+                blue: Color::Rgb(50, 110, 220),
+                white: Color::Rgb(230, 230, 230),
+                purple: Color::Rgb(150, 70, 200),
+            },
+            glyphs: None,
+        }
+    }
+
+    pub fn light() -> Self {
+        Self {
+            ui: UiColors {
+                text: Color::Black,
+                muted: Color::Rgb(90, 90, 90),
+                dim: Color::Rgb(150, 150, 150),
+                accent: Color::Rgb(0, 90, 190),
+                highlight: Color::Rgb(160, 100, 0),
+                selection_bg: Color::Rgb(200, 220, 245),
+                row_bg: Color::Rgb(225, 235, 250),
+                marker: Color::Rgb(190, 130, 0),
+                positive: Color::Rgb(0, 130, 0),
+                negative: Color::Rgb(190, 0, 0),
+                input: Color::Rgb(0, 110, 0),
+                surface: Color::Rgb(205, 205, 205),
+            },
+            elements: ElementColors {
+                fire: Color::Rgb(200, 30, 30),
+                water: Color::Rgb(30, 100, 210),
+                thunder: Color::Rgb(165, 135, 0),
+                ice: Color::Rgb(0, 135, 160),
+                dragon: Color::Rgb(120, 50, 200),
+                poison: Color::Rgb(110, 30, 190),
+                none: Color::Rgb(90, 90, 90),
+            },
+            ailments: AilmentColors {
+                poison: Color::Rgb(110, 30, 190),
+                stun: Color::Rgb(165, 135, 0),
+                paralysis: Color::Rgb(165, 135, 0),
+                sleep: Color::Rgb(0, 135, 160),
+                blast: Color::Rgb(90, 90, 90),
+                exhaust: Color::Rgb(120, 120, 120),
+            },
+            effects: EffectColors {
+                blastblight: Color::Rgb(200, 85, 10),
+                frostblight: Color::Rgb(60, 120, 150),
+                frenzy: Color::Rgb(120, 0, 190),
+                stench: Color::Rgb(120, 135, 0),
+                defense_down: Color::Rgb(40, 90, 200),
+                bleeding: Color::Rgb(200, 30, 30),
+                other: Color::Rgb(60, 60, 60),
+            },
+            sharpness: SharpnessColors {
+                red: Color::Rgb(200, 40, 40),
+                orange: Color::Rgb(220, 110, 20),
+                yellow: Color::Rgb(190, 170, 0),
+                green: Color::Rgb(50, 150, 40),
+                blue: Color::Rgb(40, 100, 210),
+                white: Color::Rgb(150, 150, 150),
+                purple: Color::Rgb(130, 50, 180),
+            },
+            glyphs: None,
+        }
+    }
+
+    pub fn high_contrast() -> Self {
+        Self {
+            ui: UiColors {
+                text: Color::White,
+                muted: Color::White,
+                dim: Color::Gray,
+                accent: Color::LightCyan,
+                highlight: Color::LightYellow,
+                selection_bg: Color::Blue,
+                row_bg: Color::Blue,
+                marker: Color::LightYellow,
+                positive: Color::LightGreen,
+                negative: Color::LightRed,
+                input: Color::LightGreen,
+                surface: Color::Gray,
+            },
+            elements: ElementColors {
+                fire: Color::LightRed,
+                water: Color::LightBlue,
+                thunder: Color::LightYellow,
+                ice: Color::LightCyan,
+                dragon: Color::LightMagenta,
+                poison: Color::Magenta,
+                none: Color::White,
+            },
+            ailments: AilmentColors {
+                poison: Color::Magenta,
+                stun: Color::LightYellow,
+                paralysis: Color::LightYellow,
+                sleep: Color::LightCyan,
+                blast: Color::LightRed,
+                exhaust: Color::White,
+            },
+            effects: EffectColors {
+                blastblight: Color::LightRed,
+                frostblight: Color::LightCyan,
+                frenzy: Color::LightMagenta,
+                stench: Color::LightGreen,
+                defense_down: Color::LightBlue,
+                bleeding: Color::LightRed,
+                other: Color::White,
+            },
+            sharpness: SharpnessColors {
+                red: Color::LightRed,
+                orange: Color::Rgb(255, 160, 0),
+                yellow: Color::LightYellow,
+                green: Color::LightGreen,
+                blue: Color::LightBlue,
+                white: Color::White,
+                purple: Color::LightMagenta,
+            },
+            glyphs: None,
+        }
+    }
+
+    /// A built-in theme by name, a theme file, or `theme.toml` in the config directory.
+    pub fn load(spec: Option<&str>) -> Result<Self, ThemeError> {
+        match spec {
+            Some(spec) => match Self::builtin(spec) {
+                Some(theme) => Ok(theme),
+                None => Self::read(Path::new(spec)),
+            },
+            None => match default_theme_path() {
+                Some(path) if path.is_file() => Self::read(&path),
+                _ => Ok(Self::default()),
+            },
+        }
+    }
+
+    fn read(path: &Path) -> Result<Self, ThemeError> {
+        let content = fs::read_to_string(path).map_err(|source| ThemeError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+        Self::parse(&content, path)
+    }
+
+    /// A theme file at `path`: the colors it sets on top of the built-in theme named by `extends`.
+    pub fn parse(content: &str, path: &Path) -> Result<Self, ThemeError> {
+        let parse_error = |source| ThemeError::Parse {
+            path: path.to_path_buf(),
+            source: Box::new(source),
+        };
+
+        let mut overrides: toml::Table = toml::from_str(content).map_err(parse_error)?;
+        let base = match overrides.remove("extends") {
+            Some(toml::Value::String(name)) => {
+                Self::builtin(&name).ok_or(ThemeError::UnknownTheme(name))?
+            }
+            Some(value) => return Err(ThemeError::UnknownTheme(value.to_string())),
+            None => Self::default(),
+        };
+
+        let mut table = toml::Table::try_from(base).expect("built-in themes serialize to TOML");
+        merge(&mut table, overrides);
+        table.try_into().map_err(parse_error)
+    }
+
+    /// Selected list item.
+    pub fn selected_style(&self) -> Style {
+        Style::new()
+            .bg(self.ui.selection_bg)
+            .add_modifier(Modifier::BOLD)
+    }
+
+    /// Selected table row.
+    pub fn row_style(&self) -> Style {
+        Style::new().bg(self.ui.row_bg)
+    }
+
+    /// Bar in front of the selected table row.
+    pub fn row_marker(&self, glyphs: &Glyphs) -> Span<'static> {
+        Span::styled(
+            format!(" {} ", glyphs.marker),
+            Style::new().fg(self.ui.marker),
+        )
+    }
+
+    /// Accent color while `selected`, like a tab title or the border of the focused pane.
+    pub fn accent_style(&self, selected: bool) -> Style {
+        Style::new().fg(if selected {
+            self.ui.accent
+        } else {
+            self.ui.text
+        })
+    }
+
+    /// Highlighted part of a text, like the letters matched by a search.
+    pub fn match_style(&self) -> Style {
+        Style::new()
+            .fg(self.ui.highlight)
+            .add_modifier(Modifier::BOLD)
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+fn merge(base: &mut toml::Table, overrides: toml::Table) {
+    for (key, value) in overrides {
+        match (base.get_mut(&key), value) {
+            (Some(toml::Value::Table(base)), toml::Value::Table(overrides)) => {
+                merge(base, overrides)
+            }
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
+}
+
+/// `$XDG_CONFIG_HOME/monsterex/theme.toml` (or the platform equivalent).
+pub fn default_theme_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("monsterex").join("theme.toml"))
+}
+
+#[derive(Debug)]
+pub enum ThemeError {
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+    Parse {
+        path: PathBuf,
+        source: Box<toml::de::Error>,
+    },
+    UnknownTheme(String),
+}
+
+impl Display for ThemeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ThemeError::Io { path, source } => {
+                write!(f, "cannot read theme {}: {}", path.display(), source)
+            }
+            ThemeError::Parse { path, source } => {
+                write!(f, "invalid theme {}: {}", path.display(), source)
+            }
+            ThemeError::UnknownTheme(name) => write!(
+                f,
+                "unknown theme {}, expected one of {}",
+                name,
+                Theme::BUILTIN.join(", ")
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ThemeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ThemeError::Io { source, .. } => Some(source),
+            ThemeError::Parse { source, .. } => Some(source.as_ref()),
+            ThemeError::UnknownTheme(_) => None,
+        }
+    }
+}
@@ -1,7 +1,16 @@
 use crate::{
     screen::{
+        armor::{render_armor_screen, render_armor_selection},
+        hunt_log::{render_hunt_log_screen, render_hunt_log_selection},
         main_s::render_main_screen,
-        monster::{render_monster_screen, render_monster_selection},
+        material::{render_material_screen, render_material_selection},
+        monster::{
+            render_damage_calculator, render_monster_compare, render_monster_screen,
+            render_monster_selection, render_recent_monsters, render_record_hunt,
+        },
+        quest::{render_quest_screen, render_quest_selection},
+        weak_to::{render_weak_to_screen, render_weak_to_selection},
+        weapon::{render_weapon_screen, render_weapon_selection},
     },
     state::AppState,
     widget::bottom_nav::BottomNavigation,
@@ -13,8 +22,9 @@ use ratatui::{
 
 pub fn ui(frame: &mut Frame, app: &mut AppState) {
     let area = frame.area();
+    let nav_height = BottomNavigation::height(app, area.width).min(area.height / 2);
     let [main_chunk, bottom_chunk] =
-        Layout::vertical([Constraint::Min(1), Constraint::Length(2)]).areas(area);
+        Layout::vertical([Constraint::Min(1), Constraint::Length(nav_height)]).areas(area);
 
     // let nc = format!("width: {}", area.width);
 
@@ -26,12 +36,50 @@ pub fn ui(frame: &mut Frame, app: &mut AppState) {
         crate::state::CurrentScreen::Monster => {
             render_monster_screen(frame, left_chunk, app);
             render_monster_selection(frame, right_chunk, app);
+
+            if app.calculator.open {
+                render_damage_calculator(frame, centered_popup_rect(70, 80, main_chunk), app);
+            }
+            if app.compare.open {
+                render_monster_compare(frame, centered_popup_rect(80, 90, main_chunk), app);
+            }
+            if app.recent.open {
+                render_recent_monsters(frame, centered_popup_rect(30, 40, main_chunk), app);
+            }
+            if app.hunt_log.record.open {
+                render_record_hunt(frame, centered_popup_rect(30, 30, main_chunk), app);
+            }
+        }
+        crate::state::CurrentScreen::Material => {
+            render_material_screen(frame, left_chunk, app);
+            render_material_selection(frame, right_chunk, app);
+        }
+        crate::state::CurrentScreen::Quest => {
+            render_quest_screen(frame, left_chunk, app);
+            render_quest_selection(frame, right_chunk, app);
+        }
+        crate::state::CurrentScreen::Weapon => {
+            render_weapon_screen(frame, left_chunk, app);
+            render_weapon_selection(frame, right_chunk, app);
+        }
+        crate::state::CurrentScreen::Armor => {
+            render_armor_screen(frame, left_chunk, app);
+            render_armor_selection(frame, right_chunk, app);
+        }
+        crate::state::CurrentScreen::WeakTo => {
+            render_weak_to_screen(frame, left_chunk, app);
+            render_weak_to_selection(frame, right_chunk, app);
+        }
+        crate::state::CurrentScreen::HuntLog => {
+            render_hunt_log_screen(frame, left_chunk, app);
+            render_hunt_log_selection(frame, right_chunk, app);
         }
-        _ => todo!(),
     }
 
     // render Bottom Navigation Info
     frame.render_stateful_widget(BottomNavigation, bottom_chunk, app);
+
+    app.glyphs.apply(frame.buffer_mut());
 }
 
 pub fn centered_popup_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
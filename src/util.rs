@@ -0,0 +1,14 @@
+/// The value `step` places after `current` in `all`, wrapping around.
+pub(crate) fn cycle<T: PartialEq + Clone>(all: &[T], current: T, step: usize) -> T {
+    let idx = all.iter().position(|v| *v == current).unwrap_or(0);
+    all[(idx + step) % all.len()].clone()
+}
+
+/// The filter after `current` in `all`, from no filter to the first value and from the
+/// last value back to none.
+pub(crate) fn next_filter<T: Copy + PartialEq>(all: &[T], current: Option<T>) -> Option<T> {
+    match current {
+        None => all.first().copied(),
+        Some(current) => all.iter().skip_while(|v| **v != current).nth(1).copied(),
+    }
+}
@@ -0,0 +1,386 @@
+use std::fmt::Display;
+
+use serde::{
+    de::{Unexpected, Visitor},
+    Deserialize, Serialize,
+};
+
+use crate::util::cycle;
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct WeaponEntity {
+    pub id: u16,
+    pub name: String,
+    pub weapon_type: WeaponType,
+    /// Upgrade tree the weapon belongs to, e.g. "Rathalos Tree".
+    pub tree: String,
+    /// Id of the weapon this one is upgraded from, `None` for the root of a tree.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub upgrade_from: Option<u16>,
+    pub rarity: u8,
+    pub attack: u16,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub element: Option<WeaponElement>,
+    /// Hits per sharpness color, red to purple. Empty for ranged weapons.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sharpness: Vec<u16>,
+    /// Decoration slot levels.
+    #[serde(default)]
+    pub slots: Vec<u8>,
+    pub materials: Vec<CraftingMaterial>,
+}
+
+impl WeaponEntity {
+    /// Best sharpness color of the bar, `None` for ranged weapons.
+    pub fn max_sharpness(&self) -> Option<Sharpness> {
+        self.sharpness
+            .iter()
+            .zip(Sharpness::ALL)
+            .rev()
+            .find(|(hits, _)| **hits > 0)
+            .map(|(_, sharpness)| sharpness)
+    }
+}
+
+/// Element or status of a weapon.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct WeaponElement {
+    pub kind: WeaponElementKind,
+    pub value: u16,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct CraftingMaterial {
+    pub material: String,
+    pub quantity: u8,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WeaponElementKind {
+    Fire,
+    Water,
+    Thunder,
+    Ice,
+    Dragon,
+    Poison,
+    Paralysis,
+    Sleep,
+    Blast,
+}
+
+impl WeaponElementKind {
+    pub fn from_key(key: &str) -> Option<Self> {
+        use WeaponElementKind::*;
+        match key {
+            "fire" => Some(Fire),
+            "water" => Some(Water),
+            "thunder" => Some(Thunder),
+            "ice" => Some(Ice),
+            "dragon" => Some(Dragon),
+            "poison" => Some(Poison),
+            "paralysis" => Some(Paralysis),
+            "sleep" => Some(Sleep),
+            "blast" => Some(Blast),
+            _ => None,
+        }
+    }
+
+    pub fn key(&self) -> &'static str {
+        use WeaponElementKind::*;
+        match self {
+            Fire => "fire",
+            Water => "water",
+            Thunder => "thunder",
+            Ice => "ice",
+            Dragon => "dragon",
+            Poison => "poison",
+            Paralysis => "paralysis",
+            Sleep => "sleep",
+            Blast => "blast",
+        }
+    }
+}
+
+impl Display for WeaponElementKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use WeaponElementKind::*;
+        match self {
+            Fire => write!(f, "Fire"),
+            Water => write!(f, "Water"),
+            Thunder => write!(f, "Thunder"),
+            Ice => write!(f, "Ice"),
+            Dragon => write!(f, "Dragon"),
+            Poison => write!(f, "Poison"),
+            Paralysis => write!(f, "Paralysis"),
+            Sleep => write!(f, "Sleep"),
+            Blast => write!(f, "Blast"),
+        }
+    }
+}
+
+impl Serialize for WeaponElementKind {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.key())
+    }
+}
+
+impl<'de> Deserialize<'de> for WeaponElementKind {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(WeaponElementKindVisitor)
+    }
+}
+
+struct WeaponElementKindVisitor;
+
+impl<'de> Visitor<'de> for WeaponElementKindVisitor {
+    type Value = WeaponElementKind;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("Weapon Element")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        WeaponElementKind::from_key(v).ok_or_else(|| E::invalid_value(Unexpected::Str(v), &self))
+    }
+}
+
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WeaponType {
+    #[default]
+    GreatSword,
+    LongSword,
+    SwordAndShield,
+    DualBlades,
+    Hammer,
+    HuntingHorn,
+    Lance,
+    Gunlance,
+    SwitchAxe,
+    ChargeBlade,
+    InsectGlaive,
+    LightBowgun,
+    HeavyBowgun,
+    Bow,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DamageType {
+    Cut,
+    Blunt,
+    Ammo,
+}
+
+impl WeaponType {
+    pub const ALL: [WeaponType; 14] = [
+        WeaponType::GreatSword,
+        WeaponType::LongSword,
+        WeaponType::SwordAndShield,
+        WeaponType::DualBlades,
+        WeaponType::Hammer,
+        WeaponType::HuntingHorn,
+        WeaponType::Lance,
+        WeaponType::Gunlance,
+        WeaponType::SwitchAxe,
+        WeaponType::ChargeBlade,
+        WeaponType::InsectGlaive,
+        WeaponType::LightBowgun,
+        WeaponType::HeavyBowgun,
+        WeaponType::Bow,
+    ];
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|t| t.key() == key)
+    }
+
+    pub fn key(&self) -> &'static str {
+        use WeaponType::*;
+        match self {
+            GreatSword => "great_sword",
+            LongSword => "long_sword",
+            SwordAndShield => "sword_and_shield",
+            DualBlades => "dual_blades",
+            Hammer => "hammer",
+            HuntingHorn => "hunting_horn",
+            Lance => "lance",
+            Gunlance => "gunlance",
+            SwitchAxe => "switch_axe",
+            ChargeBlade => "charge_blade",
+            InsectGlaive => "insect_glaive",
+            LightBowgun => "light_bowgun",
+            HeavyBowgun => "heavy_bowgun",
+            Bow => "bow",
+        }
+    }
+
+    pub fn damage_type(&self) -> DamageType {
+        use WeaponType::*;
+        match self {
+            Hammer | HuntingHorn => DamageType::Blunt,
+            LightBowgun | HeavyBowgun | Bow => DamageType::Ammo,
+            _ => DamageType::Cut,
+        }
+    }
+
+    /// Ranged weapons have no sharpness.
+    pub fn uses_sharpness(&self) -> bool {
+        self.damage_type() != DamageType::Ammo
+    }
+
+    pub fn next(self) -> Self {
+        cycle(&Self::ALL, self, 1)
+    }
+
+    pub fn prev(self) -> Self {
+        cycle(&Self::ALL, self, Self::ALL.len() - 1)
+    }
+}
+
+impl Display for WeaponType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use WeaponType::*;
+        match self {
+            GreatSword => write!(f, "Great Sword"),
+            LongSword => write!(f, "Long Sword"),
+            SwordAndShield => write!(f, "Sword and Shield"),
+            DualBlades => write!(f, "Dual Blades"),
+            Hammer => write!(f, "Hammer"),
+            HuntingHorn => write!(f, "Hunting Horn"),
+            Lance => write!(f, "Lance"),
+            Gunlance => write!(f, "Gunlance"),
+            SwitchAxe => write!(f, "Switch Axe"),
+            ChargeBlade => write!(f, "Charge Blade"),
+            InsectGlaive => write!(f, "Insect Glaive"),
+            LightBowgun => write!(f, "Light Bowgun"),
+            HeavyBowgun => write!(f, "Heavy Bowgun"),
+            Bow => write!(f, "Bow"),
+        }
+    }
+}
+
+impl Display for DamageType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DamageType::Cut => write!(f, "Cut"),
+            DamageType::Blunt => write!(f, "Blunt"),
+            DamageType::Ammo => write!(f, "Ammo"),
+        }
+    }
+}
+
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Sharpness {
+    Red,
+    Orange,
+    Yellow,
+    Green,
+    Blue,
+    #[default]
+    White,
+    Purple,
+}
+
+impl Sharpness {
+    /// Red to purple, the order of a sharpness bar.
+    pub const ALL: [Sharpness; 7] = [
+        Sharpness::Red,
+        Sharpness::Orange,
+        Sharpness::Yellow,
+        Sharpness::Green,
+        Sharpness::Blue,
+        Sharpness::White,
+        Sharpness::Purple,
+    ];
+
+    pub fn raw_multiplier(&self) -> f32 {
+        use Sharpness::*;
+        match self {
+            Red => 0.5,
+            Orange => 0.75,
+            Yellow => 1.0,
+            Green => 1.05,
+            Blue => 1.2,
+            White => 1.32,
+            Purple => 1.39,
+        }
+    }
+
+    pub fn element_multiplier(&self) -> f32 {
+        use Sharpness::*;
+        match self {
+            Red => 0.25,
+            Orange => 0.5,
+            Yellow => 0.75,
+            Green => 1.0,
+            Blue => 1.0625,
+            White => 1.15,
+            Purple => 1.2,
+        }
+    }
+
+    pub fn next(self) -> Self {
+        cycle(&Self::ALL, self, 1)
+    }
+
+    pub fn prev(self) -> Self {
+        cycle(&Self::ALL, self, Self::ALL.len() - 1)
+    }
+}
+
+impl Display for Sharpness {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use Sharpness::*;
+        match self {
+            Red => write!(f, "Red"),
+            Orange => write!(f, "Orange"),
+            Yellow => write!(f, "Yellow"),
+            Green => write!(f, "Green"),
+            Blue => write!(f, "Blue"),
+            White => write!(f, "White"),
+            Purple => write!(f, "Purple"),
+        }
+    }
+}
+
+impl Serialize for WeaponType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.key())
+    }
+}
+
+impl<'de> Deserialize<'de> for WeaponType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(WeaponTypeVisitor)
+    }
+}
+
+struct WeaponTypeVisitor;
+
+impl<'de> Visitor<'de> for WeaponTypeVisitor {
+    type Value = WeaponType;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("Weapon Type")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        WeaponType::from_key(v).ok_or_else(|| E::invalid_value(Unexpected::Str(v), &self))
+    }
+}
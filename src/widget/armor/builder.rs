@@ -0,0 +1,84 @@
+use ratatui::{
+    layout::{Constraint, Layout},
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph, Widget, Wrap},
+};
+
+use crate::armor::{ArmorKind, ArmorSet};
+use crate::theme::Theme;
+
+use super::detail::{resistance_spans, skill_spans};
+
+/// Pieces picked in the set builder and their totals.
+pub struct SetBuilder<'a>(pub &'a ArmorSet, pub &'a Theme);
+
+impl Widget for SetBuilder<'_> {
+    fn render(self, area: ratatui::prelude::Rect, buf: &mut ratatui::prelude::Buffer) {
+        let set = self.0;
+        let theme = self.1;
+
+        let block = Block::new()
+            .title(" Set Builder ")
+            .title_style(Style::default().bold())
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded);
+
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        let [pieces_chunk, totals_chunk] =
+            Layout::horizontal([Constraint::Percentage(40), Constraint::Percentage(60)])
+                .horizontal_margin(1)
+                .areas(inner);
+
+        let pieces: Vec<Line> = ArmorKind::ALL
+            .iter()
+            .zip(&set.pieces)
+            .map(|(kind, piece)| {
+                Line::from(vec![
+                    Span::styled(format!("{:<7}", kind.to_string()), Style::default().bold()),
+                    match piece {
+                        Some(piece) => Span::from(piece.name.clone()),
+                        None => Span::styled("-", Style::default().fg(theme.ui.dim)),
+                    },
+                ])
+            })
+            .collect();
+
+        Paragraph::new(pieces).render(pieces_chunk, buf);
+
+        let mut resistances = vec![Span::styled("Resistances ", Style::default().bold())];
+        resistances.extend(resistance_spans(theme, &set.resistances()));
+
+        let mut skills = vec![Span::styled("Skills ", Style::default().bold())];
+        skills.extend(skill_spans(theme, &set.skills()));
+
+        Paragraph::new(vec![
+            Line::from(vec![
+                Span::styled("Defense ", Style::default().bold()),
+                Span::from(set.defense().to_string()),
+            ]),
+            Line::from(resistances),
+            Line::from(skills),
+        ])
+        .wrap(Wrap { trim: true })
+        .render(totals_chunk, buf);
+
+        // hint for an empty builder
+        if set.pieces.iter().all(Option::is_none) {
+            Line::from(Span::styled(
+                "Press (a) on a piece to add it",
+                Style::default().italic().fg(theme.ui.dim),
+            ))
+            .render(
+                ratatui::prelude::Rect {
+                    y: inner.bottom().saturating_sub(1),
+                    height: 1,
+                    ..pieces_chunk
+                },
+                buf,
+            );
+        }
+    }
+}
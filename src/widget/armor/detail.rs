@@ -0,0 +1,144 @@
+use ratatui::{
+    layout::{Constraint, Layout},
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph, StatefulWidget, Widget},
+};
+
+use crate::{
+    armor::{ArmorSkill, Resistances},
+    state::{armor::ArmorListState, list_detail::ListPane},
+    theme::{glyph::Glyphs, Theme},
+    widget::crafting::CraftingTable,
+};
+
+pub struct ArmorDetail<'a>(pub &'a Theme, pub &'a Glyphs);
+
+impl StatefulWidget for ArmorDetail<'_> {
+    type State = ArmorListState;
+
+    fn render(
+        self,
+        area: ratatui::prelude::Rect,
+        buf: &mut ratatui::prelude::Buffer,
+        state: &mut Self::State,
+    ) {
+        let theme = self.0;
+        let glyphs = self.1;
+        let piece = state.list.current_data.clone();
+
+        let block = Block::new()
+            .title(format!(" {} ", piece.name))
+            .title_style(Style::default().bold())
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(theme.accent_style(matches!(state.list.focused_pane, ListPane::Detail)));
+
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        let [info_chunk, table_chunk] =
+            Layout::vertical([Constraint::Length(5), Constraint::Min(1)])
+                .horizontal_margin(1)
+                .areas(inner);
+
+        let label = |text: &'static str| Span::styled(text, Style::default().bold());
+
+        let slots = match piece.slots.is_empty() {
+            true => String::from("-"),
+            false => piece
+                .slots
+                .iter()
+                .map(|s| format!("[{}]", s))
+                .collect::<String>(),
+        };
+
+        let mut skills = vec![label("Skills ")];
+        skills.extend(skill_spans(theme, &piece.skills));
+
+        let mut resistances = vec![label("Resistances ")];
+        resistances.extend(resistance_spans(theme, &piece.resistances));
+
+        Paragraph::new(vec![
+            Line::from(vec![
+                label("Set "),
+                Span::from(piece.set.clone()),
+                Span::from("  "),
+                label("Rank "),
+                Span::from(piece.rank.to_string()),
+                Span::from("  "),
+                label("Kind "),
+                Span::from(piece.kind.to_string()),
+                Span::from("  "),
+                label("Rarity "),
+                Span::styled(
+                    piece.rarity.to_string(),
+                    Style::default().fg(theme.ui.highlight),
+                ),
+            ]),
+            Line::from(vec![
+                label("Defense "),
+                Span::from(piece.defense.to_string()),
+                Span::from("  "),
+                label("Slots "),
+                Span::from(slots),
+            ]),
+            Line::from(resistances),
+            Line::from(skills),
+        ])
+        .render(info_chunk, buf);
+
+        StatefulWidget::render(
+            CraftingTable {
+                materials: &piece.materials,
+                index: &state.materials,
+                focused: state.list.focused_pane == ListPane::Detail,
+                theme,
+                glyphs,
+            },
+            table_chunk,
+            buf,
+            &mut state.list.detail_table_state,
+        );
+    }
+}
+
+pub(super) fn resistance_spans(theme: &Theme, resistances: &Resistances) -> Vec<Span<'static>> {
+    ["Fire", "Water", "Thunder", "Ice", "Dragon"]
+        .into_iter()
+        .zip(resistances.values())
+        .flat_map(|(element, value)| {
+            let color = match value {
+                v if v > 0 => theme.ui.positive,
+                v if v < 0 => theme.ui.negative,
+                _ => theme.ui.muted,
+            };
+            [
+                Span::from(format!("{} ", element)),
+                Span::styled(format!("{:+}", value), Style::default().fg(color)),
+                Span::from("  "),
+            ]
+        })
+        .collect()
+}
+
+pub(super) fn skill_spans(theme: &Theme, skills: &[ArmorSkill]) -> Vec<Span<'static>> {
+    if skills.is_empty() {
+        return vec![Span::from("-")];
+    }
+
+    skills
+        .iter()
+        .enumerate()
+        .flat_map(|(i, skill)| {
+            [
+                Span::from(if i == 0 { "" } else { ", " }),
+                Span::from(skill.name.clone()),
+                Span::styled(
+                    format!(" Lv{}", skill.level),
+                    Style::default().fg(theme.ui.highlight),
+                ),
+            ]
+        })
+        .collect()
+}
@@ -0,0 +1,107 @@
+use ratatui::{
+    layout::{Constraint, Layout},
+    style::{Modifier, Style},
+    text::{Line, Span, Text},
+    widgets::{
+        Block, Borders, HighlightSpacing, List, ListItem, Scrollbar, ScrollbarOrientation,
+        StatefulWidget, Widget,
+    },
+};
+
+use crate::state::{armor::ArmorListState, list_detail::ListPane};
+use crate::theme::{glyph::Glyphs, Theme};
+
+pub struct ArmorList<'a>(pub &'a Theme, pub &'a Glyphs);
+
+impl StatefulWidget for ArmorList<'_> {
+    type State = ArmorListState;
+
+    fn render(
+        self,
+        area: ratatui::prelude::Rect,
+        buf: &mut ratatui::prelude::Buffer,
+        state: &mut Self::State,
+    ) {
+        let theme = self.0;
+        let glyphs = self.1;
+        let rank_filter = state
+            .rank_filter
+            .map_or_else(|| String::from("All Ranks"), |r| r.to_string());
+        let kind_filter = state
+            .kind_filter
+            .map_or_else(|| String::from("All Kinds"), |k| k.to_string());
+
+        Block::default()
+            .borders(Borders::LEFT)
+            .border_style(theme.accent_style(matches!(state.list.focused_pane, ListPane::List)))
+            .render(area, buf);
+
+        let [filter_chunk, wrapper] = Layout::vertical([Constraint::Length(1), Constraint::Min(1)])
+            .horizontal_margin(1)
+            .areas(area);
+
+        Line::from(vec![
+            Span::styled(
+                format!("[ {} ]", rank_filter),
+                Style::default().fg(theme.ui.muted),
+            ),
+            Span::from(" "),
+            Span::styled(
+                format!("[ {} ]", kind_filter),
+                Style::default().fg(theme.ui.muted),
+            ),
+        ])
+        .render(filter_chunk, buf);
+
+        let pieces = state.list.list_items();
+        let items: Vec<ListItem> = pieces
+            .iter()
+            .enumerate()
+            .map(|(i, piece)| {
+                let mut lines = vec![];
+
+                // the first piece of every set carries the set header
+                let new_set = i == 0
+                    || pieces
+                        .get(i - 1)
+                        .is_some_and(|prev| prev.set != piece.set || prev.rank != piece.rank);
+                if new_set {
+                    lines.push(Line::from(Span::styled(
+                        format!("{} {} {}", piece.set, glyphs.separator, piece.rank),
+                        Style::default()
+                            .fg(theme.ui.highlight)
+                            .add_modifier(Modifier::BOLD),
+                    )));
+                }
+
+                let worn = if state.builder.is_worn(piece) {
+                    Span::styled(
+                        format!("{} ", glyphs.worn),
+                        Style::default().fg(theme.ui.accent),
+                    )
+                } else {
+                    Span::from("  ")
+                };
+                lines.push(Line::from(vec![worn, Span::from(piece.name.to_string())]));
+
+                ListItem::new(Text::from(lines))
+            })
+            .collect();
+
+        StatefulWidget::render(
+            List::new(items)
+                .highlight_style(theme.selected_style())
+                .highlight_symbol("> ")
+                .highlight_spacing(HighlightSpacing::Always),
+            wrapper,
+            buf,
+            &mut state.list.list_state,
+        );
+
+        Scrollbar::new(ScrollbarOrientation::VerticalRight).render(
+            wrapper,
+            buf,
+            &mut state.list.list_scrollbar_state,
+        );
+    }
+}
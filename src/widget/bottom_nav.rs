@@ -1,13 +1,150 @@
 use ratatui::{
-    style::{Color, Style, Stylize},
+    style::{Style, Stylize},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph, StatefulWidget, Widget},
 };
 
-use crate::state::AppState;
+use crate::{
+    data::hunt_log::format_time,
+    keymap::{Action, Scope},
+    state::AppState,
+};
+
+type Hint = (&'static [Action], &'static str);
+
+/// Actions shown in the hint bar of each keymap scope, with the keys taken from the keymap.
+fn hints(scope: Scope) -> &'static [Hint] {
+    match scope {
+        Scope::Global | Scope::Main => &[
+            (&[Action::Quit], "quit"),
+            (&[Action::Down, Action::Up], "Choose Menu"),
+            (&[Action::Select], "Open"),
+        ],
+        Scope::Monster => &[
+            (&[Action::Quit], "quit"),
+            (&[Action::Back], "Back to Main Menu"),
+            (&[Action::Down, Action::Up], "Move"),
+            (&[Action::NextPane, Action::PrevPane], "Switch Pane"),
+            (&[Action::Left, Action::Right], "Page/Tab"),
+            (&[Action::ToggleWeaponElement], "Toggle Weapon-Element"),
+            (&[Action::ToggleAilmentItem], "Toggle Ailment-Item"),
+            (&[Action::CycleDropSource], "Toggle Material Drop"),
+            (&[Action::ToggleDropRank], "Toggle Drop Rank"),
+            (&[Action::Calculator], "Damage Calculator"),
+            (&[Action::MarkCompare], "Mark"),
+            (&[Action::Compare], "Compare"),
+            (&[Action::ToggleFavorite], "Favorite"),
+            (&[Action::FilterFavorites], "Favorites Only"),
+            (&[Action::Recent], "Recent"),
+            (&[Action::EditNotes], "Notes"),
+            (
+                &[Action::Stopwatch, Action::StopStopwatch],
+                "Stopwatch/Stop",
+            ),
+            (&[Action::RecordHunt], "Record Hunt"),
+            (&[Action::Search], "Search"),
+        ],
+        Scope::Calculator => &[
+            (&[Action::Down, Action::Up], "Field"),
+            (&[Action::Left, Action::Right], "Change"),
+            (&[Action::Back, Action::Calculator], "Close"),
+        ],
+        Scope::Compare => &[
+            (&[Action::Down, Action::Up], "Scroll"),
+            (&[Action::Back, Action::Compare], "Close"),
+        ],
+        Scope::Recent => &[
+            (&[Action::Down, Action::Up], "Select"),
+            (&[Action::Select], "Open Monster"),
+            (&[Action::Back, Action::Recent], "Close"),
+        ],
+        Scope::RecordHunt => &[
+            (&[Action::Down, Action::Up], "Field"),
+            (&[Action::Left, Action::Right], "Change"),
+            (&[Action::Select], "Log Hunt"),
+            (&[Action::Back, Action::RecordHunt], "Cancel"),
+        ],
+        Scope::Material => &[
+            (&[Action::Quit], "quit"),
+            (&[Action::Back], "Back to Main Menu"),
+            (&[Action::Down, Action::Up], "Select"),
+            (
+                &[Action::NextPane, Action::Left, Action::Right],
+                "Switch Pane",
+            ),
+            (&[Action::Select], "Open Monster Drops"),
+            (&[Action::Search], "Search"),
+        ],
+        Scope::Quest => &[
+            (&[Action::Quit], "quit"),
+            (&[Action::Back], "Back to Main Menu"),
+            (&[Action::Down, Action::Up], "Select"),
+            (
+                &[Action::NextPane, Action::Left, Action::Right],
+                "Switch Pane",
+            ),
+            (&[Action::Select], "Open Monster"),
+            (&[Action::FilterType], "Filter Type"),
+            (&[Action::FilterLevel], "Filter Level"),
+            (&[Action::Search], "Search"),
+        ],
+        Scope::Weapon => &[
+            (&[Action::Quit], "quit"),
+            (&[Action::Back], "Back to Main Menu"),
+            (&[Action::Down, Action::Up], "Select"),
+            (
+                &[Action::NextPane, Action::Left, Action::Right],
+                "Switch Pane",
+            ),
+            (&[Action::Select], "Open Material"),
+            (&[Action::FilterType], "Filter Type"),
+            (&[Action::Search], "Search"),
+        ],
+        Scope::Armor => &[
+            (&[Action::Quit], "quit"),
+            (&[Action::Back], "Back to Main Menu"),
+            (&[Action::Down, Action::Up], "Select"),
+            (
+                &[Action::NextPane, Action::Left, Action::Right],
+                "Switch Pane",
+            ),
+            (&[Action::Select], "Open Material"),
+            (&[Action::TogglePiece, Action::ClearSet], "Add/Clear Set"),
+            (
+                &[Action::FilterRank, Action::FilterKind],
+                "Filter Rank/Kind",
+            ),
+            (&[Action::Search], "Search"),
+        ],
+        Scope::WeakTo => &[
+            (&[Action::Quit], "quit"),
+            (&[Action::Back], "Back to Main Menu"),
+            (&[Action::Down, Action::Up], "Select"),
+            (
+                &[Action::NextPane, Action::Left, Action::Right],
+                "Switch Pane",
+            ),
+            (&[Action::Select], "Open Monster"),
+            (&[Action::ToggleRankBy], "Rank By Best/Average"),
+        ],
+        Scope::HuntLog => &[
+            (&[Action::Quit], "quit"),
+            (&[Action::Back], "Back to Main Menu"),
+            (&[Action::Down, Action::Up], "Select"),
+            (&[Action::Select], "Open Monster"),
+        ],
+    }
+}
 
 pub struct BottomNavigation;
 
+impl BottomNavigation {
+    /// Rows the bar takes at `width`: the border and the hints wrapped to fit.
+    pub fn height(state: &AppState, width: u16) -> u16 {
+        wrap(nav_groups(state), width).len() as u16 + 1
+    }
+}
+
 impl StatefulWidget for BottomNavigation {
     type State = AppState;
 
@@ -17,74 +154,156 @@ impl StatefulWidget for BottomNavigation {
         buf: &mut ratatui::prelude::Buffer,
         state: &mut Self::State,
     ) {
-        let nav_block = Block::default().borders(Borders::TOP);
+        Paragraph::new(wrap(nav_groups(state), area.width))
+            .block(Block::default().borders(Borders::TOP))
+            .render(area, buf);
+    }
+}
 
-        let mut basic_nav = vec![
-            Span::styled("(q)", Style::default().fg(Color::White).bold()),
-            Span::from(" "),
-            Span::styled("quit", Style::default().fg(Color::Gray)),
-            Span::from(" "),
-            Span::styled("(Esc)", Style::default().fg(Color::White).bold()),
+/// Pack the groups into lines of `width`, a group is never split unless it is wider
+/// than a line.
+fn wrap(groups: Vec<Vec<Span<'static>>>, width: u16) -> Vec<Line<'static>> {
+    let mut lines: Vec<Line> = vec![];
+    for group in groups {
+        let group_width: usize = group.iter().map(Span::width).sum();
+        match lines.last_mut() {
+            Some(line) if line.width() + group_width <= width as usize => line.spans.extend(group),
+            _ => lines.push(Line::from(group)),
+        }
+    }
+    lines
+}
+
+/// The current screen, what is going on and the hints of the keymap scope, each a group
+/// of spans kept on one line.
+fn nav_groups(state: &AppState) -> Vec<Vec<Span<'static>>> {
+    let theme = &state.theme;
+
+    let scope = state.keymap_scope();
+    let mut basic_nav = Vec::new();
+    for (actions, label) in hints(scope) {
+        let keys: Vec<String> = actions
+            .iter()
+            .flat_map(|action| state.keymap.keys(scope, *action))
+            .map(|sequence| sequence.to_string())
+            .collect();
+        if keys.is_empty() {
+            continue;
+        }
+
+        basic_nav.push(vec![
+            Span::styled(
+                format!("({})", keys.join("/")),
+                Style::default().fg(theme.ui.text).bold(),
+            ),
             Span::from(" "),
-            Span::styled("Back to Main Menu", Style::default().fg(Color::Gray)),
+            Span::styled(*label, Style::default().fg(theme.ui.muted)),
             Span::from(" "),
-        ];
+        ]);
+    }
 
+    // TODO: update key nav
+    let mut nav_text = vec![vec![
+        // display current screen
         match state.current_screen {
-            crate::state::CurrentScreen::Main => {
-                basic_nav.extend([
-                    Span::styled("(j/k/down/up)", Style::default().fg(Color::White).bold()),
-                    Span::from(" "),
-                    Span::styled("Choose Menu", Style::default().fg(Color::Gray)),
-                ]);
-            }
-            crate::state::CurrentScreen::Monster => {
-                basic_nav.extend([
-                    Span::styled("(j/k/down/up)", Style::default().fg(Color::White).bold()),
-                    Span::from(" "),
-                    Span::styled("Select Monster", Style::default().fg(Color::Gray)),
-                    Span::from(" "),
-                    Span::styled("(4)", Style::default().fg(Color::White).bold()),
-                    Span::from(" "),
-                    Span::styled("Toggle Weapon-Element", Style::default().fg(Color::Gray)),
-                    Span::from(" "),
-                    Span::styled("($)", Style::default().fg(Color::White).bold()),
-                    Span::from(" "),
-                    Span::styled("Toggle Ailment-Item", Style::default().fg(Color::Gray)),
-                    Span::from(" "),
-                    Span::styled("(5)", Style::default().fg(Color::White).bold()),
-                    Span::from(" "),
-                    Span::styled("Toggle Material Drop", Style::default().fg(Color::Gray)),
-                    Span::from(" "),
-                    Span::styled("(%)", Style::default().fg(Color::White).bold()),
-                    Span::from(" "),
-                    Span::styled("Toggle Drop Rank", Style::default().fg(Color::Gray)),
-                ]);
-            }
-            _ => {}
-        }
+            crate::state::CurrentScreen::Main => Span::styled(
+                " Main ",
+                Style::default().fg(theme.ui.text).bg(theme.ui.surface),
+            ),
+            crate::state::CurrentScreen::Monster => Span::styled(
+                " Monster ",
+                Style::default().fg(theme.ui.text).bg(theme.ui.surface),
+            ),
+            crate::state::CurrentScreen::Material => Span::styled(
+                " Material ",
+                Style::default().fg(theme.ui.text).bg(theme.ui.surface),
+            ),
+            crate::state::CurrentScreen::Quest => Span::styled(
+                " Quest ",
+                Style::default().fg(theme.ui.text).bg(theme.ui.surface),
+            ),
+            crate::state::CurrentScreen::Weapon => Span::styled(
+                " Weapon ",
+                Style::default().fg(theme.ui.text).bg(theme.ui.surface),
+            ),
+            crate::state::CurrentScreen::Armor => Span::styled(
+                " Armor ",
+                Style::default().fg(theme.ui.text).bg(theme.ui.surface),
+            ),
+            crate::state::CurrentScreen::WeakTo => Span::styled(
+                " Weak To ",
+                Style::default().fg(theme.ui.text).bg(theme.ui.surface),
+            ),
+            crate::state::CurrentScreen::HuntLog => Span::styled(
+                " Hunt Log ",
+                Style::default().fg(theme.ui.text).bg(theme.ui.surface),
+            ),
+        },
+        Span::styled("  ", Style::default()),
+    ]];
 
-        // TODO: update key nav
-        let mut nav_text = vec![
-            // display current screen
-            match state.current_screen {
-                crate::state::CurrentScreen::Main => Span::styled(
-                    " Main ",
-                    Style::default().fg(Color::White).bg(Color::DarkGray),
-                ),
-                crate::state::CurrentScreen::Monster => Span::styled(
-                    " Monster ",
-                    Style::default().fg(Color::White).bg(Color::DarkGray),
+    if let (crate::state::CurrentScreen::Monster, Some(marked)) =
+        (state.current_screen, &state.compare.marked)
+    {
+        nav_text.push(vec![
+            Span::styled(
+                format!(" vs {} ", marked.name.name),
+                Style::default().fg(theme.ui.accent).bold(),
+            ),
+            Span::from("  "),
+        ]);
+    }
+
+    let stopwatch = &state.hunt_log.stopwatch;
+    if let Some(id) = stopwatch.monster_id {
+        let name = state
+            .monster_list
+            .bundle
+            .iter()
+            .find(|monster| monster.id == id)
+            .map(|monster| monster.name.name.as_str())
+            .unwrap_or_default();
+        let (color, paused) = match stopwatch.is_running() {
+            true => (theme.ui.highlight, ""),
+            false => (theme.ui.dim, " (paused)"),
+        };
+        nav_text.push(vec![
+            Span::styled(
+                format!(
+                    " {} {}{} ",
+                    format_time(stopwatch.elapsed().as_secs()),
+                    name,
+                    paused
                 ),
-                _ => Span::styled(" Other ", Style::default().fg(Color::Green)),
-            },
-            Span::styled("  ", Style::default()),
-        ];
+                Style::default().fg(color).bold(),
+            ),
+            Span::from("  "),
+        ]);
+    }
 
-        nav_text.extend(basic_nav);
+    if let Some(error) = &state.hunt_log.error {
+        nav_text.push(vec![
+            Span::styled(format!(" {error} "), Style::default().fg(theme.ui.negative)),
+            Span::from("  "),
+        ]);
+    }
 
-        Paragraph::new(Line::from(nav_text))
-            .block(nav_block)
-            .render(area, buf);
+    if !state.key_handle.pending.is_empty() {
+        let pending: Vec<String> = state
+            .key_handle
+            .pending
+            .iter()
+            .map(|chord| chord.to_string())
+            .collect();
+        nav_text.push(vec![
+            Span::styled(
+                format!(" {} ", pending.join(" ")),
+                Style::default().fg(theme.ui.highlight).bold(),
+            ),
+            Span::from("  "),
+        ]);
     }
+
+    nav_text.extend(basic_nav);
+    nav_text
 }
@@ -0,0 +1,98 @@
+use ratatui::{
+    layout::Constraint,
+    style::{Style, Stylize},
+    text::{Span, Text},
+    widgets::{Block, Borders, Cell, HighlightSpacing, Row, StatefulWidget, Table, TableState},
+};
+
+use crate::{
+    data::material_index::MaterialIndex,
+    theme::{glyph::Glyphs, Theme},
+    weapon::CraftingMaterial,
+};
+
+/// Crafting materials of a weapon or armor piece with the best monster drop for each.
+pub struct CraftingTable<'a> {
+    pub materials: &'a [CraftingMaterial],
+    pub index: &'a MaterialIndex,
+    pub focused: bool,
+    pub theme: &'a Theme,
+    pub glyphs: &'a Glyphs,
+}
+
+impl StatefulWidget for CraftingTable<'_> {
+    type State = TableState;
+
+    fn render(
+        self,
+        area: ratatui::prelude::Rect,
+        buf: &mut ratatui::prelude::Buffer,
+        state: &mut Self::State,
+    ) {
+        let header = ["Material", "Qty", "Best Source", "Chance"]
+            .into_iter()
+            .map(|h| Cell::from(Span::styled(h, Style::default().fg(self.theme.ui.text))))
+            .collect::<Row>()
+            .style(Style::default().bold())
+            .height(1);
+
+        let rows: Vec<Row<'_>> = self
+            .materials
+            .iter()
+            .map(|m| {
+                let best = self
+                    .index
+                    .find(&m.material)
+                    .and_then(|entry| entry.sources.first());
+                let (source, chance) = match best {
+                    Some(s) => (
+                        format!(
+                            "{} ({} {}{})",
+                            s.monster,
+                            s.rank,
+                            s.source,
+                            s.part
+                                .as_ref()
+                                .map(|p| format!(", {}", p))
+                                .unwrap_or_default()
+                        ),
+                        format!("{}%", s.percentage),
+                    ),
+                    None => (String::from("Not dropped by monsters"), String::from("-")),
+                };
+
+                Row::new([
+                    Cell::from(m.material.clone()),
+                    Cell::from(format!("x{}", m.quantity)),
+                    Cell::from(source),
+                    Cell::from(Text::from(chance).right_aligned()),
+                ])
+            })
+            .collect();
+
+        let columns = [
+            Constraint::Percentage(30),
+            Constraint::Percentage(10),
+            Constraint::Percentage(50),
+            Constraint::Percentage(10),
+        ];
+
+        let highlight_style = if self.focused {
+            self.theme.row_style()
+        } else {
+            Style::default()
+        };
+
+        StatefulWidget::render(
+            Table::new(rows, columns)
+                .header(header)
+                .block(Block::new().borders(Borders::TOP).title(" Materials "))
+                .row_highlight_style(highlight_style)
+                .highlight_symbol(self.theme.row_marker(self.glyphs))
+                .highlight_spacing(HighlightSpacing::Always),
+            area,
+            buf,
+            state,
+        );
+    }
+}
@@ -0,0 +1,14 @@
+pub mod overview;
+pub mod recent;
+
+use std::rc::Rc;
+
+use crate::monster::monster::MonsterEntity;
+
+/// Name of monster `id`, its id when it is not in the data.
+fn monster_name(bundle: &[Rc<MonsterEntity>], id: u16) -> String {
+    bundle
+        .iter()
+        .find(|monster| monster.id == id)
+        .map_or_else(|| format!("#{id}"), |monster| monster.name.name.clone())
+}
@@ -0,0 +1,171 @@
+use std::rc::Rc;
+
+use ratatui::{
+    layout::{Constraint, Layout},
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::{
+        Block, BorderType, Borders, Cell, HighlightSpacing, Paragraph, Row, StatefulWidget, Table,
+        Widget,
+    },
+};
+
+use crate::{
+    data::hunt_log::{format_time, RankRecord},
+    monster::monster::MonsterEntity,
+    state::hunt_log::HuntLogState,
+    theme::{glyph::Glyphs, Theme},
+};
+
+use super::monster_name;
+
+/// Totals of the hunt log and the record of every hunted monster.
+pub struct HuntLogOverview<'a>(pub &'a [Rc<MonsterEntity>], pub &'a Theme, pub &'a Glyphs);
+
+impl StatefulWidget for HuntLogOverview<'_> {
+    type State = HuntLogState;
+
+    fn render(
+        self,
+        area: ratatui::prelude::Rect,
+        buf: &mut ratatui::prelude::Buffer,
+        state: &mut Self::State,
+    ) {
+        let theme = self.1;
+        let glyphs = self.2;
+        let block = Block::new()
+            .title(" Hunt Log ")
+            .title_style(Style::default().bold())
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(theme.accent_style(true));
+
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        let records = state.log.monster_records();
+        if records.is_empty() {
+            Line::styled(
+                " No hunts logged yet",
+                Style::default().fg(theme.ui.dim).italic(),
+            )
+            .render(inner, buf);
+            return;
+        }
+
+        let [summary_chunk, table_chunk] =
+            Layout::vertical([Constraint::Length(3), Constraint::Min(1)])
+                .horizontal_margin(1)
+                .areas(inner);
+
+        let (slays, captures) = records.iter().fold((0, 0), |(slays, captures), record| {
+            (
+                slays + record.low.slays + record.high.slays,
+                captures + record.low.captures + record.high.captures,
+            )
+        });
+        let label = |text: &'static str| Span::styled(text, Style::default().bold());
+        let mut highlights = vec![
+            label("Most Hunted "),
+            Span::from(monster_name(self.0, records[0].monster_id)),
+        ];
+        if let Some(fastest) = state.log.fastest() {
+            highlights.extend([
+                Span::from("  "),
+                label("Fastest Clear "),
+                Span::styled(
+                    format_time(fastest.time.unwrap_or_default()),
+                    Style::default().fg(theme.ui.positive),
+                ),
+                Span::from(format!(
+                    " {} ({})",
+                    monster_name(self.0, fastest.monster_id),
+                    fastest.rank
+                )),
+            ]);
+        }
+
+        Paragraph::new(vec![
+            Line::from(vec![
+                label("Hunts "),
+                Span::from(state.log.hunts.len().to_string()),
+                Span::from("  "),
+                label("Slain "),
+                Span::from(slays.to_string()),
+                Span::from("  "),
+                label("Captured "),
+                Span::from(captures.to_string()),
+                Span::from("  "),
+                label("Monsters "),
+                Span::from(records.len().to_string()),
+                Span::from("  "),
+                label("Time Hunting "),
+                Span::from(format_time(state.log.total_time())),
+            ]),
+            Line::from(highlights),
+        ])
+        .render(summary_chunk, buf);
+
+        let header = [
+            "Monster",
+            "LR Slain",
+            "LR Captured",
+            "LR Best",
+            "HR Slain",
+            "HR Captured",
+            "HR Best",
+            "Hunts",
+        ]
+        .into_iter()
+        .map(|h| Cell::from(Span::styled(h, Style::default().fg(theme.ui.text))))
+        .collect::<Row>()
+        .style(Style::default().bold())
+        .height(1);
+
+        let rank_cells = |record: &RankRecord| {
+            [
+                Cell::from(record.slays.to_string()),
+                Cell::from(record.captures.to_string()),
+                Cell::from(match record.best_time {
+                    Some(time) => {
+                        Span::styled(format_time(time), Style::default().fg(theme.ui.positive))
+                    }
+                    None => Span::styled("-", Style::default().fg(theme.ui.dim)),
+                }),
+            ]
+        };
+
+        let rows: Vec<Row<'_>> = records
+            .iter()
+            .map(|record| {
+                let mut cells = vec![Cell::from(monster_name(self.0, record.monster_id))];
+                cells.extend(rank_cells(&record.low));
+                cells.extend(rank_cells(&record.high));
+                cells.push(Cell::from(record.hunts().to_string()));
+                Row::new(cells)
+            })
+            .collect();
+
+        let columns = [
+            Constraint::Length(30),
+            Constraint::Length(9),
+            Constraint::Length(12),
+            Constraint::Length(9),
+            Constraint::Length(9),
+            Constraint::Length(12),
+            Constraint::Length(9),
+            Constraint::Fill(1),
+        ];
+
+        StatefulWidget::render(
+            Table::new(rows, columns)
+                .header(header)
+                .row_highlight_style(theme.row_style())
+                .highlight_symbol(theme.row_marker(glyphs))
+                .highlight_spacing(HighlightSpacing::Always),
+            table_chunk,
+            buf,
+            &mut state.table_state,
+        );
+    }
+}
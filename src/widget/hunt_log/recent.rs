@@ -0,0 +1,63 @@
+use std::rc::Rc;
+
+use ratatui::{
+    layout::{Constraint, Layout},
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, Widget},
+};
+
+use crate::{
+    data::hunt_log::{format_time, HuntLog},
+    monster::monster::MonsterEntity,
+    theme::Theme,
+};
+
+use super::monster_name;
+
+/// The latest hunts of the log, the most recent first.
+pub struct RecentHunts<'a> {
+    pub log: &'a HuntLog,
+    pub bundle: &'a [Rc<MonsterEntity>],
+    pub theme: &'a Theme,
+}
+
+impl Widget for RecentHunts<'_> {
+    fn render(self, area: ratatui::prelude::Rect, buf: &mut ratatui::prelude::Buffer) {
+        Block::default()
+            .borders(Borders::LEFT)
+            .border_style(self.theme.accent_style(false))
+            .render(area, buf);
+
+        let [title_chunk, wrapper] = Layout::vertical([Constraint::Length(2), Constraint::Min(1)])
+            .horizontal_margin(1)
+            .areas(area);
+
+        Line::styled("Recent Hunts", Style::default().bold()).render(title_chunk, buf);
+
+        let items: Vec<ListItem> = self
+            .log
+            .hunts
+            .iter()
+            .rev()
+            .take(wrapper.height as usize / 2)
+            .map(|hunt| {
+                let mut detail = vec![Span::from(format!("{} {}", hunt.rank, hunt.outcome))];
+                if let Some(time) = hunt.time {
+                    detail.push(Span::from(" "));
+                    detail.push(Span::styled(
+                        format_time(time),
+                        Style::default().fg(self.theme.ui.positive),
+                    ));
+                }
+
+                ListItem::new(vec![
+                    Line::from(monster_name(self.bundle, hunt.monster_id)),
+                    Line::from(detail).fg(self.theme.ui.muted),
+                ])
+            })
+            .collect();
+
+        List::new(items).render(wrapper, buf);
+    }
+}
@@ -0,0 +1,58 @@
+use ratatui::{
+    layout::{Constraint, Layout},
+    text::Text,
+    widgets::{
+        Block, Borders, HighlightSpacing, List, ListItem, Scrollbar, ScrollbarOrientation,
+        StatefulWidget, Widget,
+    },
+};
+
+use crate::state::{list_detail::ListPane, material::MaterialListState};
+use crate::theme::Theme;
+
+pub struct MaterialList<'a>(pub &'a Theme);
+
+impl StatefulWidget for MaterialList<'_> {
+    type State = MaterialListState;
+
+    fn render(
+        self,
+        area: ratatui::prelude::Rect,
+        buf: &mut ratatui::prelude::Buffer,
+        state: &mut Self::State,
+    ) {
+        let theme = self.0;
+        Block::default()
+            .borders(Borders::LEFT)
+            .border_style(theme.accent_style(matches!(state.list.focused_pane, ListPane::List)))
+            .render(area, buf);
+
+        let [wrapper] = Layout::default()
+            .horizontal_margin(1)
+            .constraints([Constraint::Min(0)])
+            .areas(area);
+
+        let items: Vec<ListItem> = state
+            .list
+            .list_items()
+            .iter()
+            .map(|item| ListItem::new(Text::from(item.material.to_string())))
+            .collect();
+
+        StatefulWidget::render(
+            List::new(items)
+                .highlight_style(theme.selected_style())
+                .highlight_symbol("> ")
+                .highlight_spacing(HighlightSpacing::Always),
+            wrapper,
+            buf,
+            &mut state.list.list_state,
+        );
+
+        Scrollbar::new(ScrollbarOrientation::VerticalRight).render(
+            wrapper,
+            buf,
+            &mut state.list.list_scrollbar_state,
+        );
+    }
+}
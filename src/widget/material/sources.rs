@@ -0,0 +1,112 @@
+use ratatui::{
+    layout::{Constraint, Layout},
+    style::{Style, Stylize},
+    text::{Line, Span, Text},
+    widgets::{
+        Block, BorderType, Borders, Cell, HighlightSpacing, Paragraph, Row, StatefulWidget, Table,
+        Widget,
+    },
+};
+
+use crate::state::{list_detail::ListPane, material::MaterialListState};
+use crate::theme::{glyph::Glyphs, Theme};
+
+pub struct MaterialSourceTable<'a>(pub &'a Theme, pub &'a Glyphs);
+
+impl StatefulWidget for MaterialSourceTable<'_> {
+    type State = MaterialListState;
+
+    fn render(
+        self,
+        area: ratatui::prelude::Rect,
+        buf: &mut ratatui::prelude::Buffer,
+        state: &mut Self::State,
+    ) {
+        let theme = self.0;
+        let glyphs = self.1;
+        let entry = state.list.current_data.clone();
+
+        let block = Block::new()
+            .title(format!(" {} ", entry.material))
+            .title_style(Style::default().bold())
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(theme.accent_style(matches!(state.list.focused_pane, ListPane::Detail)));
+
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        let [summary_chunk, table_chunk] =
+            Layout::vertical([Constraint::Length(2), Constraint::Min(1)])
+                .horizontal_margin(1)
+                .areas(inner);
+
+        let monster_count = {
+            let mut monsters = entry
+                .sources
+                .iter()
+                .map(|s| s.monster_id)
+                .collect::<Vec<u16>>();
+            monsters.sort();
+            monsters.dedup();
+            monsters.len()
+        };
+
+        Paragraph::new(Line::from(vec![
+            Span::styled("Best chance ", Style::default().bold()),
+            Span::styled(
+                format!("{}%", entry.best_percentage()),
+                Style::default().fg(theme.ui.highlight),
+            ),
+            Span::from("  "),
+            Span::styled("Dropped by ", Style::default().bold()),
+            Span::from(format!("{} monster(s)", monster_count)),
+        ]))
+        .render(summary_chunk, buf);
+
+        let header = ["Monster", "Rank", "Source", "Part", "Chance"]
+            .into_iter()
+            .map(|h| Cell::from(Span::styled(h, Style::default().fg(theme.ui.text))))
+            .collect::<Row>()
+            .style(Style::default().bold())
+            .height(1);
+
+        let rows: Vec<Row<'_>> = entry
+            .sources
+            .iter()
+            .map(|s| {
+                Row::new([
+                    Cell::from(s.monster.clone()),
+                    Cell::from(s.rank.to_string()),
+                    Cell::from(s.source.to_string()),
+                    Cell::from(s.part.clone().unwrap_or_else(|| String::from("-"))),
+                    Cell::from(Text::from(format!("{}%", s.percentage)).right_aligned()),
+                ])
+            })
+            .collect();
+
+        let columns = [
+            Constraint::Percentage(30),
+            Constraint::Percentage(15),
+            Constraint::Percentage(20),
+            Constraint::Percentage(25),
+            Constraint::Percentage(10),
+        ];
+
+        let highlight_style = match state.list.focused_pane {
+            ListPane::Detail => theme.row_style(),
+            ListPane::List => Style::default(),
+        };
+
+        StatefulWidget::render(
+            Table::new(rows, columns)
+                .header(header)
+                .row_highlight_style(highlight_style)
+                .highlight_symbol(theme.row_marker(glyphs))
+                .highlight_spacing(HighlightSpacing::Always),
+            table_chunk,
+            buf,
+            &mut state.list.detail_table_state,
+        );
+    }
+}
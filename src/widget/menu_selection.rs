@@ -6,6 +6,7 @@ use ratatui::{
 };
 
 use crate::state::{AppState, MainMenuOption};
+use crate::theme::{glyph::GlyphSet, Theme};
 
 pub struct MenuSelection;
 
@@ -18,68 +19,92 @@ impl StatefulWidget for MenuSelection {
         buf: &mut ratatui::prelude::Buffer,
         state: &mut Self::State,
     ) {
-        let [monster_rect, quest_rect, weapon_rect, armor_rect] = Layout::vertical([
-            Constraint::Length(4),
-            Constraint::Length(4),
-            Constraint::Length(4),
-            Constraint::Length(4),
-        ])
-        .areas(area);
+        let [monster_rect, material_rect, quest_rect, weapon_rect, armor_rect, weak_to_rect, hunt_log_rect] =
+            Layout::vertical([
+                Constraint::Length(4),
+                Constraint::Length(4),
+                Constraint::Length(4),
+                Constraint::Length(4),
+                Constraint::Length(4),
+                Constraint::Length(4),
+                Constraint::Length(4),
+            ])
+            .areas(area);
 
-        Text::from(vec![
-            Line::from(Span::styled(
-                "█▀▄▀█ █▀█ █▄░█ █▀ ▀█▀ █▀▀ █▀█",
-                Style::default().fg(get_color(MainMenuOption::Monster, &state.main_menu)),
-            )),
-            Line::from(Span::styled(
-                "█░▀░█ █▄█ █░▀█ ▄█ ░█░ ██▄ █▀▄",
-                Style::default().fg(get_color(MainMenuOption::Monster, &state.main_menu)),
-            )),
-        ])
-        .render(monster_rect, buf);
+        let items = [
+            (
+                MainMenuOption::Monster,
+                monster_rect,
+                "MONSTER",
+                [
+                    "█▀▄▀█ █▀█ █▄░█ █▀ ▀█▀ █▀▀ █▀█",
+                    "█░▀░█ █▄█ █░▀█ ▄█ ░█░ ██▄ █▀▄",
+                ],
+            ),
+            (
+                MainMenuOption::Material,
+                material_rect,
+                "MATERIAL",
+                [
+                    "█▀▄▀█ ▄▀█ ▀█▀ █▀▀ █▀█ █ ▄▀█ █░░",
+                    "█░▀░█ █▀█ ░█░ ██▄ █▀▄ █ █▀█ █▄▄",
+                ],
+            ),
+            (
+                MainMenuOption::Quest,
+                quest_rect,
+                "QUEST",
+                ["█▀█ █░█ █▀▀ █▀ ▀█▀", "▀▀█ █▄█ ██▄ ▄█ ░█░"],
+            ),
+            (
+                MainMenuOption::Weapon,
+                weapon_rect,
+                "WEAPON",
+                ["█░█░█ █▀▀ ▄▀█ █▀█ █▀█ █▄░█", "▀▄▀▄▀ ██▄ █▀█ █▀▀ █▄█ █░▀█"],
+            ),
+            (
+                MainMenuOption::Armor,
+                armor_rect,
+                "ARMOR",
+                ["▄▀█ █▀█ █▀▄▀█ █▀█ █▀█", "█▀█ █▀▄ █░▀░█ █▄█ █▀▄"],
+            ),
+            (
+                MainMenuOption::WeakTo,
+                weak_to_rect,
+                "WEAK TO",
+                ["█░█░█ █▀▀ ▄▀█ █▄▀   ▀█▀ █▀█", "▀▄▀▄▀ ██▄ █▀█ █░█   ░█░ █▄█"],
+            ),
+            (
+                MainMenuOption::HuntLog,
+                hunt_log_rect,
+                "HUNT LOG",
+                [
+                    "█░█ █░█ █▄░█ ▀█▀   █░░ █▀█ █▀▀",
+                    "█▀█ █▄█ █░▀█ ░█░   █▄▄ █▄█ █▄█",
+                ],
+            ),
+        ];
 
-        // Text::from(vec![
-        //     Line::from(Span::styled(
-        //         "█▀█ █░█ █▀▀ █▀ ▀█▀",
-        //         Style::default().fg(get_color(MainMenuOption::Quest, &state.main_menu)),
-        //     )),
-        //     Line::from(Span::styled(
-        //         "▀▀█ █▄█ ██▄ ▄█ ░█░",
-        //         Style::default().fg(get_color(MainMenuOption::Quest, &state.main_menu)),
-        //     )),
-        // ])
-        // .render(quest_rect, buf);
+        for (option, rect, name, letters) in items {
+            let style = Style::default().fg(get_color(&state.theme, option, &state.main_menu));
+            // block letters turn to noise in ASCII, spell the name instead
+            let lines: Vec<Line> = match state.glyphs.set {
+                GlyphSet::Ascii => vec![Line::from(Span::styled(name, style))],
+                _ => letters
+                    .into_iter()
+                    .map(|line| Line::from(Span::styled(line, style)))
+                    .collect(),
+            };
 
-        // Text::from(vec![
-        //     Line::from(Span::styled(
-        //         "█░█░█ █▀▀ ▄▀█ █▀█ █▀█ █▄░█",
-        //         Style::default().fg(get_color(MainMenuOption::Weapon, &state.main_menu)),
-        //     )),
-        //     Line::from(Span::styled(
-        //         "▀▄▀▄▀ ██▄ █▀█ █▀▀ █▄█ █░▀█",
-        //         Style::default().fg(get_color(MainMenuOption::Weapon, &state.main_menu)),
-        //     )),
-        // ])
-        // .render(weapon_rect, buf);
-
-        // Text::from(vec![
-        //     Line::from(Span::styled(
-        //         "▄▀█ █▀█ █▀▄▀█ █▀█ █▀█",
-        //         Style::default().fg(get_color(MainMenuOption::Armor, &state.main_menu)),
-        //     )),
-        //     Line::from(Span::styled(
-        //         "█▀█ █▀▄ █░▀░█ █▄█ █▀▄",
-        //         Style::default().fg(get_color(MainMenuOption::Armor, &state.main_menu)),
-        //     )),
-        // ])
-        // .render(armor_rect, buf);
+            Text::from(lines).render(rect, buf);
+        }
     }
 }
 
-fn get_color(src: MainMenuOption, target: &MainMenuOption) -> Color {
+fn get_color(theme: &Theme, src: MainMenuOption, target: &MainMenuOption) -> Color {
     if src == *target {
-        Color::White
+        theme.ui.text
     } else {
-        Color::Rgb(128, 128, 128)
+        theme.ui.dim
     }
 }
@@ -1,4 +1,12 @@
 pub mod menu_selection;
 pub mod bottom_nav;
+pub mod crafting;
 
+pub mod material;
 pub mod monster;
+
+pub mod quest;
+pub mod weapon;
+pub mod armor;
+pub mod weak_to;
+pub mod hunt_log;
@@ -6,18 +6,23 @@ use ratatui::{
 };
 
 use crate::monster::monster::MonsterBasicInfo;
+use crate::theme::Theme;
 
-pub struct BasicInfo {
+pub struct BasicInfo<'a> {
     pub basic_info: MonsterBasicInfo,
+    pub theme: &'a Theme,
 }
 
-impl BasicInfo {
-    pub fn new(bi: MonsterBasicInfo) -> Self {
-        Self { basic_info: bi }
+impl<'a> BasicInfo<'a> {
+    pub fn new(bi: MonsterBasicInfo, theme: &'a Theme) -> Self {
+        Self {
+            basic_info: bi,
+            theme,
+        }
     }
 }
 
-impl Widget for BasicInfo {
+impl Widget for BasicInfo<'_> {
     fn render(self, area: ratatui::prelude::Rect, buf: &mut ratatui::prelude::Buffer)
     where
         Self: Sized,
@@ -36,19 +41,31 @@ impl Widget for BasicInfo {
         // match {}  none, minor, weak, strong, then give color for each level
         let text_roar = vec![
             "Roar".bold().into(),
-            self.basic_info.roar.to_string().dark_gray().into(),
+            self.basic_info
+                .roar
+                .to_string()
+                .fg(self.theme.ui.dim)
+                .into(),
         ];
         let info_roar_paragraph = Paragraph::new(text_roar).centered();
 
         let text_wind_pressure = vec![
             "Wind Pressure".bold().into(),
-            self.basic_info.wind_pressure.to_string().dark_gray().into(),
+            self.basic_info
+                .wind_pressure
+                .to_string()
+                .fg(self.theme.ui.dim)
+                .into(),
         ];
         let info_wind_p_paragraph = Paragraph::new(text_wind_pressure).centered();
 
         let text_tremor = vec![
             "Tremor".bold().into(),
-            self.basic_info.tremor.to_string().dark_gray().into(),
+            self.basic_info
+                .tremor
+                .to_string()
+                .fg(self.theme.ui.dim)
+                .into(),
         ];
         let info_tremor_paragraph = Paragraph::new(text_tremor).centered();
 
@@ -60,7 +77,7 @@ impl Widget for BasicInfo {
 
             status_effect_list.push(Span::styled(
                 self.basic_info.status_effect[i].to_string(),
-                Style::new().fg(self.basic_info.status_effect[i].color()),
+                Style::new().fg(self.basic_info.status_effect[i].color(self.theme)),
             ));
             let next = self.basic_info.status_effect.get(i + 1);
 
@@ -0,0 +1,173 @@
+use ratatui::{
+    layout::{Constraint, Layout},
+    style::{Style, Stylize},
+    text::{Line, Span, Text},
+    widgets::{
+        Block, BorderType, Borders, Cell, Clear, Paragraph, Row, StatefulWidget, Table, Widget,
+    },
+};
+
+use crate::{
+    monster::{
+        damage::PartDamage,
+        monster::{DamageData, MonsterElements},
+    },
+    state::calculator::{CalculatorField, CalculatorState},
+    theme::{glyph::Glyphs, Theme},
+};
+
+pub struct DamageCalculator<'a>(pub Vec<DamageData>, pub &'a Theme, pub &'a Glyphs);
+
+impl StatefulWidget for DamageCalculator<'_> {
+    type State = CalculatorState;
+
+    fn render(
+        self,
+        area: ratatui::prelude::Rect,
+        buf: &mut ratatui::prelude::Buffer,
+        state: &mut Self::State,
+    ) {
+        let theme = self.1;
+        let glyphs = self.2;
+        Clear.render(area, buf);
+
+        let block = Block::new()
+            .title(" Damage Calculator ")
+            .title_style(Style::default().bold())
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.ui.accent));
+
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        let [loadout_chunk, table_chunk] =
+            Layout::vertical([Constraint::Length(7), Constraint::Min(1)])
+                .margin(1)
+                .areas(inner);
+
+        let loadout = &state.loadout;
+        let field_line = |field: CalculatorField, label: &str, value: String| {
+            let focused = state.field == field;
+            Line::from(vec![
+                Span::styled(
+                    format!("{:<16}", label),
+                    Style::default().bold().fg(if focused {
+                        theme.ui.accent
+                    } else {
+                        theme.ui.text
+                    }),
+                ),
+                Span::from(if focused { "< " } else { "  " }),
+                Span::from(value),
+                Span::from(if focused { " >" } else { "" }),
+            ])
+        };
+
+        let sharpness = if loadout.weapon.uses_sharpness() {
+            format!(
+                "{} (raw x{} / element x{})",
+                loadout.sharpness,
+                loadout.sharpness.raw_multiplier(),
+                loadout.sharpness.element_multiplier()
+            )
+        } else {
+            String::from("- (ranged weapon)")
+        };
+
+        Paragraph::new(vec![
+            field_line(
+                CalculatorField::Weapon,
+                "Weapon",
+                format!("{} ({})", loadout.weapon, loadout.weapon.damage_type()),
+            ),
+            field_line(
+                CalculatorField::Attack,
+                "Attack",
+                loadout.attack.to_string(),
+            ),
+            field_line(
+                CalculatorField::Element,
+                "Element",
+                loadout.element.to_string(),
+            ),
+            field_line(
+                CalculatorField::ElementValue,
+                "Element Value",
+                loadout.element_value.to_string(),
+            ),
+            field_line(CalculatorField::Sharpness, "Sharpness", sharpness),
+            Line::from(""),
+            Line::from(Span::styled(
+                "Damage of one hit with a motion value of 100",
+                Style::default().italic().fg(theme.ui.dim),
+            )),
+        ])
+        .render(loadout_chunk, buf);
+
+        let damages: Vec<PartDamage> = self
+            .0
+            .iter()
+            .map(|part| loadout.effective_damage(part))
+            .collect();
+
+        let best = damages
+            .iter()
+            .enumerate()
+            .rev()
+            .max_by(|(_, a), (_, b)| a.total().total_cmp(&b.total()))
+            .map(|(i, _)| i);
+
+        let element_header = match loadout.element {
+            MonsterElements::None => String::from("Element"),
+            ref element => element.to_string(),
+        };
+
+        let header = ["Part", "Raw", element_header.as_str(), "Total"]
+            .into_iter()
+            .map(|h| Cell::from(Text::from(h.to_string())))
+            .collect::<Row>()
+            .style(Style::default().bold())
+            .height(1);
+
+        let rows: Vec<Row<'_>> = self
+            .0
+            .iter()
+            .zip(damages.iter())
+            .enumerate()
+            .map(|(i, (part, damage))| {
+                let is_best = Some(i) == best;
+                let row = Row::new([
+                    Cell::from(format!(
+                        "{} {}",
+                        if is_best { glyphs.star } else { " " },
+                        part.monster_part
+                    )),
+                    Cell::from(format!("{:.1}", damage.raw)),
+                    Cell::from(format!("{:.1}", damage.element)),
+                    Cell::from(format!("{:.1}", damage.total())),
+                ]);
+
+                if is_best {
+                    row.style(Style::default().bold().fg(theme.ui.highlight))
+                } else {
+                    row
+                }
+            })
+            .collect();
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Percentage(40),
+                Constraint::Percentage(20),
+                Constraint::Percentage(20),
+                Constraint::Percentage(20),
+            ],
+        )
+        .header(header)
+        .block(Block::new().borders(Borders::TOP));
+
+        Widget::render(table, table_chunk, buf);
+    }
+}
@@ -0,0 +1,317 @@
+use ratatui::{
+    layout::{Constraint, Layout},
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::{
+        Block, BorderType, Borders, Clear, Paragraph, Scrollbar, ScrollbarOrientation,
+        StatefulWidget, Widget,
+    },
+};
+
+use crate::{
+    monster::{
+        compare::{ailment_effs, item_effectiveness, matching_parts, shared_drops},
+        monster::{ElementDamageData, MonsterEntity, HITZONE_ELEMENTS},
+    },
+    state::compare::CompareState,
+    theme::Theme,
+};
+
+/// Width of each monster's column, the hitzones of the five elements take 40 of it.
+const SIDE_WIDTH: usize = 44;
+const HITZONE_WIDTH: usize = 8;
+
+pub struct MonsterCompare<'a>(pub &'a Theme);
+
+impl StatefulWidget for MonsterCompare<'_> {
+    type State = CompareState;
+
+    fn render(
+        self,
+        area: ratatui::prelude::Rect,
+        buf: &mut ratatui::prelude::Buffer,
+        state: &mut Self::State,
+    ) {
+        let theme = self.0;
+        let (Some(left), Some(right)) = (state.marked.clone(), state.other.clone()) else {
+            return;
+        };
+
+        Clear.render(area, buf);
+
+        let block = Block::new()
+            .title(" Compare ")
+            .title_style(Style::default().bold())
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.ui.accent));
+
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        let [header_chunk, content_chunk] =
+            Layout::vertical([Constraint::Length(3), Constraint::Min(1)])
+                .margin(1)
+                .areas(inner);
+
+        let columns = Columns::new(&left, &right);
+        let name = Style::default().fg(theme.ui.accent).bold();
+        let aka = Style::default().fg(theme.ui.muted);
+        Paragraph::new(vec![
+            columns.row(
+                Span::from(""),
+                Span::styled(left.name.name.clone(), name),
+                Span::styled(right.name.name.clone(), name),
+            ),
+            columns.row(
+                Span::from(""),
+                Span::styled(left.name.aka.clone(), aka),
+                Span::styled(right.name.aka.clone(), aka),
+            ),
+        ])
+        .render(header_chunk, buf);
+
+        let lines = compare_lines(theme, &columns, &left, &right);
+        state
+            .scroll
+            .set_height(if lines.len() > content_chunk.height as usize {
+                lines.len()
+            } else {
+                0
+            });
+
+        Paragraph::new(lines)
+            .scroll((state.scroll.position as u16, 0))
+            .render(content_chunk, buf);
+
+        Scrollbar::new(ScrollbarOrientation::VerticalRight).render(
+            content_chunk,
+            buf,
+            &mut state.scroll.scrollbar_state,
+        );
+    }
+}
+
+fn compare_lines<'a>(
+    theme: &Theme,
+    columns: &Columns,
+    left: &'a MonsterEntity,
+    right: &'a MonsterEntity,
+) -> Vec<Line<'a>> {
+    let mut lines = Vec::new();
+
+    section(theme, &mut lines, "Basic Info");
+    let info = |monster: &MonsterEntity| {
+        let mut effects: Vec<String> = monster
+            .basic_info
+            .status_effect
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        effects.sort();
+
+        [
+            ("Type", monster.basic_info.m_type.clone()),
+            ("Roar", monster.basic_info.roar.to_string()),
+            (
+                "Wind Pressure",
+                monster.basic_info.wind_pressure.to_string(),
+            ),
+            ("Tremor", monster.basic_info.tremor.to_string()),
+            ("Status Effects", list(effects)),
+        ]
+    };
+    for ((label, l), (_, r)) in info(left).into_iter().zip(info(right)) {
+        let style = match l == r {
+            true => Style::default(),
+            false => Style::default().fg(theme.ui.highlight).bold(),
+        };
+        lines.push(columns.row(
+            Span::from(label),
+            Span::styled(l, style),
+            Span::styled(r, style),
+        ));
+    }
+
+    section(theme, &mut lines, "Element Weakness");
+    let elements = || {
+        Line::from(
+            HITZONE_ELEMENTS
+                .iter()
+                .map(|e| {
+                    Span::styled(
+                        format!("{:<width$}", e.to_string(), width = HITZONE_WIDTH),
+                        Style::default().fg(e.color(theme)).bold(),
+                    )
+                })
+                .collect::<Vec<Span>>(),
+        )
+    };
+    lines.push(columns.row_of_lines(
+        Span::styled("Part", Style::default().bold()),
+        elements(),
+        elements(),
+    ));
+    let best = |monster: &MonsterEntity| {
+        HITZONE_ELEMENTS.map(|e| monster.weaknesses.max_element_hitzone(&e))
+    };
+    lines.push(hitzone_row(
+        theme,
+        columns,
+        Span::styled("Best Part", Style::default().fg(theme.ui.muted)),
+        best(left),
+        best(right),
+    ));
+    for pair in matching_parts(left, right) {
+        lines.push(hitzone_row(
+            theme,
+            columns,
+            Span::from(pair.part),
+            hitzones(pair.left),
+            hitzones(pair.right),
+        ));
+    }
+
+    section(theme, &mut lines, "Ailments");
+    for (ailment, l, r) in ailment_effs(left, right) {
+        let (l_style, r_style) = better(theme, l, r);
+        lines.push(columns.row(
+            Span::styled(
+                ailment.to_string(),
+                Style::default().fg(ailment.color(theme)),
+            ),
+            Span::styled(l.map_or(String::from("-"), |eff| eff.to_string()), l_style),
+            Span::styled(r.map_or(String::from("-"), |eff| eff.to_string()), r_style),
+        ));
+    }
+
+    section(theme, &mut lines, "Items");
+    let effective = |is_effective: Option<bool>| match is_effective {
+        Some(true) => "Yes",
+        Some(false) => "No",
+        None => "-",
+    };
+    for (item, l, r) in item_effectiveness(left, right) {
+        let (l_style, r_style) = better(theme, l, r);
+        lines.push(columns.row(
+            Span::from(item.to_string()),
+            Span::styled(effective(l), l_style),
+            Span::styled(effective(r), r_style),
+        ));
+    }
+
+    section(theme, &mut lines, "Shared Drops");
+    let drops = shared_drops(left, right);
+    if drops.is_empty() {
+        lines.push(Line::styled(
+            "No material in common",
+            Style::default().fg(theme.ui.dim),
+        ));
+    }
+    for drop in drops {
+        let (l_style, r_style) = better(theme, drop.left, drop.right);
+        lines.push(columns.row(
+            Span::from(drop.material),
+            Span::styled(format!("{}%", drop.left), l_style),
+            Span::styled(format!("{}%", drop.right), r_style),
+        ));
+    }
+
+    lines
+}
+
+/// The label column is as wide as the longest part or material name.
+struct Columns {
+    label_width: usize,
+}
+
+impl Columns {
+    fn new(left: &MonsterEntity, right: &MonsterEntity) -> Self {
+        let parts = matching_parts(left, right)
+            .into_iter()
+            .map(|pair| pair.part.chars().count());
+        let materials = shared_drops(left, right)
+            .into_iter()
+            .map(|drop| drop.material.chars().count());
+
+        Self {
+            label_width: parts.chain(materials).max().unwrap_or_default().max(16) + 2,
+        }
+    }
+
+    fn row<'a>(&self, label: Span<'a>, left: Span<'a>, right: Span<'a>) -> Line<'a> {
+        self.row_of_lines(label, Line::from(left), Line::from(right))
+    }
+
+    /// `left` padded to its column, `right` after it.
+    fn row_of_lines<'a>(&self, label: Span<'a>, left: Line<'a>, right: Line<'a>) -> Line<'a> {
+        let label_padding = self.label_width.saturating_sub(label.width());
+        let left_padding = SIDE_WIDTH.saturating_sub(left.width());
+
+        let mut spans = vec![label, Span::from(" ".repeat(label_padding))];
+        spans.extend(left.spans);
+        spans.push(Span::from(" ".repeat(left_padding)));
+        spans.extend(right.spans);
+        Line::from(spans)
+    }
+}
+
+fn hitzone_row<'a>(
+    theme: &Theme,
+    columns: &Columns,
+    label: Span<'a>,
+    left: [u8; 5],
+    right: [u8; 5],
+) -> Line<'a> {
+    let side = |values: [u8; 5], other: [u8; 5]| {
+        Line::from(
+            values
+                .iter()
+                .zip(other)
+                .map(|(value, other)| {
+                    Span::styled(
+                        format!("{:<width$}", value, width = HITZONE_WIDTH),
+                        better(theme, *value, other).0,
+                    )
+                })
+                .collect::<Vec<Span>>(),
+        )
+    };
+
+    columns.row_of_lines(label, side(left, right), side(right, left))
+}
+
+fn hitzones(data: &ElementDamageData) -> [u8; 5] {
+    HITZONE_ELEMENTS.map(|e| data.get(&e))
+}
+
+/// Styles of two differing values, the higher one in the positive color.
+fn better<T: PartialOrd>(theme: &Theme, left: T, right: T) -> (Style, Style) {
+    let positive = Style::default().fg(theme.ui.positive).bold();
+    let negative = Style::default().fg(theme.ui.negative);
+
+    if left > right {
+        (positive, negative)
+    } else if left < right {
+        (negative, positive)
+    } else {
+        (Style::default(), Style::default())
+    }
+}
+
+fn section(theme: &Theme, lines: &mut Vec<Line>, title: &'static str) {
+    if !lines.is_empty() {
+        lines.push(Line::from(""));
+    }
+    lines.push(Line::styled(
+        title,
+        Style::default().fg(theme.ui.highlight).bold(),
+    ));
+}
+
+fn list(items: Vec<String>) -> String {
+    match items.is_empty() {
+        true => String::from("-"),
+        false => items.join(", "),
+    }
+}
@@ -1,6 +1,6 @@
 use ratatui::{
     layout::{Constraint, Layout},
-    style::{Color, Style, Stylize},
+    style::{Style, Stylize},
     text::{Line, Span, Text},
     widgets::{
         Block, BorderType, Borders, Cell, HighlightSpacing, Row, Scrollbar, ScrollbarOrientation,
@@ -11,15 +11,19 @@ use ratatui::{
 use crate::{
     monster::monster::{MaterialDrop, MaterialDropWithPart, MonsterMaterialsDrop},
     state::monster::{MaterialSourceTab, MonsterDropRankTab, MonsterDropTabState},
+    theme::{glyph::Glyphs, Theme},
 };
 
-use super::get_lines;
+use super::{get_lines, pane_border_style, title_span_areas};
 
-const SELECTED_ROW_COLOR: Color = Color::Rgb(54, 127, 222);
+pub struct MonsterDropTable<'a>(
+    pub MonsterMaterialsDrop,
+    pub MonsterMaterialsDrop,
+    pub &'a Theme,
+    pub &'a Glyphs,
+);
 
-pub struct MonsterDropTable(pub MonsterMaterialsDrop, pub MonsterMaterialsDrop);
-
-impl StatefulWidget for MonsterDropTable {
+impl StatefulWidget for MonsterDropTable<'_> {
     type State = MonsterDropTabState;
 
     fn render(
@@ -28,11 +32,14 @@ impl StatefulWidget for MonsterDropTable {
         buf: &mut ratatui::prelude::Buffer,
         state: &mut Self::State,
     ) {
+        let theme = self.2;
+        let glyphs = self.3;
         let block_title = Block::new()
             .title(" Drops ")
             .title_style(Style::default().bold())
             .borders(Borders::ALL)
-            .border_type(BorderType::Rounded);
+            .border_type(BorderType::Rounded)
+            .border_style(pane_border_style(theme, state.focused));
 
         block_title.render(area, buf);
 
@@ -57,18 +64,18 @@ impl StatefulWidget for MonsterDropTable {
             Span::from(" [ "),
             Span::styled(
                 "Low",
-                Style::default().fg(match state.selected_rank_tab {
-                    MonsterDropRankTab::LowRank => SELECTED_ROW_COLOR,
-                    _ => Color::White,
-                }),
+                theme.accent_style(matches!(
+                    state.selected_rank_tab,
+                    MonsterDropRankTab::LowRank
+                )),
             ),
             Span::from(" | "),
             Span::styled(
                 "High",
-                Style::default().fg(match state.selected_rank_tab {
-                    MonsterDropRankTab::HighRank => SELECTED_ROW_COLOR,
-                    _ => Color::White,
-                }),
+                theme.accent_style(matches!(
+                    state.selected_rank_tab,
+                    MonsterDropRankTab::HighRank
+                )),
             ),
             Span::from(" ] "),
         ]);
@@ -77,38 +84,49 @@ impl StatefulWidget for MonsterDropTable {
             Span::from(" [ "),
             Span::styled(
                 "Target",
-                Style::default().fg(match state.selected_source_tab {
-                    MaterialSourceTab::Target => SELECTED_ROW_COLOR,
-                    _ => Color::White,
-                }),
+                theme.accent_style(matches!(
+                    state.selected_source_tab,
+                    MaterialSourceTab::Target
+                )),
             ),
             Span::from(" | "),
             Span::styled(
                 "Broken P.",
-                Style::default().fg(match state.selected_source_tab {
-                    MaterialSourceTab::BrokenPart => SELECTED_ROW_COLOR,
-                    _ => Color::White,
-                }),
+                theme.accent_style(matches!(
+                    state.selected_source_tab,
+                    MaterialSourceTab::BrokenPart
+                )),
             ),
             Span::from(" | "),
             Span::styled(
                 "Wound D.",
-                Style::default().fg(match state.selected_source_tab {
-                    MaterialSourceTab::WoundDestroy => SELECTED_ROW_COLOR,
-                    _ => Color::White,
-                }),
+                theme.accent_style(matches!(
+                    state.selected_source_tab,
+                    MaterialSourceTab::WoundDestroy
+                )),
             ),
             Span::from(" | "),
             Span::styled(
                 "Carves",
-                Style::default().fg(match state.selected_source_tab {
-                    MaterialSourceTab::Carve => SELECTED_ROW_COLOR,
-                    _ => Color::White,
-                }),
+                theme.accent_style(matches!(
+                    state.selected_source_tab,
+                    MaterialSourceTab::Carve
+                )),
             ),
             Span::from(" ] "),
         ]);
 
+        let rank_spans = title_span_areas(&title_rank, drop_wrapper_chunk);
+        state.rank_tab_areas = [rank_spans[1], rank_spans[3]];
+
+        let source_spans = title_span_areas(&title_source, drop_rank_chunk);
+        state.source_tab_areas = [
+            source_spans[1],
+            source_spans[3],
+            source_spans[5],
+            source_spans[7],
+        ];
+
         let drop_rank_blok = Block::new()
             .title(title_rank)
             .title_style(Style::default().bold())
@@ -127,7 +145,11 @@ impl StatefulWidget for MonsterDropTable {
 
         let header = ["Material", "Percentage"]
             .into_iter()
-            .map(|m| Cell::from(Text::from(Span::styled(m, Style::default().white())).centered()))
+            .map(|m| {
+                Cell::from(
+                    Text::from(Span::styled(m, Style::default().fg(theme.ui.text))).centered(),
+                )
+            })
             .collect::<Row>()
             .style(Style::default().bold())
             .height(1);
@@ -164,16 +186,15 @@ impl StatefulWidget for MonsterDropTable {
         };
 
         let columns = [Constraint::Percentage(50), Constraint::Percentage(50)];
-        let selected_row_style = Style::default().bg(Color::Rgb(16, 33, 56));
-        let bar = " █ ";
+        let selected_row_style = theme.row_style();
 
         StatefulWidget::render(
             Table::new(rows, columns)
                 .header(header)
                 .row_highlight_style(selected_row_style)
                 .highlight_symbol(Text::from(vec![
-                    bar.yellow().into(),
-                    bar.yellow().into(),
+                    theme.row_marker(glyphs).into(),
+                    theme.row_marker(glyphs).into(),
                     "".into(),
                 ]))
                 .highlight_spacing(HighlightSpacing::Always),
@@ -1,24 +1,31 @@
 use ratatui::{
     layout::{Constraint, Layout},
-    style::{Color, Style, Stylize},
+    style::{Style, Stylize},
     symbols,
     text::Span,
     widgets::{Block, BorderType, Borders, Paragraph, StatefulWidget, Widget},
 };
 
 use crate::{
-    monster::monster::MonsterHabitatData, state::monster::ChangeableHabitatPageState,
+    monster::monster::MonsterHabitatData, state::monster::ChangeableHabitatPageState, theme::Theme,
     ui::centered_rect,
 };
 
+use super::pane_border_style;
+
 pub struct HabitatsDetailInfo<'a> {
     title: &'a str,
     value: &'a str,
+    theme: &'a Theme,
 }
 
 impl<'a> HabitatsDetailInfo<'a> {
-    pub fn new(title: &'a str, value: &'a str) -> Self {
-        Self { title, value }
+    pub fn new(title: &'a str, value: &'a str, theme: &'a Theme) -> Self {
+        Self {
+            title,
+            value,
+            theme,
+        }
     }
 }
 
@@ -38,9 +45,9 @@ impl<'a> Widget for HabitatsDetailInfo<'a> {
             Style::default()
                 .bold()
                 .fg(if self.title.contains("Region") {
-                    Color::Rgb(54, 127, 222)
+                    self.theme.ui.accent
                 } else {
-                    Color::Rgb(199, 159, 0)
+                    self.theme.ui.highlight
                 }),
         ))
         .render(
@@ -64,9 +71,9 @@ impl<'a> Widget for HabitatsDetailInfo<'a> {
 //     }
 // }
 
-pub struct HabitatsMonster(pub Vec<MonsterHabitatData>);
+pub struct HabitatsMonster<'a>(pub Vec<MonsterHabitatData>, pub &'a Theme);
 
-impl StatefulWidget for HabitatsMonster {
+impl StatefulWidget for HabitatsMonster<'_> {
     type State = ChangeableHabitatPageState;
 
     fn render(
@@ -75,6 +82,7 @@ impl StatefulWidget for HabitatsMonster {
         buf: &mut ratatui::prelude::Buffer,
         state: &mut Self::State,
     ) {
+        let theme = self.1;
         let [left, right] =
             Layout::horizontal([Constraint::Percentage(48), Constraint::Percentage(52)])
                 .areas(area);
@@ -85,8 +93,11 @@ impl StatefulWidget for HabitatsMonster {
         let [top_right, bottom_right] =
             Layout::vertical([Constraint::Percentage(45), Constraint::Percentage(55)]).areas(right);
 
+        let border_style = pane_border_style(theme, state.focused);
+
         let top_left_block = Block::new()
             .borders(Borders::TOP | Borders::LEFT)
+            .border_style(border_style)
             .border_type(BorderType::Rounded)
             .title_style(Style::default().bold())
             .title(" Habitats ");
@@ -99,7 +110,8 @@ impl StatefulWidget for HabitatsMonster {
 
         let top_right_block = Block::new()
             .border_set(top_right_border_set)
-            .borders(Borders::LEFT | Borders::TOP | Borders::RIGHT);
+            .borders(Borders::LEFT | Borders::TOP | Borders::RIGHT)
+            .border_style(border_style);
 
         let bottom_left_border_set = symbols::border::Set {
             top_left: symbols::line::NORMAL.vertical_right,
@@ -109,7 +121,8 @@ impl StatefulWidget for HabitatsMonster {
 
         let bottom_left_block = Block::new()
             .border_set(bottom_left_border_set)
-            .borders(Borders::LEFT | Borders::TOP | Borders::BOTTOM);
+            .borders(Borders::LEFT | Borders::TOP | Borders::BOTTOM)
+            .border_style(border_style);
 
         let bottom_right_block_border_set = symbols::border::Set {
             bottom_left: symbols::line::NORMAL.horizontal_up,
@@ -121,7 +134,8 @@ impl StatefulWidget for HabitatsMonster {
 
         let bottom_right_block = Block::new()
             .border_set(bottom_right_block_border_set)
-            .borders(Borders::ALL);
+            .borders(Borders::ALL)
+            .border_style(border_style);
 
         top_left_block.render(top_left, buf);
         bottom_left_block.render(bottom_left, buf);
@@ -156,11 +170,12 @@ impl StatefulWidget for HabitatsMonster {
                     state.habitat_total_page,
                 ),
                 region,
+                theme,
             )
             .render(top_left, buf);
-            HabitatsDetailInfo::new("Starting Area", &starting_area).render(top_right, buf);
-            HabitatsDetailInfo::new("Visited Areas", visited_areas).render(bottom_left, buf);
-            HabitatsDetailInfo::new("Resting Areas", resting_area).render(bottom_right, buf);
+            HabitatsDetailInfo::new("Starting Area", &starting_area, theme).render(top_right, buf);
+            HabitatsDetailInfo::new("Visited Areas", visited_areas, theme).render(bottom_left, buf);
+            HabitatsDetailInfo::new("Resting Areas", resting_area, theme).render(bottom_right, buf);
         }
     }
 }
@@ -1,9 +1,14 @@
 pub mod basic_info;
+pub mod calculator;
+pub mod compare;
 pub mod drop;
 pub mod habitats;
 pub mod monster_desc;
 pub mod monster_name_icon;
 pub mod monster_quest;
+pub mod notes;
+pub mod recent;
+pub mod record_hunt;
 pub mod search;
 pub mod selection;
 pub mod weakness;
@@ -14,28 +19,64 @@ use habitats::HabitatsMonster;
 use monster_desc::DescMonster;
 use monster_name_icon::NameIcon;
 use monster_quest::QuestMonsterList;
+use notes::NotesMonster;
 use ratatui::{
     layout::{Constraint, Layout, Rect},
+    style::Style,
+    text::Line,
     widgets::{StatefulWidget, Widget},
 };
+
+use crate::{
+    data::hunt_log::MonsterRecord,
+    theme::{glyph::Glyphs, Theme},
+};
 use textwrap::WordSeparator;
 use weakness::WeaknessMonsterTable;
 
-use crate::state::monster::MonsterListState;
+use crate::state::monster::{MonsterListState, MonsterPane};
+
+type ProfileLayout = [Rect; 8];
 
-type ProfileLayout = [Rect; 7];
+/// Border of a Monster screen pane, highlighted while it has the focus.
+pub fn pane_border_style(theme: &Theme, focused: bool) -> Style {
+    if focused {
+        Style::default().fg(theme.ui.accent)
+    } else {
+        Style::default()
+    }
+}
 
-pub struct MonsterProfileWidget;
+/// Screen area of every span of a left aligned `title` on a bordered block at `area`.
+pub fn title_span_areas(title: &Line, area: Rect) -> Vec<Rect> {
+    let mut x = area.x + 1;
+    title
+        .spans
+        .iter()
+        .map(|span| {
+            let width = span.width() as u16;
+            let span_area = Rect::new(x, area.y, width, 1).intersection(area);
+            x = x.saturating_add(width);
+            span_area
+        })
+        .collect()
+}
 
-impl MonsterProfileWidget {
+/// The profile of the selected monster, with its hunt record under the name.
+pub struct MonsterProfileWidget<'a>(pub Option<MonsterRecord>, pub &'a Theme, pub &'a Glyphs);
+
+impl MonsterProfileWidget<'_> {
     fn get_render_areas(&self, area: Rect) -> ProfileLayout {
         let [left_chunk, right_chunk] =
             Layout::horizontal([Constraint::Percentage(40), Constraint::Percentage(60)])
                 .areas(area);
 
-        let [monster_name_icon_chunk, monster_desc_chunk] =
-            Layout::vertical([Constraint::Percentage(50), Constraint::Percentage(50)])
-                .areas(left_chunk);
+        let [monster_name_icon_chunk, monster_desc_chunk, notes_chunk] = Layout::vertical([
+            Constraint::Percentage(45),
+            Constraint::Min(1),
+            Constraint::Length(8),
+        ])
+        .areas(left_chunk);
 
         let [basic_info_chunk, right_chunk] =
             Layout::vertical([Constraint::Length(4), Constraint::Min(1)]).areas(right_chunk);
@@ -54,6 +95,7 @@ impl MonsterProfileWidget {
         [
             monster_name_icon_chunk,
             monster_desc_chunk,
+            notes_chunk,
             basic_info_chunk,
             habitat_chunk,
             quest_chunk,
@@ -63,7 +105,7 @@ impl MonsterProfileWidget {
     }
 }
 
-impl StatefulWidget for MonsterProfileWidget {
+impl StatefulWidget for MonsterProfileWidget<'_> {
     type State = MonsterListState;
 
     fn render(
@@ -72,45 +114,71 @@ impl StatefulWidget for MonsterProfileWidget {
         buf: &mut ratatui::prelude::Buffer,
         state: &mut Self::State,
     ) {
-        let [monster_icon_rect, monster_desc_rect, basic_info_chunk, habitat_chunk, quest_chunk, monster_material_chunk, weakness_chunk] =
+        let theme = self.1;
+        let glyphs = self.2;
+        let [monster_icon_rect, monster_desc_rect, notes_chunk, basic_info_chunk, habitat_chunk, quest_chunk, monster_material_chunk, weakness_chunk] =
             self.get_render_areas(area);
 
+        for (pane, pane_area) in [
+            (MonsterPane::Description, monster_desc_rect),
+            (MonsterPane::Notes, notes_chunk),
+            (MonsterPane::Habitats, habitat_chunk),
+            (MonsterPane::Quests, quest_chunk),
+            (MonsterPane::Drops, monster_material_chunk),
+            (MonsterPane::Weakness, weakness_chunk),
+        ] {
+            state.pane_areas[pane.index()] = pane_area;
+        }
+
         NameIcon::new(
             state.get_name_data(),
             state.get_elements_data(),
-            state.get_asset_path(),
+            state.get_icon(),
+            theme,
+            glyphs,
         )
+        .record(self.0)
         .render(monster_icon_rect, buf);
 
-        BasicInfo::new(state.get_basic_info_data()).render(basic_info_chunk, buf);
+        BasicInfo::new(state.get_basic_info_data(), theme).render(basic_info_chunk, buf);
 
-        HabitatsMonster(state.get_habitat_data()).render(
+        HabitatsMonster(state.get_habitat_data(), theme).render(
             habitat_chunk,
             buf,
             &mut state.habitat_page_state,
         );
 
-        QuestMonsterList(state.get_quest_list()).render(
+        QuestMonsterList(state.get_quest_list(), theme, glyphs).render(
             quest_chunk,
             buf,
             &mut state.monster_on_quest_list_state,
         );
 
-        DescMonster(state.get_desc_data()).render(
+        DescMonster(state.get_desc_data(), theme).render(
             monster_desc_rect,
             buf,
             &mut state.desc_scrollbar_state,
         );
 
-        WeaknessMonsterTable(state.get_damage_data(), state.get_status_ailment_data()).render(
-            weakness_chunk,
+        NotesMonster(state.current_notes().to_string(), theme).render(
+            notes_chunk,
             buf,
-            &mut state.monster_weakness_tab_state,
+            &mut state.notes_state,
         );
 
+        WeaknessMonsterTable(
+            state.get_damage_data(),
+            state.get_status_ailment_data(),
+            theme,
+            glyphs,
+        )
+        .render(weakness_chunk, buf, &mut state.monster_weakness_tab_state);
+
         MonsterDropTable(
             state.get_low_rank_drop_data(),
             state.get_high_rank_drop_data(),
+            theme,
+            glyphs,
         )
         .render(
             monster_material_chunk,
@@ -1,17 +1,18 @@
+use crate::theme::Theme;
 use crate::{monster::MonsterDescText, state::monster::ScrollableParagraphState};
 use ratatui::{
-    style::{Color, Style, Stylize},
+    style::{Style, Stylize},
     text::{Line, Span},
     widgets::{
         Block, BorderType, Paragraph, Scrollbar, ScrollbarOrientation, StatefulWidget, Widget,
     },
 };
 
-use super::get_lines;
+use super::{get_lines, pane_border_style};
 
-pub struct DescMonster(pub MonsterDescText);
+pub struct DescMonster<'a>(pub MonsterDescText, pub &'a Theme);
 
-impl StatefulWidget for DescMonster {
+impl StatefulWidget for DescMonster<'_> {
     type State = ScrollableParagraphState;
 
     fn render(
@@ -20,6 +21,7 @@ impl StatefulWidget for DescMonster {
         buf: &mut ratatui::prelude::Buffer,
         state: &mut Self::State,
     ) {
+        let theme = self.1;
         let mut monster_abi_desc = vec![];
         let mut count = 0;
 
@@ -27,7 +29,7 @@ impl StatefulWidget for DescMonster {
         ori_text.into_iter().for_each(|d| {
             monster_abi_desc.push(Line::from(Span::styled(
                 d,
-                Style::default().italic().white(),
+                Style::default().italic().fg(theme.ui.text),
             )))
         });
 
@@ -55,13 +57,14 @@ impl StatefulWidget for DescMonster {
                 Block::bordered()
                     .title(" Ability ")
                     .title_style(Style::default().bold())
-                    .border_type(BorderType::Rounded),
+                    .border_type(BorderType::Rounded)
+                    .border_style(pane_border_style(theme, state.focused)),
             )
             .scroll((state.position as u16, 0))
             .render(area, buf);
 
         Scrollbar::new(ScrollbarOrientation::VerticalRight)
-            .style(Style::default().bg(Color::DarkGray))
+            .style(Style::default().bg(theme.ui.surface))
             .render(area, buf, &mut state.scrollbar_state);
     }
 }
@@ -1,80 +1,163 @@
-use std::path::PathBuf;
+use std::sync::Arc;
 
-use ansi_to_tui::IntoText;
 use ratatui::{
     layout::{Constraint, Layout},
-    style::{Color, Style, Stylize},
-    text::{Line, Span},
-    widgets::{Paragraph, Widget},
+    style::{Style, Stylize},
+    text::{Line, Span, Text},
+    widgets::{Block, BorderType, Borders, Paragraph, Widget},
 };
 
 use crate::{
-    monster::monster::{MonsterElements, MonsterName},
+    data::hunt_log::{format_time, MonsterRecord},
+    monster::monster::{DropRank, MonsterElements, MonsterName},
+    theme::{glyph::Glyphs, Theme},
     ui::centered_rect,
 };
 
-pub struct NameIcon {
+pub struct NameIcon<'a> {
     pub name: MonsterName,
     pub monster_el: Vec<MonsterElements>,
-    pub asset_path: PathBuf,
+    pub icon: Option<Arc<Text<'static>>>,
+    /// Hunt record shown under the name.
+    pub record: Option<MonsterRecord>,
+    pub theme: &'a Theme,
+    pub glyphs: &'a Glyphs,
 }
 
-impl NameIcon {
-    pub fn new(name: MonsterName, monster_el: Vec<MonsterElements>, asset_path: PathBuf) -> Self {
+impl<'a> NameIcon<'a> {
+    pub fn new(
+        name: MonsterName,
+        monster_el: Vec<MonsterElements>,
+        icon: Option<Arc<Text<'static>>>,
+        theme: &'a Theme,
+        glyphs: &'a Glyphs,
+    ) -> Self {
         Self {
             name,
             monster_el,
-            asset_path,
+            icon,
+            record: None,
+            theme,
+            glyphs,
         }
     }
+
+    pub fn record(mut self, record: Option<MonsterRecord>) -> Self {
+        self.record = record;
+        self
+    }
 }
 
-impl Widget for NameIcon {
+impl Widget for NameIcon<'_> {
     fn render(self, area: ratatui::prelude::Rect, buf: &mut ratatui::prelude::Buffer)
     where
         Self: Sized,
     {
-        let [monster_name_rect, monster_icon_rect] =
-            Layout::vertical([Constraint::Length(1), Constraint::Min(1)]).areas(area);
+        let theme = self.theme;
+        let record_lines = self
+            .record
+            .as_ref()
+            .map(|record| record_lines(theme, record))
+            .unwrap_or_default();
+        let [monster_name_rect, record_rect, monster_icon_rect] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Length(record_lines.len() as u16),
+            Constraint::Min(1),
+        ])
+        .areas(area);
 
         let mut monster_info_title = vec![
             Span::styled(self.name.name, Style::default().bold().italic()),
-            Span::styled(" | ", Style::default().fg(Color::White)),
+            Span::styled(" | ", Style::default().fg(theme.ui.text)),
             Span::styled(self.name.aka, Style::default().bold().italic()),
         ];
 
         if !self.monster_el.is_empty() {
-            match self.monster_el[0] {
+            match &self.monster_el[0] {
                 MonsterElements::None => {}
-                _ => {
+                el => {
+                    let label = match el.icon(self.glyphs) {
+                        icon if icon.is_empty() => el.to_string(),
+                        icon => format!("{} {}", icon, el),
+                    };
                     monster_info_title.extend([
                         Span::from(" "),
                         Span::styled(
-                            format!(" {} {} ", self.monster_el[0].icon(), self.monster_el[0]),
+                            format!(" {} ", label),
                             Style::default()
-                                .bg(self.monster_el[0].color())
-                                .fg(Color::White),
+                                .bg(self.monster_el[0].color(theme))
+                                .fg(theme.ui.text),
                         ),
                     ]);
                 }
             }
         }
 
-        if let Ok(buffer) = std::fs::read(self.asset_path) {
-            let ansi_text = buffer.into_text();
+        Paragraph::new(Line::from(monster_info_title)).render(monster_name_rect, buf);
 
-            Paragraph::new(Line::from(monster_info_title)).render(monster_name_rect, buf);
+        Paragraph::new(record_lines).render(record_rect, buf);
 
-            if let Ok(ansi_text) = ansi_text {
-                Paragraph::new(ansi_text.clone()).render(
-                    centered_rect(
-                        monster_icon_rect,
-                        Constraint::Length(ansi_text.width() as u16),
-                        Constraint::Length(ansi_text.height() as u16),
-                    ),
-                    buf,
-                );
-            }
+        match self.icon {
+            Some(icon) => Paragraph::new(Text::clone(&icon)).render(
+                centered_rect(
+                    monster_icon_rect,
+                    Constraint::Length(icon.width() as u16),
+                    Constraint::Length(icon.height() as u16),
+                ),
+                buf,
+            ),
+            None => Paragraph::new(vec![
+                Line::from(""),
+                Line::from(Span::styled("?", Style::default().bold())),
+                Line::from(Span::styled("No icon", Style::default().italic())),
+            ])
+            .centered()
+            .block(
+                Block::new()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::default().fg(theme.ui.dim)),
+            )
+            .render(
+                centered_rect(
+                    monster_icon_rect,
+                    Constraint::Length(20),
+                    Constraint::Length(6),
+                ),
+                buf,
+            ),
         }
     }
 }
+
+/// Slays, captures and personal best of each hunted rank, a line per rank.
+fn record_lines(theme: &Theme, record: &MonsterRecord) -> Vec<Line<'static>> {
+    if record.hunts() == 0 {
+        return vec![Line::styled(
+            "No hunts logged",
+            Style::default().fg(theme.ui.dim).italic(),
+        )];
+    }
+
+    [DropRank::Low, DropRank::High]
+        .into_iter()
+        .filter(|rank| record.rank(*rank).hunts() > 0)
+        .map(|rank| {
+            let rank_record = record.rank(rank);
+            let mut spans = vec![
+                Span::styled(format!("{rank} "), Style::default().bold()),
+                Span::from(format!(
+                    "{} slain, {} captured",
+                    rank_record.slays, rank_record.captures
+                )),
+            ];
+            if let Some(best) = rank_record.best_time {
+                spans.extend([
+                    Span::from(", best "),
+                    Span::styled(format_time(best), Style::default().fg(theme.ui.positive)),
+                ]);
+            }
+            Line::from(spans)
+        })
+        .collect()
+}
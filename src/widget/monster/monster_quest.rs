@@ -1,6 +1,6 @@
 use ratatui::{
     layout::{Constraint, Layout},
-    style::{Color, Modifier, Style, Stylize},
+    style::{Style, Stylize},
     text::{Line, Text},
     widgets::{
         Block, BorderType, Borders, HighlightSpacing, List, ListItem, Scrollbar,
@@ -8,15 +8,14 @@ use ratatui::{
     },
 };
 
+use crate::theme::{glyph::Glyphs, Theme};
 use crate::{monster::monster::MonsterQuestData, state::monster::MonsterOnQuestListState};
 
-const SELECTED_STYLE: Style = Style::new()
-    .bg(Color::Rgb(29, 46, 69))
-    .add_modifier(Modifier::BOLD);
+use super::pane_border_style;
 
-pub struct QuestMonsterList(pub Vec<MonsterQuestData>);
+pub struct QuestMonsterList<'a>(pub Vec<MonsterQuestData>, pub &'a Theme, pub &'a Glyphs);
 
-impl StatefulWidget for QuestMonsterList {
+impl StatefulWidget for QuestMonsterList<'_> {
     type State = MonsterOnQuestListState;
 
     fn render(
@@ -25,6 +24,8 @@ impl StatefulWidget for QuestMonsterList {
         buf: &mut ratatui::prelude::Buffer,
         state: &mut Self::State,
     ) {
+        let theme = self.1;
+        let glyphs = self.2;
         state.set_scrollbar_height(area);
 
         let [layout] = Layout::default()
@@ -37,7 +38,7 @@ impl StatefulWidget for QuestMonsterList {
             .enumerate()
             .map(|(i, item)| {
                 let quest_type_text = format!("[ {} ]", item.quest_type);
-                let quest_level_text = format!("[ {} ]", item.level);
+                let quest_level_text = format!("[ {}{} ]", item.level, glyphs.star);
                 let quest_name_text = item.name.to_string();
 
                 let line = Line::from(vec![
@@ -46,12 +47,12 @@ impl StatefulWidget for QuestMonsterList {
                     " ".into(),
                     quest_name_text.fg(if let Some(i_selected) = state.list_state.selected() {
                         if i == i_selected {
-                            Color::Rgb(54, 127, 222)
+                            theme.ui.accent
                         } else {
-                            Color::White
+                            theme.ui.text
                         }
                     } else {
-                        Color::White
+                        theme.ui.text
                     }),
                 ]);
                 let text = Text::from(line);
@@ -66,9 +67,10 @@ impl StatefulWidget for QuestMonsterList {
                     .title(" Quest Appearances ")
                     .title_style(Style::default().bold())
                     .borders(Borders::ALL)
-                    .border_type(BorderType::Rounded),
+                    .border_type(BorderType::Rounded)
+                    .border_style(pane_border_style(theme, state.focused)),
             )
-            .highlight_style(SELECTED_STYLE)
+            .highlight_style(theme.selected_style())
             .highlight_symbol("> ")
             .highlight_spacing(HighlightSpacing::Always)
             .render(layout, buf, &mut state.list_state);
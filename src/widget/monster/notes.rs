@@ -0,0 +1,126 @@
+use ratatui::{
+    layout::Rect,
+    style::{Style, Stylize},
+    text::Line,
+    widgets::{
+        Block, BorderType, Clear, Paragraph, Scrollbar, ScrollbarOrientation, StatefulWidget,
+        Widget,
+    },
+};
+
+use crate::{state::monster::MonsterNotesState, theme::Theme};
+
+use super::{get_lines, pane_border_style};
+
+/// Personal notes on the monster, or the editor while they are edited.
+pub struct NotesMonster<'a>(pub String, pub &'a Theme);
+
+impl StatefulWidget for NotesMonster<'_> {
+    type State = MonsterNotesState;
+
+    fn render(
+        self,
+        area: ratatui::prelude::Rect,
+        buf: &mut ratatui::prelude::Buffer,
+        state: &mut Self::State,
+    ) {
+        let theme = self.1;
+        let mut block = Block::bordered()
+            .title(match state.editing {
+                true => " Notes (Esc to save) ",
+                false => " Notes ",
+            })
+            .title_style(Style::default().bold())
+            .border_type(BorderType::Rounded)
+            .border_style(pane_border_style(theme, state.focused));
+        if let Some(error) = &state.error {
+            block = block
+                .border_style(Style::default().fg(theme.ui.negative))
+                .title_bottom(Line::from(format!(" {error} ")).fg(theme.ui.negative));
+        }
+
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        if state.editing {
+            render_editor(theme, inner, buf, state);
+            return;
+        }
+
+        state.cursor = None;
+        if self.0.is_empty() {
+            Line::styled("No notes", Style::default().fg(theme.ui.dim).italic()).render(inner, buf);
+            state.scroll.set_height(0);
+            return;
+        }
+
+        let lines: Vec<Line> = self
+            .0
+            .lines()
+            .flat_map(|line| match line.is_empty() {
+                true => vec![String::new()],
+                false => get_lines(line, (inner.width as usize).saturating_sub(1).max(1)),
+            })
+            .map(Line::from)
+            .collect();
+
+        state
+            .scroll
+            .set_height(if lines.len() > inner.height as usize {
+                lines.len()
+            } else {
+                0
+            });
+
+        Paragraph::new(lines)
+            .scroll((state.scroll.position as u16, 0))
+            .render(inner, buf);
+
+        Scrollbar::new(ScrollbarOrientation::VerticalRight)
+            .style(Style::default().bg(theme.ui.surface))
+            .render(area, buf, &mut state.scroll.scrollbar_state);
+    }
+}
+
+/// Unwrapped lines scrolled to keep the cursor line in view, that line scrolled to the cursor.
+fn render_editor(
+    theme: &Theme,
+    area: Rect,
+    buf: &mut ratatui::prelude::Buffer,
+    state: &mut MonsterNotesState,
+) {
+    if area.is_empty() {
+        return;
+    }
+
+    let top = state.row.saturating_sub(area.height as usize - 1);
+    Paragraph::new(
+        state
+            .lines
+            .iter()
+            .map(|line| Line::from(line.as_str()))
+            .collect::<Vec<Line>>(),
+    )
+    .style(Style::default().fg(theme.ui.input))
+    .scroll((top as u16, 0))
+    .render(area, buf);
+
+    let row_area = Rect {
+        y: area.y + (state.row - top) as u16,
+        height: 1,
+        ..area
+    };
+    let scroll = state
+        .input
+        .visual_scroll((area.width as usize).saturating_sub(1));
+    Clear.render(row_area, buf);
+    Paragraph::new(state.input.value())
+        .style(Style::default().fg(theme.ui.input))
+        .scroll((0, scroll as u16))
+        .render(row_area, buf);
+
+    state.cursor = Some((
+        row_area.x + (state.input.visual_cursor().max(scroll) - scroll) as u16,
+        row_area.y,
+    ));
+}
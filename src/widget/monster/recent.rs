@@ -0,0 +1,60 @@
+use std::rc::Rc;
+
+use ratatui::{
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::{
+        Block, BorderType, Borders, Clear, HighlightSpacing, List, ListItem, StatefulWidget, Widget,
+    },
+};
+
+use crate::{monster::monster::MonsterEntity, state::recent::RecentState, theme::Theme};
+
+/// Recently viewed monsters, the most recent first.
+pub struct RecentMonsters<'a>(pub Vec<Rc<MonsterEntity>>, pub &'a Theme);
+
+impl StatefulWidget for RecentMonsters<'_> {
+    type State = RecentState;
+
+    fn render(
+        self,
+        area: ratatui::prelude::Rect,
+        buf: &mut ratatui::prelude::Buffer,
+        state: &mut Self::State,
+    ) {
+        let theme = self.1;
+        Clear.render(area, buf);
+
+        let block = Block::new()
+            .title(" Recent ")
+            .title_style(Style::default().bold())
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.ui.accent));
+
+        let items: Vec<ListItem> = self
+            .0
+            .iter()
+            .map(|monster| {
+                ListItem::new(Line::from(vec![
+                    Span::from(monster.name.name.clone()),
+                    Span::styled(
+                        format!("  {}", monster.name.aka),
+                        Style::default().fg(theme.ui.muted),
+                    ),
+                ]))
+            })
+            .collect();
+
+        StatefulWidget::render(
+            List::new(items)
+                .block(block)
+                .highlight_style(theme.selected_style())
+                .highlight_symbol("> ")
+                .highlight_spacing(HighlightSpacing::Always),
+            area,
+            buf,
+            &mut state.list_state,
+        );
+    }
+}
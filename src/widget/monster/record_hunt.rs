@@ -0,0 +1,99 @@
+use ratatui::{
+    layout::Constraint,
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph, Widget},
+};
+
+use crate::{
+    data::hunt_log::{format_time, MonsterRecord},
+    state::hunt_log::{RecordField, RecordHuntState},
+    theme::Theme,
+    ui::centered_rect,
+};
+
+/// Result, rank and time of a finished hunt, before it goes into the log.
+pub struct RecordHunt<'a> {
+    name: String,
+    state: &'a RecordHuntState,
+    record: MonsterRecord,
+    theme: &'a Theme,
+}
+
+impl<'a> RecordHunt<'a> {
+    pub fn new(
+        name: String,
+        state: &'a RecordHuntState,
+        record: MonsterRecord,
+        theme: &'a Theme,
+    ) -> Self {
+        Self {
+            name,
+            state,
+            record,
+            theme,
+        }
+    }
+}
+
+impl Widget for RecordHunt<'_> {
+    fn render(self, area: ratatui::prelude::Rect, buf: &mut ratatui::prelude::Buffer) {
+        Clear.render(area, buf);
+
+        let block = Block::new()
+            .title(" Record Hunt ")
+            .title_bottom(Line::from(" Enter to log, Esc to cancel ").right_aligned())
+            .title_style(Style::default().bold())
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(self.theme.ui.accent));
+
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        let state = self.state;
+        let label = |text: &str| Span::styled(format!("{:<10}", text), Style::default().bold());
+        let field_line = |field: RecordField, text: &str, value: String| {
+            let focused = state.field == field;
+            Line::from(vec![
+                label(text).fg(if focused {
+                    self.theme.ui.accent
+                } else {
+                    self.theme.ui.text
+                }),
+                Span::from(if focused { "< " } else { "  " }),
+                Span::from(value),
+                Span::from(if focused { " >" } else { "" }),
+            ])
+        };
+
+        let time = match state.time {
+            Some(time) => Span::styled(
+                format_time(time),
+                Style::default().fg(self.theme.ui.positive),
+            ),
+            None => Span::styled("not timed", Style::default().fg(self.theme.ui.dim).italic()),
+        };
+        let best = match self.record.rank(state.rank).best_time {
+            Some(best) => Span::from(format_time(best)),
+            None => Span::styled("-", Style::default().fg(self.theme.ui.dim)),
+        };
+
+        Paragraph::new(vec![
+            Line::from(vec![
+                label("Monster"),
+                Span::from(format!("  {}", self.name)),
+            ]),
+            Line::from(""),
+            field_line(RecordField::Outcome, "Result", state.outcome.to_string()),
+            field_line(RecordField::Rank, "Rank", state.rank.to_string()),
+            Line::from(""),
+            Line::from(vec![label("Time"), Span::from("  "), time]),
+            Line::from(vec![label("Best"), Span::from("  "), best]),
+        ])
+        .render(
+            centered_rect(inner, Constraint::Length(36), Constraint::Length(7)),
+            buf,
+        );
+    }
+}
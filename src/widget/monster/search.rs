@@ -1,19 +1,27 @@
 use ratatui::{
     layout::{Alignment, Constraint, Layout},
-    style::{Color, Style},
+    style::{Style, Stylize},
+    text::Line,
     widgets::{Block, Borders, Paragraph, StatefulWidget, Widget},
 };
 
-use crate::state::{AppState, InputMode};
+use crate::state::{AppState, CurrentScreen, InputMode};
+use crate::theme::Theme;
 
 pub struct Search;
 
 impl Search {
-    fn paragraph(self, scroll: usize, value: &str) -> Paragraph {
+    fn paragraph<'a>(
+        self,
+        theme: &Theme,
+        scroll: usize,
+        value: &'a str,
+        error: Option<String>,
+    ) -> Paragraph<'a> {
         Paragraph::new(value)
-            .style(Style::default().fg(Color::Green))
+            .style(Style::default().fg(theme.ui.input))
             .scroll((0, scroll as u16))
-            .block(Block::bordered())
+            .block(with_error(theme, Block::bordered(), error))
     }
 }
 
@@ -26,6 +34,7 @@ impl StatefulWidget for Search {
         buf: &mut ratatui::prelude::Buffer,
         state: &mut Self::State,
     ) {
+        let theme = &state.theme;
         Block::default().borders(Borders::LEFT).render(area, buf);
 
         let [wrapper] = Layout::default()
@@ -34,7 +43,7 @@ impl StatefulWidget for Search {
             .areas(area);
 
         match state.tui_state.input_mode {
-            InputMode::Normal => {
+            InputMode::Normal | InputMode::Notes => {
                 state.tui_state.cursor = None;
                 let block = Block::default()
                     .borders(Borders::ALL)
@@ -42,14 +51,19 @@ impl StatefulWidget for Search {
                     .title(" Press '/' search ");
 
                 Paragraph::new(state.key_handle.input.value())
-                    .block(block)
+                    .block(with_error(theme, block, search_error(state)))
                     .render(wrapper, buf);
             }
             InputMode::Editing => {
                 let width = wrapper.width.max(3) - 3;
                 let scroll = state.key_handle.input.visual_scroll(width as usize);
-                self.paragraph(scroll, state.key_handle.input.value())
-                    .render(wrapper, buf);
+                self.paragraph(
+                    theme,
+                    scroll,
+                    state.key_handle.input.value(),
+                    search_error(state),
+                )
+                .render(wrapper, buf);
                 state.tui_state.cursor = Some((
                     wrapper.x
                         + ((state.key_handle.input.visual_cursor()).max(scroll) - scroll) as u16
@@ -60,3 +74,24 @@ impl StatefulWidget for Search {
         }
     }
 }
+
+/// Only the monster search understands queries.
+fn search_error(state: &AppState) -> Option<String> {
+    match state.current_screen {
+        CurrentScreen::Monster => state
+            .monster_list
+            .filter_error
+            .as_ref()
+            .map(|e| e.to_string()),
+        _ => None,
+    }
+}
+
+fn with_error<'a>(theme: &Theme, block: Block<'a>, error: Option<String>) -> Block<'a> {
+    match error {
+        Some(error) => block
+            .border_style(Style::default().fg(theme.ui.negative))
+            .title_bottom(Line::from(format!(" {error} ")).fg(theme.ui.negative)),
+        None => block,
+    }
+}
@@ -1,19 +1,18 @@
 use ratatui::{
     layout::{Constraint, Layout},
-    style::{Color, Modifier, Style},
-    text::Text,
+    style::{Style, Stylize},
+    text::{Line, Span},
     widgets::{Block, Borders, HighlightSpacing, List, ListItem, StatefulWidget, Widget},
 };
 
-use crate::state::monster::MonsterListState;
+use crate::state::monster::{MonsterListState, MonsterPane};
+use crate::theme::{glyph::Glyphs, Theme};
 
-const SELECTED_STYLE: Style = Style::new()
-    .bg(Color::Rgb(29, 46, 69))
-    .add_modifier(Modifier::BOLD);
+use super::pane_border_style;
 
-pub struct MonsterSelection;
+pub struct MonsterSelection<'a>(pub &'a Theme, pub &'a Glyphs);
 
-impl StatefulWidget for MonsterSelection {
+impl StatefulWidget for MonsterSelection<'_> {
     type State = MonsterListState;
 
     fn render(
@@ -22,25 +21,59 @@ impl StatefulWidget for MonsterSelection {
         buf: &mut ratatui::prelude::Buffer,
         state: &mut Self::State,
     ) {
-        Block::default().borders(Borders::LEFT).render(area, buf);
+        let theme = self.0;
+        let glyphs = self.1;
+        Block::default()
+            .borders(Borders::LEFT)
+            .border_style(pane_border_style(
+                theme,
+                state.focused_pane == MonsterPane::Monsters,
+            ))
+            .render(area, buf);
 
         let [wrapper] = Layout::default()
             .horizontal_margin(1)
             .constraints([Constraint::Min(0)])
             .areas(area);
 
+        let wrapper = if state.favorites_only {
+            let [filter, list] =
+                Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).areas(wrapper);
+            Line::styled(
+                format!("{} Favorites", glyphs.star),
+                Style::default().fg(theme.ui.highlight).bold(),
+            )
+            .render(filter, buf);
+            list
+        } else {
+            wrapper
+        };
+
+        state.pane_areas[MonsterPane::Monsters.index()] = wrapper;
+
         let items: Vec<ListItem> = state
             .list_items()
             .iter()
             .map(|item| {
-                let text = Text::from(item.name.name.to_string());
-                ListItem::new(text)
+                let highlights = state
+                    .name_highlights
+                    .get(&item.id)
+                    .map(Vec::as_slice)
+                    .unwrap_or_default();
+                let mut name = highlight_name(theme, &item.name.name, highlights);
+                if state.is_favorite(item.id) {
+                    name.push_span(Span::styled(
+                        format!(" {}", glyphs.star),
+                        Style::default().fg(theme.ui.highlight),
+                    ));
+                }
+                ListItem::new(name)
             })
             .collect();
 
         StatefulWidget::render(
             List::new(items)
-                .highlight_style(SELECTED_STYLE)
+                .highlight_style(theme.selected_style())
                 .highlight_symbol("> ")
                 .highlight_spacing(HighlightSpacing::Always),
             wrapper,
@@ -49,3 +82,23 @@ impl StatefulWidget for MonsterSelection {
         );
     }
 }
+
+fn highlight_name(theme: &Theme, name: &str, highlights: &[usize]) -> Line<'static> {
+    if highlights.is_empty() {
+        return Line::from(name.to_string());
+    }
+
+    let spans: Vec<Span> = name
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            if highlights.binary_search(&i).is_ok() {
+                Span::styled(c.to_string(), theme.match_style())
+            } else {
+                Span::raw(c.to_string())
+            }
+        })
+        .collect();
+
+    Line::from(spans)
+}
@@ -1,6 +1,6 @@
 use ratatui::{
     layout::{Constraint, Layout},
-    style::{Color, Style, Stylize},
+    style::{Style, Stylize},
     text::{Line, Span, Text},
     widgets::{
         Block, BorderType, Borders, Cell, HighlightSpacing, Paragraph, Row, Scrollbar,
@@ -13,22 +13,29 @@ use crate::{
     state::monster::{
         MonsterStatusAndItemWeaknessTab, MonsterWeaknessTab, MonsterWeaknessTabState,
     },
+    theme::{glyph::Glyphs, Theme},
 };
 
-use super::get_lines;
+use super::{get_lines, pane_border_style, title_span_areas};
 
 pub struct WeaknessAilmentItems<'a> {
     weakness: &'a StatusAilmentItem,
+    theme: &'a Theme,
+    glyphs: &'a Glyphs,
 }
 
 impl<'a> WeaknessAilmentItems<'a> {
-    pub fn new(weakness: &'a StatusAilmentItem) -> Self {
-        Self { weakness }
+    pub fn new(weakness: &'a StatusAilmentItem, theme: &'a Theme, glyphs: &'a Glyphs) -> Self {
+        Self {
+            weakness,
+            theme,
+            glyphs,
+        }
     }
 }
 
 impl<'a> StatefulWidget for WeaknessAilmentItems<'a> {
-    type State = MonsterStatusAndItemWeaknessTab;
+    type State = MonsterWeaknessTabState;
 
     fn render(
         self,
@@ -36,6 +43,8 @@ impl<'a> StatefulWidget for WeaknessAilmentItems<'a> {
         buf: &mut ratatui::prelude::Buffer,
         state: &mut Self::State,
     ) {
+        let theme = self.theme;
+        let glyphs = self.glyphs;
         let [_, ailment_item_wrapper_chunk] =
             Layout::vertical([Constraint::Length(1), Constraint::Min(1)])
                 .horizontal_margin(1)
@@ -50,22 +59,25 @@ impl<'a> StatefulWidget for WeaknessAilmentItems<'a> {
             Span::from(" [ "),
             Span::styled(
                 "Ailment",
-                Style::default().fg(match state {
-                    MonsterStatusAndItemWeaknessTab::Status => Color::Rgb(54, 127, 222),
-                    _ => Color::White,
-                }),
+                theme.accent_style(matches!(
+                    state.selected_status_item_tab,
+                    MonsterStatusAndItemWeaknessTab::Status
+                )),
             ),
             Span::from(" | "),
             Span::styled(
                 "Item",
-                Style::default().fg(match state {
-                    MonsterStatusAndItemWeaknessTab::Item => Color::Rgb(54, 127, 222),
-                    _ => Color::White,
-                }),
+                theme.accent_style(matches!(
+                    state.selected_status_item_tab,
+                    MonsterStatusAndItemWeaknessTab::Item
+                )),
             ),
             Span::from(" ] "),
         ]);
 
+        let ailment_item_spans = title_span_areas(&title_ailment_item, ailment_item_wrapper_chunk);
+        state.ailment_item_tab_areas = [ailment_item_spans[1], ailment_item_spans[3]];
+
         let weakness_block = Block::new()
             .title(title_ailment_item)
             .title_style(Style::default().bold())
@@ -84,7 +96,7 @@ impl<'a> StatefulWidget for WeaknessAilmentItems<'a> {
         ])
         .areas(ailment_item_chunk);
 
-        let mapped_paragraph: Vec<Paragraph<'_>> = match state {
+        let mapped_paragraph: Vec<Paragraph<'_>> = match state.selected_status_item_tab {
             MonsterStatusAndItemWeaknessTab::Status => self
                 .weakness
                 .status
@@ -92,11 +104,14 @@ impl<'a> StatefulWidget for WeaknessAilmentItems<'a> {
                 .map(|h| {
                     let text_header = vec![
                         Line::from(vec![
-                            Span::styled(h.ailment.icon(), Style::default().fg(h.ailment.color())),
+                            Span::styled(
+                                h.ailment.icon(glyphs),
+                                Style::default().fg(h.ailment.color(theme)),
+                            ),
                             Span::styled(format!(" {}", &h.ailment), Style::default().bold()),
                         ]),
                         Line::from(vec![
-                            Span::styled("", Style::default().fg(Color::Yellow)),
+                            Span::styled(glyphs.star, Style::default().fg(theme.ui.marker)),
                             Span::from(format!(" {}", &h.eff)),
                         ]),
                     ];
@@ -115,9 +130,9 @@ impl<'a> StatefulWidget for WeaknessAilmentItems<'a> {
                             Style::default().bold(),
                         )]),
                         if h.is_effective {
-                            "".green().bold().into()
+                            glyphs.check.fg(theme.ui.positive).bold().into()
                         } else {
-                            "".red().bold().into()
+                            glyphs.cross.fg(theme.ui.negative).bold().into()
                         },
                     ];
 
@@ -144,9 +159,14 @@ impl<'a> StatefulWidget for WeaknessAilmentItems<'a> {
     }
 }
 
-pub struct WeaknessMonsterTable(pub Vec<DamageData>, pub StatusAilmentItem);
+pub struct WeaknessMonsterTable<'a>(
+    pub Vec<DamageData>,
+    pub StatusAilmentItem,
+    pub &'a Theme,
+    pub &'a Glyphs,
+);
 
-impl StatefulWidget for WeaknessMonsterTable {
+impl StatefulWidget for WeaknessMonsterTable<'_> {
     type State = MonsterWeaknessTabState;
 
     fn render(
@@ -155,6 +175,8 @@ impl StatefulWidget for WeaknessMonsterTable {
         buf: &mut ratatui::prelude::Buffer,
         state: &mut Self::State,
     ) {
+        let theme = self.2;
+        let glyphs = self.3;
         let [ailment_item_chunk, table_chunk] =
             Layout::vertical([Constraint::Length(5), Constraint::Min(1)]).areas(area);
 
@@ -174,18 +196,18 @@ impl StatefulWidget for WeaknessMonsterTable {
             Span::from(" [ "),
             Span::styled(
                 "Weapon",
-                Style::default().fg(match state.selected_weapon_elm_tab {
-                    MonsterWeaknessTab::WeaponDamage => Color::Rgb(54, 127, 222),
-                    _ => Color::White,
-                }),
+                theme.accent_style(matches!(
+                    state.selected_weapon_elm_tab,
+                    MonsterWeaknessTab::WeaponDamage
+                )),
             ),
             Span::from(" | "),
             Span::styled(
                 "Element",
-                Style::default().fg(match state.selected_weapon_elm_tab {
-                    MonsterWeaknessTab::ElementDamage => Color::Rgb(54, 127, 222),
-                    _ => Color::White,
-                }),
+                theme.accent_style(matches!(
+                    state.selected_weapon_elm_tab,
+                    MonsterWeaknessTab::ElementDamage
+                )),
             ),
             Span::from(" ] "),
         ]);
@@ -194,10 +216,14 @@ impl StatefulWidget for WeaknessMonsterTable {
             .title(" Weakness ")
             .title_style(Style::default().bold())
             .borders(Borders::ALL)
-            .border_type(BorderType::Rounded);
+            .border_type(BorderType::Rounded)
+            .border_style(pane_border_style(theme, state.focused));
 
         block_title.render(area, buf);
 
+        let weapon_elm_spans = title_span_areas(&title_weapon_element, tab_table_wrapper_chunk);
+        state.weapon_elm_tab_areas = [weapon_elm_spans[1], weapon_elm_spans[3]];
+
         let weakness_block = Block::new()
             .title(title_weapon_element)
             .title_style(Style::default().bold())
@@ -206,22 +232,30 @@ impl StatefulWidget for WeaknessMonsterTable {
 
         weakness_block.render(tab_table_wrapper_chunk, buf);
 
-        let fire_icon = MonsterElements::Fire.icon();
-        let water_icon = MonsterElements::Water.icon();
-        let thunder_icon = MonsterElements::Thunder.icon();
-        let ice_icon = MonsterElements::Ice.icon();
-        let dragon_icon = MonsterElements::Dragon.icon();
-
         let selected_header = match state.selected_weapon_elm_tab {
-            MonsterWeaknessTab::WeaponDamage => vec!["Part", "Cut(󰓥)", "Blunt(󰣪)", "Ammo(󰳳)"],
-            MonsterWeaknessTab::ElementDamage => vec![
-                "Part",
-                &fire_icon,
-                &water_icon,
-                &thunder_icon,
-                &ice_icon,
-                &dragon_icon,
+            MonsterWeaknessTab::WeaponDamage => vec![
+                "Part".to_string(),
+                Glyphs::labeled("Cut", glyphs.cut),
+                Glyphs::labeled("Blunt", glyphs.blunt),
+                Glyphs::labeled("Ammo", glyphs.ammo),
             ],
+            MonsterWeaknessTab::ElementDamage => std::iter::once("Part".to_string())
+                .chain(
+                    [
+                        MonsterElements::Fire,
+                        MonsterElements::Water,
+                        MonsterElements::Thunder,
+                        MonsterElements::Ice,
+                        MonsterElements::Dragon,
+                    ]
+                    .into_iter()
+                    // the name stands in for a missing icon
+                    .map(|el| match el.icon(glyphs) {
+                        icon if icon.is_empty() => el.to_string(),
+                        icon => icon,
+                    }),
+                )
+                .collect(),
         };
 
         let header = selected_header
@@ -231,12 +265,20 @@ impl StatefulWidget for WeaknessMonsterTable {
                 Cell::from(Span::styled(
                     h,
                     Style::default().fg(match (i, &state.selected_weapon_elm_tab) {
-                        (1, MonsterWeaknessTab::ElementDamage) => MonsterElements::Fire.color(),
-                        (2, MonsterWeaknessTab::ElementDamage) => MonsterElements::Water.color(),
-                        (3, MonsterWeaknessTab::ElementDamage) => MonsterElements::Thunder.color(),
-                        (4, MonsterWeaknessTab::ElementDamage) => MonsterElements::Ice.color(),
-                        (5, MonsterWeaknessTab::ElementDamage) => MonsterElements::Dragon.color(),
-                        _ => Color::White,
+                        (1, MonsterWeaknessTab::ElementDamage) => {
+                            MonsterElements::Fire.color(theme)
+                        }
+                        (2, MonsterWeaknessTab::ElementDamage) => {
+                            MonsterElements::Water.color(theme)
+                        }
+                        (3, MonsterWeaknessTab::ElementDamage) => {
+                            MonsterElements::Thunder.color(theme)
+                        }
+                        (4, MonsterWeaknessTab::ElementDamage) => MonsterElements::Ice.color(theme),
+                        (5, MonsterWeaknessTab::ElementDamage) => {
+                            MonsterElements::Dragon.color(theme)
+                        }
+                        _ => theme.ui.text,
                     }),
                 ))
             })
@@ -255,16 +297,36 @@ impl StatefulWidget for WeaknessMonsterTable {
 
                 let selected_rows = match state.selected_weapon_elm_tab {
                     MonsterWeaknessTab::WeaponDamage => vec![
-                        Cell::from(text_rows_generator(&data.weapon.cut_damage)),
-                        Cell::from(text_rows_generator(&data.weapon.blunt_damage)),
-                        Cell::from(text_rows_generator(&data.weapon.ammo_damage)),
+                        Cell::from(text_rows_generator(theme, glyphs, &data.weapon.cut_damage)),
+                        Cell::from(text_rows_generator(
+                            theme,
+                            glyphs,
+                            &data.weapon.blunt_damage,
+                        )),
+                        Cell::from(text_rows_generator(theme, glyphs, &data.weapon.ammo_damage)),
                     ],
                     MonsterWeaknessTab::ElementDamage => vec![
-                        Cell::from(text_rows_generator(&data.element.fire_damage)),
-                        Cell::from(text_rows_generator(&data.element.water_damage)),
-                        Cell::from(text_rows_generator(&data.element.thunder_damage)),
-                        Cell::from(text_rows_generator(&data.element.ice_damage)),
-                        Cell::from(text_rows_generator(&data.element.dragon_damage)),
+                        Cell::from(text_rows_generator(
+                            theme,
+                            glyphs,
+                            &data.element.fire_damage,
+                        )),
+                        Cell::from(text_rows_generator(
+                            theme,
+                            glyphs,
+                            &data.element.water_damage,
+                        )),
+                        Cell::from(text_rows_generator(
+                            theme,
+                            glyphs,
+                            &data.element.thunder_damage,
+                        )),
+                        Cell::from(text_rows_generator(theme, glyphs, &data.element.ice_damage)),
+                        Cell::from(text_rows_generator(
+                            theme,
+                            glyphs,
+                            &data.element.dragon_damage,
+                        )),
                     ],
                 };
 
@@ -274,8 +336,7 @@ impl StatefulWidget for WeaknessMonsterTable {
             })
             .collect();
 
-        let selected_row_style = Style::default().bg(Color::Rgb(16, 33, 56));
-        let bar = " █ ";
+        let selected_row_style = theme.row_style();
 
         let columns = match state.selected_weapon_elm_tab {
             MonsterWeaknessTab::WeaponDamage => vec![
@@ -299,8 +360,8 @@ impl StatefulWidget for WeaknessMonsterTable {
                 .header(header)
                 .row_highlight_style(selected_row_style)
                 .highlight_symbol(Text::from(vec![
-                    bar.yellow().into(),
-                    bar.yellow().into(),
+                    theme.row_marker(glyphs).into(),
+                    theme.row_marker(glyphs).into(),
                     "".into(),
                 ]))
                 .highlight_spacing(HighlightSpacing::Always),
@@ -315,21 +376,17 @@ impl StatefulWidget for WeaknessMonsterTable {
             &mut state.scrollbar_state,
         );
 
-        WeaknessAilmentItems::new(&self.1).render(
-            ailment_item_chunk,
-            buf,
-            &mut state.selected_status_item_tab,
-        );
+        WeaknessAilmentItems::new(&self.1, theme, glyphs).render(ailment_item_chunk, buf, state);
     }
 }
 
-fn text_rows_generator(number: &u8) -> Text {
+fn text_rows_generator(theme: &Theme, glyphs: &Glyphs, number: &u8) -> Text<'static> {
     Text::from(vec![
         Line::from(if *number == 0 {
-            vec!["".red().bold()]
+            vec![glyphs.cross.fg(theme.ui.negative).bold()]
         } else {
             vec![
-                Span::styled("", Style::default().fg(Color::Yellow)),
+                Span::styled(glyphs.star, Style::default().fg(theme.ui.marker)),
                 Span::from(format!(" {}", &number)),
             ]
         }),
@@ -0,0 +1,119 @@
+use ratatui::{
+    layout::{Constraint, Layout},
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::{
+        Block, BorderType, Borders, Cell, HighlightSpacing, Paragraph, Row, StatefulWidget, Table,
+        Widget,
+    },
+};
+
+use crate::{
+    monster::monster::HITZONE_ELEMENTS,
+    state::{list_detail::ListPane, quest::QuestListState},
+    theme::{glyph::Glyphs, Theme},
+};
+
+pub struct QuestDetail<'a>(pub &'a Theme, pub &'a Glyphs);
+
+impl StatefulWidget for QuestDetail<'_> {
+    type State = QuestListState;
+
+    fn render(
+        self,
+        area: ratatui::prelude::Rect,
+        buf: &mut ratatui::prelude::Buffer,
+        state: &mut Self::State,
+    ) {
+        let theme = self.0;
+        let glyphs = self.1;
+        let quest = state.list.current_data.clone();
+
+        let block = Block::new()
+            .title(format!(" {} ", quest.name))
+            .title_style(Style::default().bold())
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(theme.accent_style(matches!(state.list.focused_pane, ListPane::Detail)));
+
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        let [summary_chunk, table_chunk] =
+            Layout::vertical([Constraint::Length(2), Constraint::Min(1)])
+                .horizontal_margin(1)
+                .areas(inner);
+
+        Paragraph::new(Line::from(vec![
+            Span::styled("Type ", Style::default().bold()),
+            Span::from(quest.quest_type.to_string()),
+            Span::from("  "),
+            Span::styled("Level ", Style::default().bold()),
+            Span::styled(
+                glyphs.star.repeat(quest.level as usize),
+                Style::default().fg(theme.ui.highlight),
+            ),
+            Span::from("  "),
+            Span::styled("Targets ", Style::default().bold()),
+            Span::from(quest.targets.len().to_string()),
+        ]))
+        .render(summary_chunk, buf);
+
+        let header = ["Monster", "Type", "Elements", "Weak To"]
+            .into_iter()
+            .map(|h| Cell::from(Span::styled(h, Style::default().fg(theme.ui.text))))
+            .collect::<Row>()
+            .style(Style::default().bold())
+            .height(1);
+
+        let rows: Vec<Row<'_>> = quest
+            .targets
+            .iter()
+            .map(|target| {
+                let monster = &target.monster;
+                let elements = monster
+                    .elements
+                    .iter()
+                    .map(|e| e.to_string())
+                    .collect::<Vec<String>>()
+                    .join(" ");
+                let weak_to = HITZONE_ELEMENTS
+                    .iter()
+                    .filter(|e| monster.weaknesses.is_weak_to(e))
+                    .map(|e| e.to_string())
+                    .collect::<Vec<String>>()
+                    .join(" ");
+
+                Row::new([
+                    Cell::from(monster.name.name.clone()),
+                    Cell::from(monster.basic_info.m_type.clone()),
+                    Cell::from(elements),
+                    Cell::from(weak_to),
+                ])
+            })
+            .collect();
+
+        let columns = [
+            Constraint::Percentage(30),
+            Constraint::Percentage(25),
+            Constraint::Percentage(20),
+            Constraint::Percentage(25),
+        ];
+
+        let highlight_style = match state.list.focused_pane {
+            ListPane::Detail => theme.row_style(),
+            ListPane::List => Style::default(),
+        };
+
+        StatefulWidget::render(
+            Table::new(rows, columns)
+                .header(header)
+                .row_highlight_style(highlight_style)
+                .highlight_symbol(theme.row_marker(glyphs))
+                .highlight_spacing(HighlightSpacing::Always),
+            table_chunk,
+            buf,
+            &mut state.list.detail_table_state,
+        );
+    }
+}
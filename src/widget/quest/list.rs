@@ -0,0 +1,88 @@
+use ratatui::{
+    layout::{Constraint, Layout},
+    style::Style,
+    text::{Line, Span},
+    widgets::{
+        Block, Borders, HighlightSpacing, List, ListItem, Scrollbar, ScrollbarOrientation,
+        StatefulWidget, Widget,
+    },
+};
+
+use crate::state::{list_detail::ListPane, quest::QuestListState};
+use crate::theme::{glyph::Glyphs, Theme};
+
+pub struct QuestList<'a>(pub &'a Theme, pub &'a Glyphs);
+
+impl StatefulWidget for QuestList<'_> {
+    type State = QuestListState;
+
+    fn render(
+        self,
+        area: ratatui::prelude::Rect,
+        buf: &mut ratatui::prelude::Buffer,
+        state: &mut Self::State,
+    ) {
+        let theme = self.0;
+        let glyphs = self.1;
+        let type_filter = state
+            .type_filter
+            .map_or_else(|| String::from("All Types"), |t| t.to_string());
+        let level_filter = state.level_filter.map_or_else(
+            || String::from("All Levels"),
+            |l| format!("{} {}", glyphs.star, l),
+        );
+
+        Block::default()
+            .borders(Borders::LEFT)
+            .border_style(theme.accent_style(matches!(state.list.focused_pane, ListPane::List)))
+            .render(area, buf);
+
+        let [filter_chunk, wrapper] = Layout::vertical([Constraint::Length(1), Constraint::Min(1)])
+            .horizontal_margin(1)
+            .areas(area);
+
+        Line::from(vec![
+            Span::styled(
+                format!("[ {} ]", type_filter),
+                Style::default().fg(theme.ui.muted),
+            ),
+            Span::from(" "),
+            Span::styled(
+                format!("[ {} ]", level_filter),
+                Style::default().fg(theme.ui.muted),
+            ),
+        ])
+        .render(filter_chunk, buf);
+
+        let items: Vec<ListItem> = state
+            .list
+            .list_items()
+            .iter()
+            .map(|quest| {
+                ListItem::new(Line::from(vec![
+                    Span::styled(
+                        format!("[ {} ] ", quest.level),
+                        Style::default().fg(theme.ui.highlight),
+                    ),
+                    Span::from(quest.name.to_string()),
+                ]))
+            })
+            .collect();
+
+        StatefulWidget::render(
+            List::new(items)
+                .highlight_style(theme.selected_style())
+                .highlight_symbol("> ")
+                .highlight_spacing(HighlightSpacing::Always),
+            wrapper,
+            buf,
+            &mut state.list.list_state,
+        );
+
+        Scrollbar::new(ScrollbarOrientation::VerticalRight).render(
+            wrapper,
+            buf,
+            &mut state.list.list_scrollbar_state,
+        );
+    }
+}
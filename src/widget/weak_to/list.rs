@@ -0,0 +1,62 @@
+use ratatui::{
+    layout::{Constraint, Layout},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, HighlightSpacing, List, ListItem, StatefulWidget, Widget},
+};
+
+use crate::{
+    data::weak_to::Attack,
+    state::weak_to::{WeakToPane, WeakToState},
+    theme::{glyph::Glyphs, Theme},
+};
+
+pub struct AttackList<'a>(pub &'a Theme, pub &'a Glyphs);
+
+impl StatefulWidget for AttackList<'_> {
+    type State = WeakToState;
+
+    fn render(
+        self,
+        area: ratatui::prelude::Rect,
+        buf: &mut ratatui::prelude::Buffer,
+        state: &mut Self::State,
+    ) {
+        let theme = self.0;
+        let glyphs = self.1;
+        Block::default()
+            .borders(Borders::LEFT)
+            .border_style(theme.accent_style(matches!(state.focused_pane, WeakToPane::Attacks)))
+            .render(area, buf);
+
+        let [filter_chunk, wrapper] = Layout::vertical([Constraint::Length(2), Constraint::Min(1)])
+            .horizontal_margin(1)
+            .areas(area);
+
+        Line::from(Span::styled(
+            format!("[ {} ]", state.rank_by),
+            Style::default().fg(theme.ui.muted),
+        ))
+        .render(filter_chunk, buf);
+
+        let items: Vec<ListItem> = Attack::ALL
+            .iter()
+            .map(|attack| {
+                ListItem::new(Line::from(Span::styled(
+                    Glyphs::labeled(&attack.to_string(), &attack.icon(glyphs)),
+                    Style::default().fg(attack.color(theme)),
+                )))
+            })
+            .collect();
+
+        StatefulWidget::render(
+            List::new(items)
+                .highlight_style(theme.selected_style())
+                .highlight_symbol("> ")
+                .highlight_spacing(HighlightSpacing::Always),
+            wrapper,
+            buf,
+            &mut state.list_state,
+        );
+    }
+}
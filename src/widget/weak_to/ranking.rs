@@ -0,0 +1,113 @@
+use ratatui::{
+    layout::{Constraint, Layout},
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::{
+        Block, BorderType, Borders, Cell, HighlightSpacing, Paragraph, Row, StatefulWidget, Table,
+        Widget,
+    },
+};
+
+use crate::{
+    state::weak_to::{WeakToPane, WeakToState},
+    theme::{glyph::Glyphs, Theme},
+};
+
+pub struct WeakToRanking<'a>(pub &'a Theme, pub &'a Glyphs);
+
+impl StatefulWidget for WeakToRanking<'_> {
+    type State = WeakToState;
+
+    fn render(
+        self,
+        area: ratatui::prelude::Rect,
+        buf: &mut ratatui::prelude::Buffer,
+        state: &mut Self::State,
+    ) {
+        let theme = self.0;
+        let glyphs = self.1;
+        let attack = state.attack;
+
+        let block = Block::new()
+            .title(format!(" Weak To {} ", attack))
+            .title_style(Style::default().bold())
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(theme.accent_style(matches!(state.focused_pane, WeakToPane::Monsters)));
+
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        let [summary_chunk, table_chunk] =
+            Layout::vertical([Constraint::Length(2), Constraint::Min(1)])
+                .horizontal_margin(1)
+                .areas(inner);
+
+        Paragraph::new(Line::from(vec![
+            Span::styled("Attack ", Style::default().bold()),
+            Span::styled(attack.to_string(), Style::default().fg(attack.color(theme))),
+            Span::from("  "),
+            Span::styled("Ranked By ", Style::default().bold()),
+            Span::from(state.rank_by.to_string()),
+            Span::from("  "),
+            Span::styled("Monsters ", Style::default().bold()),
+            Span::from(state.ranking.len().to_string()),
+        ]))
+        .render(summary_chunk, buf);
+
+        let header = ["#", "Monster", "Best", "Average", "Weakest Part"]
+            .into_iter()
+            .map(|h| Cell::from(Span::styled(h, Style::default().fg(theme.ui.text))))
+            .collect::<Row>()
+            .style(Style::default().bold())
+            .height(1);
+
+        let rows: Vec<Row<'_>> = state
+            .ranking
+            .iter()
+            .enumerate()
+            .map(|(i, entry)| {
+                Row::new([
+                    Cell::from(Span::styled(
+                        (i + 1).to_string(),
+                        Style::default().fg(theme.ui.muted),
+                    )),
+                    Cell::from(entry.name.clone()),
+                    Cell::from(Line::from(vec![
+                        Span::styled(glyphs.star, Style::default().fg(theme.ui.marker)),
+                        Span::styled(
+                            format!(" {}", entry.best),
+                            Style::default().fg(attack.color(theme)),
+                        ),
+                    ])),
+                    Cell::from(format!("{:.1}", entry.average)),
+                    Cell::from(entry.weakest_part.clone()),
+                ])
+            })
+            .collect();
+
+        let columns = [
+            Constraint::Length(4),
+            Constraint::Length(30),
+            Constraint::Length(8),
+            Constraint::Length(9),
+            Constraint::Fill(1),
+        ];
+
+        let highlight_style = match state.focused_pane {
+            WeakToPane::Monsters => theme.row_style(),
+            WeakToPane::Attacks => Style::default(),
+        };
+
+        StatefulWidget::render(
+            Table::new(rows, columns)
+                .header(header)
+                .row_highlight_style(highlight_style)
+                .highlight_symbol(theme.row_marker(glyphs))
+                .highlight_spacing(HighlightSpacing::Always),
+            table_chunk,
+            buf,
+            &mut state.table_state,
+        );
+    }
+}
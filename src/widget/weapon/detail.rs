@@ -0,0 +1,163 @@
+use ratatui::{
+    layout::{Constraint, Layout},
+    style::{Color, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph, StatefulWidget, Widget},
+};
+
+use crate::{
+    state::{list_detail::ListPane, weapon::WeaponListState},
+    theme::{glyph::Glyphs, Theme},
+    weapon::{Sharpness, WeaponEntity},
+    widget::crafting::CraftingTable,
+};
+/// Sharpness hits drawn by one cell of the bar.
+const HITS_PER_CELL: u16 = 10;
+
+pub struct WeaponDetail<'a>(pub &'a Theme, pub &'a Glyphs);
+
+impl StatefulWidget for WeaponDetail<'_> {
+    type State = WeaponListState;
+
+    fn render(
+        self,
+        area: ratatui::prelude::Rect,
+        buf: &mut ratatui::prelude::Buffer,
+        state: &mut Self::State,
+    ) {
+        let theme = self.0;
+        let glyphs = self.1;
+        let weapon = state.list.current_data.weapon.clone();
+
+        let block = Block::new()
+            .title(format!(" {} ", weapon.name))
+            .title_style(Style::default().bold())
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(theme.accent_style(matches!(state.list.focused_pane, ListPane::Detail)));
+
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        let [info_chunk, table_chunk] =
+            Layout::vertical([Constraint::Length(7), Constraint::Min(1)])
+                .horizontal_margin(1)
+                .areas(inner);
+
+        let label = |text: &'static str| Span::styled(text, Style::default().bold());
+
+        let element = weapon
+            .element
+            .as_ref()
+            .map_or_else(|| String::from("-"), |e| format!("{} {}", e.kind, e.value));
+        let slots = match weapon.slots.is_empty() {
+            true => String::from("-"),
+            false => weapon
+                .slots
+                .iter()
+                .map(|s| format!("[{}]", s))
+                .collect::<String>(),
+        };
+
+        let upgrade_from = weapon
+            .upgrade_from
+            .and_then(|id| state.find_weapon(id))
+            .map_or_else(|| String::from("-"), |w| w.name.clone());
+        let upgrades_to = state
+            .upgrades_of(weapon.id)
+            .map(|w| w.name.clone())
+            .collect::<Vec<String>>();
+        let upgrades_to = match upgrades_to.is_empty() {
+            true => String::from("-"),
+            false => upgrades_to.join(", "),
+        };
+
+        Paragraph::new(vec![
+            Line::from(vec![
+                label("Type "),
+                Span::from(weapon.weapon_type.to_string()),
+                Span::from("  "),
+                label("Tree "),
+                Span::from(weapon.tree.clone()),
+                Span::from("  "),
+                label("Rarity "),
+                Span::styled(
+                    weapon.rarity.to_string(),
+                    Style::default().fg(theme.ui.highlight),
+                ),
+            ]),
+            Line::from(vec![
+                label("Attack "),
+                Span::from(weapon.attack.to_string()),
+                Span::from("  "),
+                label("Element "),
+                Span::from(element),
+                Span::from("  "),
+                label("Slots "),
+                Span::from(slots),
+            ]),
+            sharpness_line(theme, glyphs, &weapon),
+            Line::from(""),
+            Line::from(vec![label("Upgrades From "), Span::from(upgrade_from)]),
+            Line::from(vec![label("Upgrades To "), Span::from(upgrades_to)]),
+        ])
+        .render(info_chunk, buf);
+
+        StatefulWidget::render(
+            CraftingTable {
+                materials: &weapon.materials,
+                index: &state.materials,
+                focused: state.list.focused_pane == ListPane::Detail,
+                theme,
+                glyphs,
+            },
+            table_chunk,
+            buf,
+            &mut state.list.detail_table_state,
+        );
+    }
+}
+
+fn sharpness_line(theme: &Theme, glyphs: &Glyphs, weapon: &WeaponEntity) -> Line<'static> {
+    let mut spans = vec![Span::styled("Sharpness ", Style::default().bold())];
+
+    if weapon.sharpness.is_empty() {
+        spans.push(Span::from("- (ranged weapon)"));
+        return Line::from(spans);
+    }
+
+    spans.extend(
+        weapon
+            .sharpness
+            .iter()
+            .zip(Sharpness::ALL)
+            .filter(|(hits, _)| **hits > 0)
+            .map(|(hits, sharpness)| {
+                let cells = hits.div_ceil(HITS_PER_CELL) as usize;
+                Span::styled(
+                    glyphs.bar.repeat(cells),
+                    Style::default().fg(sharpness_color(theme, sharpness)),
+                )
+            }),
+    );
+
+    if let Some(max) = weapon.max_sharpness() {
+        spans.push(Span::from(format!(" {}", max)));
+    }
+
+    Line::from(spans)
+}
+
+fn sharpness_color(theme: &Theme, sharpness: Sharpness) -> Color {
+    use Sharpness::*;
+    let colors = &theme.sharpness;
+    match sharpness {
+        Red => colors.red,
+        Orange => colors.orange,
+        Yellow => colors.yellow,
+        Green => colors.green,
+        Blue => colors.blue,
+        White => colors.white,
+        Purple => colors.purple,
+    }
+}
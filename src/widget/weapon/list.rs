@@ -0,0 +1,79 @@
+use ratatui::{
+    layout::{Constraint, Layout},
+    style::Style,
+    text::{Line, Span},
+    widgets::{
+        Block, Borders, HighlightSpacing, List, ListItem, Scrollbar, ScrollbarOrientation,
+        StatefulWidget, Widget,
+    },
+};
+
+use crate::state::{list_detail::ListPane, weapon::WeaponListState};
+use crate::theme::{glyph::Glyphs, Theme};
+
+pub struct WeaponList<'a>(pub &'a Theme, pub &'a Glyphs);
+
+impl StatefulWidget for WeaponList<'_> {
+    type State = WeaponListState;
+
+    fn render(
+        self,
+        area: ratatui::prelude::Rect,
+        buf: &mut ratatui::prelude::Buffer,
+        state: &mut Self::State,
+    ) {
+        let theme = self.0;
+        let glyphs = self.1;
+        let type_filter = state
+            .type_filter
+            .map_or_else(|| String::from("All Types"), |t| t.to_string());
+
+        Block::default()
+            .borders(Borders::LEFT)
+            .border_style(theme.accent_style(matches!(state.list.focused_pane, ListPane::List)))
+            .render(area, buf);
+
+        let [filter_chunk, wrapper] = Layout::vertical([Constraint::Length(1), Constraint::Min(1)])
+            .horizontal_margin(1)
+            .areas(area);
+
+        Line::from(Span::styled(
+            format!("[ {} ]", type_filter),
+            Style::default().fg(theme.ui.muted),
+        ))
+        .render(filter_chunk, buf);
+
+        let items: Vec<ListItem> = state
+            .list
+            .list_items()
+            .iter()
+            .map(|row| {
+                let branch = match row.depth {
+                    0 => String::new(),
+                    depth => format!("{}{} ", "  ".repeat(depth - 1), glyphs.branch),
+                };
+
+                ListItem::new(Line::from(vec![
+                    Span::styled(branch, Style::default().fg(theme.ui.dim)),
+                    Span::from(row.weapon.name.to_string()),
+                ]))
+            })
+            .collect();
+
+        StatefulWidget::render(
+            List::new(items)
+                .highlight_style(theme.selected_style())
+                .highlight_symbol("> ")
+                .highlight_spacing(HighlightSpacing::Always),
+            wrapper,
+            buf,
+            &mut state.list.list_state,
+        );
+
+        Scrollbar::new(ScrollbarOrientation::VerticalRight).render(
+            wrapper,
+            buf,
+            &mut state.list.list_scrollbar_state,
+        );
+    }
+}